@@ -119,6 +119,7 @@ use crate::api::opt::Endpoint;
 use crate::api::opt::Strict;
 #[cfg(any(feature = "native-tls", feature = "rustls"))]
 use crate::api::opt::Tls;
+use crate::api::opt::Use;
 use crate::api::Connect;
 use crate::api::Result;
 use crate::api::Surreal;
@@ -146,6 +147,8 @@ impl IntoEndpoint for &str {
 			auth: Level::No,
 			username: String::new(),
 			password: String::new(),
+			ns: None,
+			db: None,
 		})
 	}
 }
@@ -162,6 +165,19 @@ impl IntoEndpoint for String {
 	}
 }
 
+impl<T> IntoEndpoint for (T, Use)
+where
+	T: IntoEndpoint,
+{
+	fn into_endpoint(self) -> Result<Endpoint> {
+		let (address, target) = self;
+		let mut endpoint = address.into_endpoint()?;
+		endpoint.ns = target.ns;
+		endpoint.db = target.db;
+		Ok(endpoint)
+	}
+}
+
 #[cfg(feature = "native-tls")]
 #[cfg_attr(docsrs, doc(cfg(feature = "native-tls")))]
 impl<T> IntoEndpoint for (T, native_tls::TlsConnector)
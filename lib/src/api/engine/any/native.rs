@@ -137,7 +137,8 @@ impl Connection for Any {
 					#[cfg(feature = "protocol-http")]
 					{
 						features.insert(ExtraFeatures::Backup);
-						let headers = http::default_headers();
+						let headers = http::default_headers(address.config.format);
+						let auth = http::Auth::from_endpoint(&address);
 						#[allow(unused_mut)]
 						let mut builder = ClientBuilder::new().default_headers(headers);
 						#[cfg(any(feature = "native-tls", feature = "rustls"))]
@@ -155,7 +156,13 @@ impl Connection for Any {
 							client.get(base_url.join(Method::Health.as_str())?),
 						)
 						.await?;
-						engine::remote::http::native::router(base_url, client, route_rx);
+						engine::remote::http::native::router(
+							base_url,
+							client,
+							address.config,
+							auth,
+							route_rx,
+						);
 					}
 
 					#[cfg(not(feature = "protocol-http"))]
@@ -91,7 +91,7 @@ pub(crate) fn router(
 			_ => url.as_str(),
 		};
 
-		let kvs = match Datastore::new(path).await {
+		let kvs = match Datastore::new_with_config(path, address.config).await {
 			Ok(kvs) => {
 				let _ = conn_tx.into_send_async(Ok(())).await;
 				kvs
@@ -102,16 +102,6 @@ pub(crate) fn router(
 			}
 		};
 
-		let kvs = kvs
-			.with_strict_mode(address.config.strict)
-			.with_query_timeout(address.config.query_timeout)
-			.with_transaction_timeout(address.config.transaction_timeout);
-
-		let kvs = match address.config.notifications {
-			true => kvs.with_notifications(),
-			false => kvs,
-		};
-
 		let mut vars = BTreeMap::new();
 		let mut stream = route_rx.into_stream();
 		let configured_root = match address.auth {
@@ -17,10 +17,14 @@ use crate::api::engine::update_statement;
 use crate::api::err::Error;
 use crate::api::method::query::QueryResult;
 use crate::api::opt::from_value;
+use crate::api::opt::Config;
+use crate::api::opt::Endpoint;
+use crate::api::opt::Format;
 use crate::api::Connect;
 use crate::api::Response as QueryResponse;
 use crate::api::Result;
 use crate::api::Surreal;
+use crate::dbs::Level;
 use crate::dbs::Status;
 use crate::opt::IntoEndpoint;
 use crate::sql::serde::deserialize;
@@ -36,6 +40,7 @@ use reqwest::header::ACCEPT;
 #[cfg(not(target_arch = "wasm32"))]
 use reqwest::header::CONTENT_TYPE;
 use reqwest::RequestBuilder;
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
 use serde::Serialize;
 use std::marker::PhantomData;
@@ -102,14 +107,14 @@ impl Surreal<Client> {
 	}
 }
 
-pub(crate) fn default_headers() -> HeaderMap {
+pub(crate) fn default_headers(format: Format) -> HeaderMap {
 	let mut headers = HeaderMap::new();
-	headers.insert(ACCEPT, HeaderValue::from_static("application/surrealdb"));
+	headers.insert(ACCEPT, HeaderValue::from_static(format.mime()));
 	headers
 }
 
 #[derive(Debug)]
-enum Auth {
+pub(crate) enum Auth {
 	Basic {
 		user: String,
 		pass: String,
@@ -119,6 +124,19 @@ enum Auth {
 	},
 }
 
+impl Auth {
+	/// The root credentials configured on the endpoint, if any
+	pub(crate) fn from_endpoint(address: &Endpoint) -> Option<Self> {
+		match address.auth {
+			Level::Kv => Some(Auth::Basic {
+				user: address.username.clone(),
+				pass: address.password.clone(),
+			}),
+			_ => None,
+		}
+	}
+}
+
 trait Authenticate {
 	fn auth(self, auth: &Option<Auth>) -> Self;
 }
@@ -140,6 +158,106 @@ impl Authenticate for RequestBuilder {
 
 type HttpQueryResponse = (String, Status, Value);
 
+/// A query response, as it is encoded in the JSON and CBOR formats
+#[derive(Debug, Deserialize)]
+struct SimpleQueryResponse {
+	time: String,
+	status: Status,
+	result: serde_json::Value,
+}
+
+/// Decodes the body of a response, which was encoded in the specified format
+fn decode<T>(format: Format, bytes: &[u8]) -> Result<T>
+where
+	T: DeserializeOwned,
+{
+	let error = |error: String| Error::ResponseFromFormat {
+		format,
+		bytes: bytes.to_vec(),
+		error,
+	};
+	match format {
+		Format::Binary => deserialize(bytes).map_err(|error| {
+			Error::ResponseFromBinary {
+				binary: bytes.to_vec(),
+				error,
+			}
+			.into()
+		}),
+		Format::Json => serde_json::from_slice(bytes).map_err(|e| error(e.to_string()).into()),
+		Format::Cbor => serde_cbor::from_slice(bytes).map_err(|e| error(e.to_string()).into()),
+	}
+}
+
+/// Sends a request to the server, checking that its body is within the configured size limit
+async fn send(
+	client: &reqwest::Client,
+	config: &Config,
+	request: RequestBuilder,
+) -> Result<reqwest::Response> {
+	let request = request.build()?;
+	if let (Some(max), Some(body)) =
+		(config.max_request_size, request.body().and_then(|body| body.as_bytes()))
+	{
+		if body.len() > max {
+			return Err(Error::PayloadTooLarge {
+				max,
+			}
+			.into());
+		}
+	}
+	Ok(client.execute(request).await?.error_for_status()?)
+}
+
+/// Reads the body of a response, stopping as soon as it exceeds the configured size limit
+#[cfg(not(target_arch = "wasm32"))]
+async fn read(mut response: reqwest::Response, config: &Config) -> Result<Vec<u8>> {
+	let max = match config.max_response_size {
+		Some(max) => max,
+		None => return Ok(response.bytes().await?.to_vec()),
+	};
+	if response.content_length().map_or(false, |len| len > max as u64) {
+		return Err(Error::PayloadTooLarge {
+			max,
+		}
+		.into());
+	}
+	let mut bytes = Vec::new();
+	while let Some(chunk) = response.chunk().await? {
+		if bytes.len() + chunk.len() > max {
+			return Err(Error::PayloadTooLarge {
+				max,
+			}
+			.into());
+		}
+		bytes.extend_from_slice(&chunk);
+	}
+	Ok(bytes)
+}
+
+/// Reads the body of a response, checking that it is within the configured size limit
+#[cfg(target_arch = "wasm32")]
+async fn read(response: reqwest::Response, config: &Config) -> Result<Vec<u8>> {
+	let max = match config.max_response_size {
+		Some(max) => max,
+		None => return Ok(response.bytes().await?.to_vec()),
+	};
+	if response.content_length().map_or(false, |len| len > max as u64) {
+		return Err(Error::PayloadTooLarge {
+			max,
+		}
+		.into());
+	}
+	let bytes = response.bytes().await?;
+	if bytes.len() > max {
+		return Err(Error::PayloadTooLarge {
+			max,
+		}
+		.into());
+	}
+	Ok(bytes.to_vec())
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct Root {
 	user: String,
@@ -154,27 +272,32 @@ struct AuthResponse {
 	token: Option<String>,
 }
 
-async fn submit_auth(request: RequestBuilder) -> Result<Value> {
-	let response = request.send().await?.error_for_status()?;
-	let bytes = response.bytes().await?;
-	let response: AuthResponse =
-		deserialize(&bytes).map_err(|error| Error::ResponseFromBinary {
-			binary: bytes.to_vec(),
-			error,
-		})?;
+async fn submit_auth(
+	client: &reqwest::Client,
+	config: &Config,
+	request: RequestBuilder,
+) -> Result<Value> {
+	let response = send(client, config, request).await?;
+	let bytes = read(response, config).await?;
+	let response: AuthResponse = decode(config.format, &bytes)?;
 	Ok(response.token.into())
 }
 
-async fn query(request: RequestBuilder) -> Result<QueryResponse> {
+async fn query(
+	client: &reqwest::Client,
+	config: &Config,
+	request: RequestBuilder,
+) -> Result<QueryResponse> {
 	info!("{request:?}");
-	let response = request.send().await?.error_for_status()?;
-	let bytes = response.bytes().await?;
-	let responses = deserialize::<Vec<HttpQueryResponse>>(&bytes).map_err(|error| {
-		Error::ResponseFromBinary {
-			binary: bytes.to_vec(),
-			error,
-		}
-	})?;
+	let response = send(client, config, request).await?;
+	let bytes = read(response, config).await?;
+	let responses: Vec<HttpQueryResponse> = match config.format {
+		Format::Binary => decode(config.format, &bytes)?,
+		format => decode::<Vec<SimpleQueryResponse>>(format, &bytes)?
+			.into_iter()
+			.map(|response| (response.time, response.status, response.result.into()))
+			.collect(),
+	};
 	let mut map = IndexMap::<usize, QueryResult>::with_capacity(responses.len());
 	for (index, (_time, status, value)) in responses.into_iter().enumerate() {
 		match status {
@@ -194,8 +317,13 @@ async fn query(request: RequestBuilder) -> Result<QueryResponse> {
 	Ok(QueryResponse(map))
 }
 
-async fn take(one: bool, request: RequestBuilder) -> Result<Value> {
-	if let Some(result) = query(request).await?.0.remove(&0) {
+async fn take(
+	one: bool,
+	client: &reqwest::Client,
+	config: &Config,
+	request: RequestBuilder,
+) -> Result<Value> {
+	if let Some(result) = query(client, config, request).await?.0.remove(&0) {
 		let mut vec = result?;
 		match one {
 			true => match vec.pop() {
@@ -264,7 +392,12 @@ async fn export(request: RequestBuilder, path: PathBuf) -> Result<Value> {
 }
 
 #[cfg(not(target_arch = "wasm32"))]
-async fn import(request: RequestBuilder, path: PathBuf) -> Result<Value> {
+async fn import(
+	client: &reqwest::Client,
+	config: &Config,
+	request: RequestBuilder,
+	path: PathBuf,
+) -> Result<Value> {
 	let mut file = match OpenOptions::new().read(true).open(&path).await {
 		Ok(path) => path,
 		Err(error) => {
@@ -283,15 +416,13 @@ async fn import(request: RequestBuilder, path: PathBuf) -> Result<Value> {
 		}
 		.into());
 	}
-	request
+	let request = request
 		.header(ACCEPT, "application/octet-stream")
 		// ideally we should pass `file` directly into the body
 		// but currently that results in
 		// "HTTP status client error (405 Method Not Allowed) for url"
-		.body(contents)
-		.send()
-		.await?
-		.error_for_status()?;
+		.body(contents);
+	send(client, config, request).await?;
 	Ok(Value::None)
 }
 
@@ -310,6 +441,7 @@ async fn router(
 	(_, method, param): (i64, Method, Param),
 	base_url: &Url,
 	client: &reqwest::Client,
+	config: &Config,
 	headers: &mut HeaderMap,
 	vars: &mut IndexMap<String, String>,
 	auth: &mut Option<Auth>,
@@ -358,7 +490,7 @@ async fn router(
 				None => None,
 			};
 			request = request.auth(auth).body("RETURN true");
-			take(true, request).await?;
+			take(true, client, config, request).await?;
 			if let Some(ns) = ns {
 				headers.insert("NS", ns);
 			}
@@ -374,7 +506,7 @@ async fn router(
 				_ => unreachable!(),
 			};
 			let request = client.post(path).headers(headers.clone()).auth(auth).body(credentials);
-			let value = submit_auth(request).await?;
+			let value = submit_auth(client, config, request).await?;
 			if let [credentials] = &mut params[..] {
 				if let Ok(Root {
 					user,
@@ -400,7 +532,7 @@ async fn router(
 				_ => unreachable!(),
 			};
 			let request = client.post(path).headers(headers.clone()).auth(auth).body(credentials);
-			let value = submit_auth(request).await?;
+			let value = submit_auth(client, config, request).await?;
 			Ok(DbResponse::Other(value))
 		}
 		Method::Authenticate => {
@@ -411,7 +543,7 @@ async fn router(
 			};
 			let request =
 				client.post(path).headers(headers.clone()).bearer_auth(&token).body("RETURN true");
-			take(true, request).await?;
+			take(true, client, config, request).await?;
 			*auth = Some(Auth::Bearer {
 				token,
 			});
@@ -426,7 +558,7 @@ async fn router(
 			let statement = create_statement(&mut params);
 			let request =
 				client.post(path).headers(headers.clone()).auth(auth).body(statement.to_string());
-			let value = take(true, request).await?;
+			let value = take(true, client, config, request).await?;
 			Ok(DbResponse::Other(value))
 		}
 		Method::Update => {
@@ -434,7 +566,7 @@ async fn router(
 			let (one, statement) = update_statement(&mut params);
 			let request =
 				client.post(path).headers(headers.clone()).auth(auth).body(statement.to_string());
-			let value = take(one, request).await?;
+			let value = take(one, client, config, request).await?;
 			Ok(DbResponse::Other(value))
 		}
 		Method::Patch => {
@@ -442,7 +574,7 @@ async fn router(
 			let (one, statement) = patch_statement(&mut params);
 			let request =
 				client.post(path).headers(headers.clone()).auth(auth).body(statement.to_string());
-			let value = take(one, request).await?;
+			let value = take(one, client, config, request).await?;
 			Ok(DbResponse::Other(value))
 		}
 		Method::Merge => {
@@ -450,7 +582,7 @@ async fn router(
 			let (one, statement) = merge_statement(&mut params);
 			let request =
 				client.post(path).headers(headers.clone()).auth(auth).body(statement.to_string());
-			let value = take(one, request).await?;
+			let value = take(one, client, config, request).await?;
 			Ok(DbResponse::Other(value))
 		}
 		Method::Select => {
@@ -458,7 +590,7 @@ async fn router(
 			let (one, statement) = select_statement(&mut params);
 			let request =
 				client.post(path).headers(headers.clone()).auth(auth).body(statement.to_string());
-			let value = take(one, request).await?;
+			let value = take(one, client, config, request).await?;
 			Ok(DbResponse::Other(value))
 		}
 		Method::Delete => {
@@ -466,7 +598,7 @@ async fn router(
 			let (one, statement) = delete_statement(&mut params);
 			let request =
 				client.post(path).headers(headers.clone()).auth(auth).body(statement.to_string());
-			let value = take(one, request).await?;
+			let value = take(one, client, config, request).await?;
 			Ok(DbResponse::Other(value))
 		}
 		Method::Query => {
@@ -483,7 +615,7 @@ async fn router(
 				}
 				_ => unreachable!(),
 			}
-			let values = query(request).await?;
+			let values = query(client, config, request).await?;
 			Ok(DbResponse::Query(values))
 		}
 		#[cfg(target_arch = "wasm32")]
@@ -509,7 +641,7 @@ async fn router(
 				.headers(headers.clone())
 				.auth(auth)
 				.header(CONTENT_TYPE, "application/octet-stream");
-			let value = import(request, file).await?;
+			let value = import(client, config, request, file).await?;
 			Ok(DbResponse::Other(value))
 		}
 		Method::Health => {
@@ -536,7 +668,7 @@ async fn router(
 				.auth(auth)
 				.query(&[(key.as_str(), value.as_str())])
 				.body(format!("RETURN ${key}"));
-			take(true, request).await?;
+			take(true, client, config, request).await?;
 			vars.insert(key, value);
 			Ok(DbResponse::Other(Value::None))
 		}
@@ -558,7 +690,7 @@ async fn router(
 				.auth(auth)
 				.query(&[("table", table)])
 				.body("LIVE SELECT * FROM type::table($table)");
-			let value = take(true, request).await?;
+			let value = take(true, client, config, request).await?;
 			Ok(DbResponse::Other(value))
 		}
 		Method::Kill => {
@@ -573,8 +705,70 @@ async fn router(
 				.auth(auth)
 				.query(&[("id", id)])
 				.body("KILL type::string($id)");
-			let value = take(true, request).await?;
+			let value = take(true, client, config, request).await?;
 			Ok(DbResponse::Other(value))
 		}
 	}
 }
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+	use super::*;
+	use serde_json::json;
+	use wiremock::matchers::header;
+	use wiremock::matchers::method;
+	use wiremock::matchers::path;
+	use wiremock::Mock;
+	use wiremock::MockServer;
+	use wiremock::ResponseTemplate;
+
+	async fn query_with_format(format: Format, body: Vec<u8>) -> Value {
+		let server = MockServer::start().await;
+		Mock::given(method("GET"))
+			.and(path("/health"))
+			.respond_with(ResponseTemplate::new(200))
+			.mount(&server)
+			.await;
+		Mock::given(method("POST"))
+			.and(path("/sql"))
+			.and(header("accept", format.mime()))
+			.respond_with(ResponseTemplate::new(200).set_body_raw(body, format.mime()))
+			.expect(1)
+			.mount(&server)
+			.await;
+		let config = Config::new().format(format);
+		let db = Surreal::new::<Http>((*server.address(), config)).await.unwrap();
+		let mut response = db.query("SELECT * FROM person").await.unwrap();
+		response.take(0).unwrap()
+	}
+
+	#[tokio::test]
+	async fn cbor_response_matches_json() {
+		let responses = json!([{
+			"time": "12.5µs",
+			"status": "OK",
+			"result": [{ "id": "person:tobie", "name": "Tobie", "tags": ["rust", 1, 2.5, true] }],
+		}]);
+		let json = serde_json::to_vec(&responses).unwrap();
+		let json = query_with_format(Format::Json, json).await;
+		let cbor = serde_cbor::to_vec(&responses).unwrap();
+		let cbor = query_with_format(Format::Cbor, cbor).await;
+		assert_eq!(cbor, json);
+		assert_eq!(cbor, Value::from(responses[0]["result"].clone()));
+	}
+
+	#[test]
+	fn invalid_cbor_response() {
+		let error = decode::<Vec<SimpleQueryResponse>>(Format::Cbor, b"[{").unwrap_err();
+		assert!(
+			matches!(
+				error,
+				crate::Error::Api(Error::ResponseFromFormat {
+					format: Format::Cbor,
+					..
+				})
+			),
+			"{error:?}"
+		);
+	}
+}
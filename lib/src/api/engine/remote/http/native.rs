@@ -1,3 +1,4 @@
+use super::Auth;
 use super::Client;
 use crate::api::conn::Connection;
 use crate::api::conn::DbResponse;
@@ -5,6 +6,7 @@ use crate::api::conn::Method;
 use crate::api::conn::Param;
 use crate::api::conn::Route;
 use crate::api::conn::Router;
+use crate::api::opt::Config;
 use crate::api::opt::Endpoint;
 #[cfg(any(feature = "native-tls", feature = "rustls"))]
 use crate::api::opt::Tls;
@@ -39,7 +41,8 @@ impl Connection for Client {
 		capacity: usize,
 	) -> Pin<Box<dyn Future<Output = Result<Surreal<Self>>> + Send + Sync + 'static>> {
 		Box::pin(async move {
-			let headers = super::default_headers();
+			let headers = super::default_headers(address.config.format);
+			let auth = Auth::from_endpoint(&address);
 
 			#[allow(unused_mut)]
 			let mut builder = ClientBuilder::new().default_headers(headers);
@@ -65,7 +68,7 @@ impl Connection for Client {
 				capacity => flume::bounded(capacity),
 			};
 
-			router(base_url, client, route_rx);
+			router(base_url, client, address.config, auth, route_rx);
 
 			let mut features = HashSet::new();
 			features.insert(ExtraFeatures::Backup);
@@ -98,11 +101,16 @@ impl Connection for Client {
 	}
 }
 
-pub(crate) fn router(base_url: Url, client: reqwest::Client, route_rx: Receiver<Option<Route>>) {
+pub(crate) fn router(
+	base_url: Url,
+	client: reqwest::Client,
+	config: Config,
+	mut auth: Option<Auth>,
+	route_rx: Receiver<Option<Route>>,
+) {
 	tokio::spawn(async move {
 		let mut headers = HeaderMap::new();
 		let mut vars = IndexMap::new();
-		let mut auth = None;
 		let mut stream = route_rx.into_stream();
 
 		while let Some(Some(route)) = stream.next().await {
@@ -110,6 +118,7 @@ pub(crate) fn router(base_url: Url, client: reqwest::Client, route_rx: Receiver<
 				route.request,
 				&base_url,
 				&client,
+				&config,
 				&mut headers,
 				&mut vars,
 				&mut auth,
@@ -1,3 +1,4 @@
+use super::Auth;
 use super::Client;
 use crate::api::conn::Connection;
 use crate::api::conn::DbResponse;
@@ -6,6 +7,7 @@ use crate::api::conn::Param;
 use crate::api::conn::Route;
 use crate::api::conn::Router;
 use crate::api::opt::Endpoint;
+use crate::api::opt::Format;
 use crate::api::Result;
 use crate::api::Surreal;
 use flume::Receiver;
@@ -78,8 +80,8 @@ impl Connection for Client {
 	}
 }
 
-async fn client(base_url: &Url) -> Result<reqwest::Client> {
-	let headers = super::default_headers();
+async fn client(base_url: &Url, format: Format) -> Result<reqwest::Client> {
+	let headers = super::default_headers(format);
 	let builder = ClientBuilder::new().default_headers(headers);
 	let client = builder.build()?;
 	let health = base_url.join(Method::Health.as_str())?;
@@ -95,7 +97,7 @@ pub(crate) fn router(
 	spawn_local(async move {
 		let base_url = address.endpoint;
 
-		let client = match client(&base_url).await {
+		let client = match client(&base_url, address.config.format).await {
 			Ok(client) => {
 				let _ = conn_tx.into_send_async(Ok(())).await;
 				client
@@ -108,7 +110,7 @@ pub(crate) fn router(
 
 		let mut headers = HeaderMap::new();
 		let mut vars = IndexMap::new();
-		let mut auth = None;
+		let mut auth = Auth::from_endpoint(&address);
 		let mut stream = route_rx.into_stream();
 
 		while let Some(Some(route)) = stream.next().await {
@@ -116,6 +118,7 @@ pub(crate) fn router(
 				route.request,
 				&base_url,
 				&client,
+				&address.config,
 				&mut headers,
 				&mut vars,
 				&mut auth,
@@ -10,6 +10,7 @@ use crate::api::engine::remote::ws::Response;
 use crate::api::engine::remote::ws::PING_INTERVAL;
 use crate::api::engine::remote::ws::PING_METHOD;
 use crate::api::err::Error;
+use crate::api::opt::Backoff;
 use crate::api::opt::Endpoint;
 #[cfg(any(feature = "native-tls", feature = "rustls"))]
 use crate::api::opt::Tls;
@@ -114,7 +115,10 @@ impl Connection for Client {
 					0 => None,
 					capacity => Some(capacity),
 				},
-				max_message_size: Some(MAX_MESSAGE_SIZE),
+				max_message_size: match address.config.max_response_size {
+					Some(max) => Some(max.min(MAX_MESSAGE_SIZE)),
+					None => Some(MAX_MESSAGE_SIZE),
+				},
 				max_frame_size: Some(MAX_FRAME_SIZE),
 				accept_unmasked_frames: false,
 			};
@@ -126,7 +130,19 @@ impl Connection for Client {
 				capacity => flume::bounded(capacity),
 			};
 
-			router(url, maybe_connector, capacity, config, socket, route_rx);
+			let max_request_size = address.config.max_request_size;
+			let backoff = address.config.reconnect.unwrap_or_default();
+
+			router(
+				url,
+				maybe_connector,
+				capacity,
+				config,
+				max_request_size,
+				backoff,
+				socket,
+				route_rx,
+			);
 
 			Ok(Surreal {
 				router: OnceCell::with_value(Arc::new(Router {
@@ -163,6 +179,8 @@ pub(crate) fn router(
 	maybe_connector: Option<Connector>,
 	capacity: usize,
 	config: WebSocketConfig,
+	max_request_size: Option<usize>,
+	backoff: Backoff,
 	mut socket: WebSocketStream<MaybeTlsStream<TcpStream>>,
 	route_rx: Receiver<Option<Route>>,
 ) {
@@ -245,6 +263,17 @@ pub(crate) fn router(
 								trace!("Request {payload}");
 								Message::Binary(payload.into())
 							};
+							if let Some(max) = max_request_size {
+								if message.len() > max {
+									let error = Error::PayloadTooLarge {
+										max,
+									};
+									if response.into_send_async(Err(error.into())).await.is_err() {
+										trace!("Receiver dropped");
+									}
+									continue;
+								}
+							}
 							if let Method::Authenticate
 							| Method::Invalidate
 							| Method::Signin
@@ -336,6 +365,20 @@ pub(crate) fn router(
 										WsError::ConnectionClosed => {
 											trace!("Connection successfully closed on the server");
 										}
+										WsError::Capacity(error) => {
+											trace!("{error}");
+											// The oversized response can't be matched to its request,
+											// so fail every request which is still waiting for one
+											let max =
+												config.max_message_size.unwrap_or(MAX_MESSAGE_SIZE);
+											for (_, (_method, sender)) in routes.drain() {
+												let error = Error::PayloadTooLarge {
+													max,
+												};
+												let _res =
+													sender.into_send_async(Err(error.into())).await;
+											}
+										}
 										error => {
 											trace!("{error}");
 										}
@@ -359,9 +402,24 @@ pub(crate) fn router(
 						}
 					}
 				}
+
+				// Fail any requests which were still waiting for a response, so they can be retried
+				for (_, (_method, sender)) in routes.drain() {
+					let _res = sender.into_send_async(Err(Error::ConnectionLost.into())).await;
+				}
 			}
 
+			let mut attempts = 0;
+
 			'reconnect: loop {
+				if backoff.exhausted(attempts) {
+					trace!("Giving up reconnecting after {attempts} attempts");
+					break 'router;
+				}
+				if attempts > 0 {
+					time::sleep(backoff.delay(attempts)).await;
+				}
+				attempts += 1;
 				trace!("Reconnecting...");
 				match connect(&url, Some(config), maybe_connector.clone()).await {
 					Ok(s) => {
@@ -369,7 +427,6 @@ pub(crate) fn router(
 						for (_, message) in &replay {
 							if let Err(error) = socket.send(message.clone()).await {
 								trace!("{error}");
-								time::sleep(time::Duration::from_secs(1)).await;
 								continue 'reconnect;
 							}
 						}
@@ -385,7 +442,6 @@ pub(crate) fn router(
 							trace!("Request {payload}");
 							if let Err(error) = socket.send(Message::Binary(payload.into())).await {
 								trace!("{error}");
-								time::sleep(time::Duration::from_secs(1)).await;
 								continue 'reconnect;
 							}
 						}
@@ -394,7 +450,6 @@ pub(crate) fn router(
 					}
 					Err(error) => {
 						trace!("Failed to reconnect; {error}");
-						time::sleep(time::Duration::from_secs(1)).await;
 					}
 				}
 			}
@@ -37,7 +37,6 @@ use std::marker::PhantomData;
 use std::pin::Pin;
 use std::sync::atomic::AtomicI64;
 use std::sync::Arc;
-use std::time::Duration;
 use trice::Instant;
 use wasm_bindgen_futures::spawn_local;
 use wasmtimer::tokio as time;
@@ -150,6 +149,7 @@ pub(crate) fn router(
 
 		let mut vars = IndexMap::new();
 		let mut replay = IndexMap::new();
+		let backoff = address.config.reconnect.unwrap_or_default();
 
 		'router: loop {
 			let (mut socket_sink, socket_stream) = socket.split();
@@ -218,6 +218,19 @@ pub(crate) fn router(
 							trace!("Request {payload}");
 							Message::Binary(payload.into())
 						};
+						if let (Some(max), Message::Binary(binary)) =
+							(address.config.max_request_size, &message)
+						{
+							if binary.len() > max {
+								let error = Error::PayloadTooLarge {
+									max,
+								};
+								if response.into_send_async(Err(error.into())).await.is_err() {
+									trace!("Receiver dropped");
+								}
+								continue;
+							}
+						}
 						if let Method::Authenticate
 						| Method::Invalidate
 						| Method::Signin
@@ -256,15 +269,24 @@ pub(crate) fn router(
 					}
 					Either::Response(message) => {
 						last_activity = Instant::now();
+						let too_large = match (&message, address.config.max_response_size) {
+							(Message::Binary(binary), Some(max)) if binary.len() > max => Some(max),
+							_ => None,
+						};
 						match Response::try_from(&message) {
 							Ok(option) => {
 								if let Some(response) = option {
 									trace!("{response:?}");
 									if let Some(Ok(id)) = response.id.map(Value::coerce_to_i64) {
 										if let Some((_method, sender)) = routes.remove(&id) {
-											let _res = sender
-												.into_send_async(DbResponse::from(response.result))
-												.await;
+											let result = match too_large {
+												Some(max) => Err(Error::PayloadTooLarge {
+													max,
+												}
+												.into()),
+												None => DbResponse::from(response.result),
+											};
+											let _res = sender.into_send_async(result).await;
 										}
 									}
 								}
@@ -325,7 +347,22 @@ pub(crate) fn router(
 				}
 			}
 
+			// Fail any requests which were still waiting for a response, so they can be retried
+			for (_, (_method, sender)) in routes.drain() {
+				let _res = sender.into_send_async(Err(Error::ConnectionLost.into())).await;
+			}
+
+			let mut attempts = 0;
+
 			'reconnect: loop {
+				if backoff.exhausted(attempts) {
+					trace!("Giving up reconnecting after {attempts} attempts");
+					break 'router;
+				}
+				if attempts > 0 {
+					time::sleep(backoff.delay(attempts)).await;
+				}
+				attempts += 1;
 				trace!("Reconnecting...");
 				match WsMeta::connect(&address.endpoint, None).await {
 					Ok((mut meta, stream)) => {
@@ -339,7 +376,6 @@ pub(crate) fn router(
 								Ok(events) => events,
 								Err(error) => {
 									trace!("{error}");
-									time::sleep(Duration::from_secs(1)).await;
 									continue 'reconnect;
 								}
 							}
@@ -347,7 +383,6 @@ pub(crate) fn router(
 						for (_, message) in &replay {
 							if let Err(error) = socket.send(message.clone()).await {
 								trace!("{error}");
-								time::sleep(Duration::from_secs(1)).await;
 								continue 'reconnect;
 							}
 						}
@@ -362,7 +397,6 @@ pub(crate) fn router(
 							trace!("Request {payload}");
 							if let Err(error) = socket.send(Message::Binary(payload.into())).await {
 								trace!("{error}");
-								time::sleep(Duration::from_secs(1)).await;
 								continue 'reconnect;
 							}
 						}
@@ -371,7 +405,6 @@ pub(crate) fn router(
 					}
 					Err(error) => {
 						trace!("Failed to reconnect; {error}");
-						time::sleep(Duration::from_secs(1)).await;
 					}
 				}
 			}
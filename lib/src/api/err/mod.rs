@@ -1,3 +1,4 @@
+use crate::api::opt::Format;
 use crate::api::Response;
 use crate::sql::Array;
 use crate::sql::Edges;
@@ -64,6 +65,16 @@ pub enum Error {
 	#[error("Duplicate request ID: {0}")]
 	DuplicateRequestId(i64),
 
+	/// A request or response body exceeded the configured maximum size
+	#[error("The payload exceeds the maximum size of {max} bytes")]
+	PayloadTooLarge {
+		max: usize,
+	},
+
+	/// The connection to the server was lost before a response was received
+	#[error("The connection to the server was lost before a response was received, the request can be retried")]
+	ConnectionLost,
+
 	/// Invalid request
 	#[error("Invalid request: {0}")]
 	InvalidRequest(String),
@@ -102,6 +113,14 @@ pub enum Error {
 		error: bincode::Error,
 	},
 
+	/// Failed to deserialize a JSON or CBOR response
+	#[error("Failed to deserialize a {format} response: {error}")]
+	ResponseFromFormat {
+		format: Format,
+		bytes: Vec<u8>,
+		error: String,
+	},
+
 	/// Failed to serialize `sql::Value` to JSON string
 	#[error("Failed to serialize `{value}` to JSON string: {error}")]
 	ToJsonString {
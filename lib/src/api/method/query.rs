@@ -17,7 +17,6 @@ use indexmap::IndexMap;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use std::collections::BTreeMap;
-use std::collections::HashMap;
 use std::future::Future;
 use std::future::IntoFuture;
 use std::mem;
@@ -209,8 +208,9 @@ impl Response {
 
 	/// Take all errors from the query response
 	///
-	/// The errors are keyed by the corresponding index of the statement that failed.
-	/// Afterwards the response is left with only statements that did not produce any errors.
+	/// The errors are paired with the corresponding index of the statement that failed,
+	/// in the order of the statements. Afterwards the response is left with only statements
+	/// that did not produce any errors.
 	///
 	/// # Examples
 	///
@@ -225,17 +225,18 @@ impl Response {
 	/// # Ok(())
 	/// # }
 	/// ```
-	pub fn take_errors(&mut self) -> HashMap<usize, crate::Error> {
+	pub fn take_errors(&mut self) -> Vec<(usize, crate::Error)> {
 		let mut keys = Vec::new();
 		for (key, result) in &self.0 {
 			if result.is_err() {
 				keys.push(*key);
 			}
 		}
-		let mut errors = HashMap::with_capacity(keys.len());
+		keys.sort_unstable();
+		let mut errors = Vec::with_capacity(keys.len());
 		for key in keys {
 			if let Some(Err(error)) = self.0.remove(&key) {
-				errors.insert(key, error);
+				errors.push((key, error));
 			}
 		}
 		errors
@@ -513,13 +514,13 @@ mod tests {
 		let errors = response.take_errors();
 		assert_eq!(response.num_statements(), 8);
 		assert_eq!(errors.len(), 3);
-		let crate::Error::Api(Error::DuplicateRequestId(0)) = errors.get(&10).unwrap() else {
+		let (10, crate::Error::Api(Error::DuplicateRequestId(0))) = &errors[2] else {
             panic!("index `10` is not `DuplicateRequestId`");
         };
-		let crate::Error::Api(Error::BackupsNotSupported) = errors.get(&7).unwrap() else {
+		let (7, crate::Error::Api(Error::BackupsNotSupported)) = &errors[1] else {
             panic!("index `7` is not `BackupsNotSupported`");
         };
-		let crate::Error::Api(Error::ConnectionUninitialised) = errors.get(&3).unwrap() else {
+		let (3, crate::Error::Api(Error::ConnectionUninitialised)) = &errors[0] else {
             panic!("index `3` is not `ConnectionUninitialised`");
         };
 		let Some(value): Option<i32> = response.take(2).unwrap() else {
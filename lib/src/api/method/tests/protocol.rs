@@ -37,6 +37,8 @@ impl IntoEndpoint<Test> for () {
 			auth: Level::No,
 			username: String::new(),
 			password: String::new(),
+			ns: None,
+			db: None,
 		})
 	}
 }
@@ -91,8 +91,11 @@ where
 
 	fn into_future(self) -> Self::IntoFuture {
 		Box::pin(async move {
-			let client = Client::connect(self.address?, self.capacity).await?;
+			let address = self.address?;
+			let (ns, db) = (address.ns.clone(), address.db.clone());
+			let client = Client::connect(address, self.capacity).await?;
 			client.check_server_version();
+			client.use_ns_db(ns, db).await?;
 			Ok(client)
 		})
 	}
@@ -109,9 +112,11 @@ where
 		Box::pin(async move {
 			match self.router {
 				Some(router) => {
-					let option =
-						Client::connect(self.address?, self.capacity).await?.router.into_inner();
-					match option {
+					let address = self.address?;
+					let (ns, db) = (address.ns.clone(), address.db.clone());
+					let client = Client::connect(address, self.capacity).await?;
+					client.use_ns_db(ns, db).await?;
+					match client.router.into_inner() {
 						Some(client) => {
 							let _res = router.set(client);
 						}
@@ -140,6 +145,16 @@ impl<C> Surreal<C>
 where
 	C: Connection,
 {
+	/// Switches to the namespace and database which were set on the endpoint
+	async fn use_ns_db(&self, ns: Option<String>, db: Option<String>) -> Result<()> {
+		match (ns, db) {
+			(Some(ns), Some(db)) => self.use_ns(ns).use_db(db).await,
+			(Some(ns), None) => self.use_ns(ns).await,
+			(None, Some(db)) => self.use_db(db).await,
+			(None, None) => Ok(()),
+		}
+	}
+
 	fn check_server_version(&self) {
 		let conn = self.clone();
 		spawn(async move {
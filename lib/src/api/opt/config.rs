@@ -1,6 +1,9 @@
+use std::fmt;
 use std::time::Duration;
 
-/// Configuration for server connection, including: strictness, notifications, query_timeout, transaction_timeout
+/// Configuration for server connection, including: strictness, notifications, query_timeout, transaction_timeout,
+/// the maximum request and response sizes of the remote engines, the WebSocket reconnection policy,
+/// and the format of the responses requested by the HTTP engine
 #[cfg(any(
 	feature = "kv-mem",
 	feature = "kv-tikv",
@@ -15,6 +18,10 @@ pub struct Config {
 	pub(crate) notifications: bool,
 	pub(crate) query_timeout: Option<Duration>,
 	pub(crate) transaction_timeout: Option<Duration>,
+	pub(crate) max_request_size: Option<usize>,
+	pub(crate) max_response_size: Option<usize>,
+	pub(crate) reconnect: Option<Backoff>,
+	pub(crate) format: Format,
 }
 #[cfg(not(any(
 	feature = "kv-mem",
@@ -25,7 +32,140 @@ pub struct Config {
 	feature = "kv-indxdb",
 )))]
 #[derive(Debug, Default)]
-pub struct Config();
+pub struct Config {
+	pub(crate) max_request_size: Option<usize>,
+	pub(crate) max_response_size: Option<usize>,
+	pub(crate) reconnect: Option<Backoff>,
+	pub(crate) format: Format,
+}
+
+impl Config {
+	///Create a default config that can be modified to configure a connection
+	pub fn new() -> Self {
+		Default::default()
+	}
+
+	///Set the maximum size, in bytes, of a request sent by a remote engine
+	pub fn max_request_size(mut self, size: impl Into<Option<usize>>) -> Self {
+		self.max_request_size = size.into();
+		self
+	}
+
+	///Set the maximum size, in bytes, of a response received by a remote engine
+	pub fn max_response_size(mut self, size: impl Into<Option<usize>>) -> Self {
+		self.max_response_size = size.into();
+		self
+	}
+
+	///Set the policy used by the WebSocket engine to reconnect after the connection drops
+	pub fn reconnect(mut self, backoff: impl Into<Option<Backoff>>) -> Self {
+		self.reconnect = backoff.into();
+		self
+	}
+
+	///Set the format of the responses requested by the HTTP engine
+	pub fn format(mut self, format: Format) -> Self {
+		self.format = format;
+		self
+	}
+}
+
+/// The format in which the HTTP engine asks the server to encode its responses
+///
+/// By default, responses are requested in the binary format, which preserves every SurrealQL type.
+/// The JSON and CBOR formats are more widely supported, but record IDs, datetimes and other
+/// SurrealQL types are received as plain values.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Format {
+	/// The native binary format
+	#[default]
+	Binary,
+	/// JSON, as described in RFC 8259
+	Json,
+	/// CBOR, as described in RFC 8949
+	Cbor,
+}
+
+impl Format {
+	/// Returns the MIME type used in the `Accept` header for this format
+	#[allow(dead_code)]
+	pub(crate) fn mime(&self) -> &'static str {
+		match self {
+			Self::Binary => "application/surrealdb",
+			Self::Json => "application/json",
+			Self::Cbor => "application/cbor",
+		}
+	}
+}
+
+impl fmt::Display for Format {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Binary => f.write_str("binary"),
+			Self::Json => f.write_str("JSON"),
+			Self::Cbor => f.write_str("CBOR"),
+		}
+	}
+}
+
+/// A policy for reconnecting to the server, backing off exponentially between attempts
+///
+/// By default, the WebSocket engine retries indefinitely, waiting one second between attempts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Backoff {
+	pub(crate) initial_delay: Duration,
+	pub(crate) max_delay: Duration,
+	pub(crate) max_attempts: Option<u32>,
+}
+
+impl Default for Backoff {
+	fn default() -> Self {
+		Self {
+			initial_delay: Duration::from_secs(1),
+			max_delay: Duration::from_secs(1),
+			max_attempts: None,
+		}
+	}
+}
+
+impl Backoff {
+	///Create a default reconnection policy that can be modified
+	pub fn new() -> Self {
+		Default::default()
+	}
+
+	///Set the delay after the first failed attempt, which doubles after each subsequent attempt
+	pub fn initial_delay(mut self, delay: Duration) -> Self {
+		self.initial_delay = delay;
+		self
+	}
+
+	///Set the maximum delay between attempts
+	pub fn max_delay(mut self, delay: Duration) -> Self {
+		self.max_delay = delay;
+		self
+	}
+
+	///Set the maximum number of attempts before giving up, or `None` to retry indefinitely
+	pub fn max_attempts(mut self, attempts: impl Into<Option<u32>>) -> Self {
+		self.max_attempts = attempts.into();
+		self
+	}
+
+	/// Returns the delay to wait after the specified number of failed attempts
+	#[allow(dead_code)]
+	pub(crate) fn delay(&self, attempt: u32) -> Duration {
+		let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+		self.initial_delay.saturating_mul(factor).min(self.max_delay)
+	}
+
+	/// Checks whether another attempt is allowed after the specified number of failed attempts
+	#[allow(dead_code)]
+	pub(crate) fn exhausted(&self, attempts: u32) -> bool {
+		self.max_attempts.map_or(false, |max| attempts >= max)
+	}
+}
 
 #[cfg(any(
 	feature = "kv-mem",
@@ -36,11 +176,6 @@ pub struct Config();
 	feature = "kv-indxdb",
 ))]
 impl Config {
-	///Create a default config that can be modified to configure a connection
-	pub fn new() -> Self {
-		Default::default()
-	}
-
 	///Set the strict value of the config to the supplied value
 	pub fn set_strict(mut self, strict: bool) -> Self {
 		self.strict = strict;
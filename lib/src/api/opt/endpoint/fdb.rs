@@ -1,6 +1,5 @@
 use crate::api::engine::local::Db;
 use crate::api::engine::local::FDb;
-use crate::api::err::Error;
 use crate::api::opt::auth::Root;
 use crate::api::opt::Config;
 use crate::api::opt::Endpoint;
@@ -9,21 +8,21 @@ use crate::api::opt::Strict;
 use crate::api::Result;
 use crate::dbs::Level;
 use std::path::Path;
-use url::Url;
 
 impl IntoEndpoint<FDb> for &str {
 	type Client = Db;
 
 	fn into_endpoint(self) -> Result<Endpoint> {
-		let url = format!("fdb://{self}");
 		Ok(Endpoint {
-			endpoint: Url::parse(&url).map_err(|_| Error::InvalidUrl(url))?,
+			endpoint: super::into_url("fdb", self)?,
 			config: Default::default(),
 			#[cfg(any(feature = "native-tls", feature = "rustls"))]
 			tls_config: None,
 			auth: Level::No,
 			username: String::new(),
 			password: String::new(),
+			ns: None,
+			db: None,
 		})
 	}
 }
@@ -2,12 +2,14 @@ use crate::api::engine::remote::http::Client;
 use crate::api::engine::remote::http::Http;
 use crate::api::engine::remote::http::Https;
 use crate::api::err::Error;
+use crate::api::opt::Config;
 use crate::api::opt::IntoEndpoint;
 #[cfg(any(feature = "native-tls", feature = "rustls"))]
 use crate::api::opt::Tls;
 use crate::api::Endpoint;
 use crate::api::Result;
 use crate::dbs::Level;
+use crate::opt::auth::Root;
 use std::net::SocketAddr;
 use url::Url;
 
@@ -15,15 +17,16 @@ impl IntoEndpoint<Http> for &str {
 	type Client = Client;
 
 	fn into_endpoint(self) -> Result<Endpoint> {
-		let url = format!("http://{self}");
 		Ok(Endpoint {
-			endpoint: Url::parse(&url).map_err(|_| Error::InvalidUrl(url))?,
+			endpoint: super::into_url("http", self)?,
 			config: Default::default(),
 			#[cfg(any(feature = "native-tls", feature = "rustls"))]
 			tls_config: None,
 			auth: Level::No,
 			username: String::new(),
 			password: String::new(),
+			ns: None,
+			db: None,
 		})
 	}
 }
@@ -41,6 +44,8 @@ impl IntoEndpoint<Http> for SocketAddr {
 			auth: Level::No,
 			username: String::new(),
 			password: String::new(),
+			ns: None,
+			db: None,
 		})
 	}
 }
@@ -49,15 +54,16 @@ impl IntoEndpoint<Http> for String {
 	type Client = Client;
 
 	fn into_endpoint(self) -> Result<Endpoint> {
-		let url = format!("http://{self}");
 		Ok(Endpoint {
-			endpoint: Url::parse(&url).map_err(|_| Error::InvalidUrl(url))?,
+			endpoint: super::into_url("http", &self)?,
 			config: Default::default(),
 			#[cfg(any(feature = "native-tls", feature = "rustls"))]
 			tls_config: None,
 			auth: Level::No,
 			username: String::new(),
 			password: String::new(),
+			ns: None,
+			db: None,
 		})
 	}
 }
@@ -66,15 +72,16 @@ impl IntoEndpoint<Https> for &str {
 	type Client = Client;
 
 	fn into_endpoint(self) -> Result<Endpoint> {
-		let url = format!("https://{self}");
 		Ok(Endpoint {
-			endpoint: Url::parse(&url).map_err(|_| Error::InvalidUrl(url))?,
+			endpoint: super::into_url("https", self)?,
 			config: Default::default(),
 			#[cfg(any(feature = "native-tls", feature = "rustls"))]
 			tls_config: None,
 			auth: Level::No,
 			username: String::new(),
 			password: String::new(),
+			ns: None,
+			db: None,
 		})
 	}
 }
@@ -92,6 +99,8 @@ impl IntoEndpoint<Https> for SocketAddr {
 			auth: Level::No,
 			username: String::new(),
 			password: String::new(),
+			ns: None,
+			db: None,
 		})
 	}
 }
@@ -100,19 +109,80 @@ impl IntoEndpoint<Https> for String {
 	type Client = Client;
 
 	fn into_endpoint(self) -> Result<Endpoint> {
-		let url = format!("https://{self}");
 		Ok(Endpoint {
-			endpoint: Url::parse(&url).map_err(|_| Error::InvalidUrl(url))?,
+			endpoint: super::into_url("https", &self)?,
 			config: Default::default(),
 			#[cfg(any(feature = "native-tls", feature = "rustls"))]
 			tls_config: None,
 			auth: Level::No,
 			username: String::new(),
 			password: String::new(),
+			ns: None,
+			db: None,
 		})
 	}
 }
 
+impl<T> IntoEndpoint<Http> for (T, Config)
+where
+	T: IntoEndpoint<Http>,
+{
+	type Client = Client;
+
+	fn into_endpoint(self) -> Result<Endpoint> {
+		let (address, config) = self;
+		let mut endpoint = address.into_endpoint()?;
+		endpoint.config = config;
+		Ok(endpoint)
+	}
+}
+
+impl<T> IntoEndpoint<Https> for (T, Config)
+where
+	T: IntoEndpoint<Https>,
+{
+	type Client = Client;
+
+	fn into_endpoint(self) -> Result<Endpoint> {
+		let (address, config) = self;
+		let mut endpoint = address.into_endpoint()?;
+		endpoint.config = config;
+		Ok(endpoint)
+	}
+}
+
+impl<T> IntoEndpoint<Http> for (T, Root<'_>)
+where
+	T: IntoEndpoint<Http>,
+{
+	type Client = Client;
+
+	fn into_endpoint(self) -> Result<Endpoint> {
+		let (address, root) = self;
+		let mut endpoint = address.into_endpoint()?;
+		endpoint.auth = Level::Kv;
+		endpoint.username = root.username.to_owned();
+		endpoint.password = root.password.to_owned();
+		Ok(endpoint)
+	}
+}
+
+impl<T> IntoEndpoint<Https> for (T, Root<'_>)
+where
+	T: IntoEndpoint<Https>,
+{
+	type Client = Client;
+
+	fn into_endpoint(self) -> Result<Endpoint> {
+		let (address, root) = self;
+		let mut endpoint = address.into_endpoint()?;
+		endpoint.auth = Level::Kv;
+		endpoint.username = root.username.to_owned();
+		endpoint.password = root.password.to_owned();
+		Ok(endpoint)
+	}
+}
+
 #[cfg(feature = "native-tls")]
 #[cfg_attr(docsrs, doc(cfg(feature = "native-tls")))]
 impl<T> IntoEndpoint<Https> for (T, native_tls::TlsConnector)
@@ -144,3 +214,48 @@ where
 		Ok(endpoint)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn scheme_prefixed_only_when_missing() {
+		let endpoint = IntoEndpoint::<Http>::into_endpoint("localhost:8000").unwrap();
+		assert_eq!(endpoint.endpoint.as_str(), "http://localhost:8000/");
+		let endpoint = IntoEndpoint::<Http>::into_endpoint("http://localhost:8000").unwrap();
+		assert_eq!(endpoint.endpoint.as_str(), "http://localhost:8000/");
+		let endpoint =
+			IntoEndpoint::<Https>::into_endpoint("https://localhost:8000".to_owned()).unwrap();
+		assert_eq!(endpoint.endpoint.as_str(), "https://localhost:8000/");
+	}
+
+	#[test]
+	fn mismatched_scheme_rejected() {
+		let error = IntoEndpoint::<Http>::into_endpoint("https://localhost:8000").unwrap_err();
+		assert!(
+			matches!(error, crate::Error::Api(Error::InvalidUrl(ref url)) if url == "https://localhost:8000"),
+			"{error:?}"
+		);
+		let error = IntoEndpoint::<Https>::into_endpoint("ws://localhost:8000").unwrap_err();
+		assert!(matches!(error, crate::Error::Api(Error::InvalidUrl(..))), "{error:?}");
+	}
+
+	#[test]
+	fn root_credentials_set_on_endpoint() {
+		let root = Root {
+			username: "root",
+			password: "secret",
+		};
+		let endpoint = IntoEndpoint::<Http>::into_endpoint(("127.0.0.1:8000", root)).unwrap();
+		assert_eq!(endpoint.endpoint.as_str(), "http://127.0.0.1:8000/");
+		assert_eq!(endpoint.auth, Level::Kv);
+		assert_eq!(endpoint.username, "root");
+		assert_eq!(endpoint.password, "secret");
+		let endpoint = IntoEndpoint::<Https>::into_endpoint(("127.0.0.1:8000", root)).unwrap();
+		assert_eq!(endpoint.endpoint.as_str(), "https://127.0.0.1:8000/");
+		assert_eq!(endpoint.auth, Level::Kv);
+		assert_eq!(endpoint.username, "root");
+		assert_eq!(endpoint.password, "secret");
+	}
+}
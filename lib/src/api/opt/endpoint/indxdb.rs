@@ -1,6 +1,5 @@
 use crate::api::engine::local::Db;
 use crate::api::engine::local::IndxDb;
-use crate::api::err::Error;
 use crate::api::opt::auth::Root;
 use crate::api::opt::Config;
 use crate::api::opt::Endpoint;
@@ -8,21 +7,21 @@ use crate::api::opt::IntoEndpoint;
 use crate::api::opt::Strict;
 use crate::api::Result;
 use crate::dbs::Level;
-use url::Url;
 
 impl IntoEndpoint<IndxDb> for &str {
 	type Client = Db;
 
 	fn into_endpoint(self) -> Result<Endpoint> {
-		let url = format!("indxdb://{self}");
 		Ok(Endpoint {
-			endpoint: Url::parse(&url).map_err(|_| Error::InvalidUrl(url))?,
+			endpoint: super::into_url("indxdb", self)?,
 			config: Default::default(),
 			#[cfg(any(feature = "native-tls", feature = "rustls"))]
 			tls_config: None,
 			auth: Level::No,
 			username: String::new(),
 			password: String::new(),
+			ns: None,
+			db: None,
 		})
 	}
 }
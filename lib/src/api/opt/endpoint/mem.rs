@@ -21,6 +21,8 @@ impl IntoEndpoint<Mem> for () {
 			auth: Level::No,
 			username: String::new(),
 			password: String::new(),
+			ns: None,
+			db: None,
 		})
 	}
 }
@@ -16,6 +16,7 @@ mod speedb;
 #[cfg(feature = "kv-tikv")]
 mod tikv;
 
+use crate::api::err::Error;
 use crate::api::Connection;
 use crate::api::Result;
 use crate::dbs::Level;
@@ -28,15 +29,18 @@ use super::Config;
 #[allow(dead_code)] // used by the embedded and remote connections
 pub struct Endpoint {
 	pub(crate) endpoint: Url,
-	#[allow(dead_code)] // used by the embedded database
+	#[allow(dead_code)] // used by the embedded database and the remote engines
 	pub(crate) config: Config,
 	#[cfg(any(feature = "native-tls", feature = "rustls"))]
 	pub(crate) tls_config: Option<super::Tls>,
-	// Only used by the local engines
+	// Only used by the local engines and the HTTP engine
 	// `Level::No` in this context means no authentication information was configured
 	pub(crate) auth: Level,
 	pub(crate) username: String,
 	pub(crate) password: String,
+	// The namespace and database to switch to once connected
+	pub(crate) ns: Option<String>,
+	pub(crate) db: Option<String>,
 }
 
 /// A trait for converting inputs to a server address object
@@ -46,3 +50,83 @@ pub trait IntoEndpoint<Scheme> {
 	/// Converts an input into a server address object
 	fn into_endpoint(self) -> Result<Endpoint>;
 }
+
+/// Parses an address into a URL, prefixing it with the scheme unless it already has one
+///
+/// An address with a scheme other than the one expected by the engine is rejected.
+#[allow(dead_code)] // used by the engine specific implementations
+fn into_url(scheme: &str, address: &str) -> Result<Url> {
+	let url = match address.split_once("://") {
+		Some((prefix, _)) if prefix.eq_ignore_ascii_case(scheme) => address.to_owned(),
+		Some(_) => return Err(Error::InvalidUrl(address.to_owned()).into()),
+		None => format!("{scheme}://{address}"),
+	};
+	Url::parse(&url).map_err(|_| Error::InvalidUrl(url).into())
+}
+
+/// The namespace and database to switch to as soon as a connection is established
+///
+/// # Examples
+///
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() -> surrealdb::Result<()> {
+/// use surrealdb::engine::remote::ws::Ws;
+/// use surrealdb::opt::Use;
+/// use surrealdb::Surreal;
+///
+/// let db = Surreal::new::<Ws>(("localhost:8000", Use::ns("namespace").db("database"))).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Use {
+	pub(crate) ns: Option<String>,
+	pub(crate) db: Option<String>,
+}
+
+impl Use {
+	/// Switch to a specific namespace once connected
+	pub fn ns(ns: impl Into<String>) -> Self {
+		Self {
+			ns: Some(ns.into()),
+			db: None,
+		}
+	}
+
+	/// Switch to a specific database once connected
+	pub fn db(mut self, db: impl Into<String>) -> Self {
+		self.db = Some(db.into());
+		self
+	}
+}
+
+impl<Scheme, T> IntoEndpoint<Scheme> for (T, Use)
+where
+	T: IntoEndpoint<Scheme>,
+{
+	type Client = T::Client;
+
+	fn into_endpoint(self) -> Result<Endpoint> {
+		let (address, target) = self;
+		let mut endpoint = address.into_endpoint()?;
+		endpoint.ns = target.ns;
+		endpoint.db = target.db;
+		Ok(endpoint)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn into_url_with_and_without_scheme() {
+		let url = into_url("speedb", "/tmp/test.db").unwrap();
+		assert_eq!(url.as_str(), "speedb:///tmp/test.db");
+		let url = into_url("speedb", "speedb:///tmp/test.db").unwrap();
+		assert_eq!(url.as_str(), "speedb:///tmp/test.db");
+		let error = into_url("speedb", "rocksdb:///tmp/test.db").unwrap_err();
+		assert!(matches!(error, crate::Error::Api(Error::InvalidUrl(..))), "{error:?}");
+	}
+}
@@ -1,7 +1,6 @@
 use crate::api::engine::local::Db;
 use crate::api::engine::local::File;
 use crate::api::engine::local::RocksDb;
-use crate::api::err::Error;
 use crate::api::opt::auth::Root;
 use crate::api::opt::Config;
 use crate::api::opt::Endpoint;
@@ -10,21 +9,21 @@ use crate::api::opt::Strict;
 use crate::api::Result;
 use crate::dbs::Level;
 use std::path::Path;
-use url::Url;
 
 impl IntoEndpoint<RocksDb> for &str {
 	type Client = Db;
 
 	fn into_endpoint(self) -> Result<Endpoint> {
-		let url = format!("rocksdb://{self}");
 		Ok(Endpoint {
-			endpoint: Url::parse(&url).map_err(|_| Error::InvalidUrl(url))?,
+			endpoint: super::into_url("rocksdb", self)?,
 			config: Default::default(),
 			#[cfg(any(feature = "native-tls", feature = "rustls"))]
 			tls_config: None,
 			auth: Level::No,
 			username: String::new(),
 			password: String::new(),
+			ns: None,
+			db: None,
 		})
 	}
 }
@@ -114,15 +113,16 @@ impl IntoEndpoint<File> for &str {
 	type Client = Db;
 
 	fn into_endpoint(self) -> Result<Endpoint> {
-		let url = format!("file://{self}");
 		Ok(Endpoint {
-			endpoint: Url::parse(&url).map_err(|_| Error::InvalidUrl(url))?,
+			endpoint: super::into_url("file", self)?,
 			config: Default::default(),
 			#[cfg(any(feature = "native-tls", feature = "rustls"))]
 			tls_config: None,
 			auth: Level::No,
 			username: String::new(),
 			password: String::new(),
+			ns: None,
+			db: None,
 		})
 	}
 }
@@ -1,6 +1,5 @@
 use crate::api::engine::local::Db;
 use crate::api::engine::local::SpeeDb;
-use crate::api::err::Error;
 use crate::api::opt::Config;
 use crate::api::opt::Endpoint;
 use crate::api::opt::IntoEndpoint;
@@ -9,21 +8,21 @@ use crate::api::Result;
 use crate::dbs::Level;
 use crate::opt::auth::Root;
 use std::path::Path;
-use url::Url;
 
 impl IntoEndpoint<SpeeDb> for &str {
 	type Client = Db;
 
 	fn into_endpoint(self) -> Result<Endpoint> {
-		let url = format!("speedb://{self}");
 		Ok(Endpoint {
-			endpoint: Url::parse(&url).map_err(|_| Error::InvalidUrl(url))?,
+			endpoint: super::into_url("speedb", self)?,
 			config: Default::default(),
 			#[cfg(any(feature = "native-tls", feature = "rustls"))]
 			tls_config: None,
 			auth: Level::No,
 			username: String::new(),
 			password: String::new(),
+			ns: None,
+			db: None,
 		})
 	}
 }
@@ -2,6 +2,7 @@ use crate::api::engine::remote::ws::Client;
 use crate::api::engine::remote::ws::Ws;
 use crate::api::engine::remote::ws::Wss;
 use crate::api::err::Error;
+use crate::api::opt::Config;
 use crate::api::opt::Endpoint;
 use crate::api::opt::IntoEndpoint;
 #[cfg(any(feature = "native-tls", feature = "rustls"))]
@@ -15,15 +16,16 @@ impl IntoEndpoint<Ws> for &str {
 	type Client = Client;
 
 	fn into_endpoint(self) -> Result<Endpoint> {
-		let url = format!("ws://{self}");
 		Ok(Endpoint {
-			endpoint: Url::parse(&url).map_err(|_| Error::InvalidUrl(url))?,
+			endpoint: super::into_url("ws", self)?,
 			config: Default::default(),
 			#[cfg(any(feature = "native-tls", feature = "rustls"))]
 			tls_config: None,
 			auth: Level::No,
 			username: String::new(),
 			password: String::new(),
+			ns: None,
+			db: None,
 		})
 	}
 }
@@ -41,6 +43,8 @@ impl IntoEndpoint<Ws> for SocketAddr {
 			auth: Level::No,
 			username: String::new(),
 			password: String::new(),
+			ns: None,
+			db: None,
 		})
 	}
 }
@@ -49,15 +53,16 @@ impl IntoEndpoint<Ws> for String {
 	type Client = Client;
 
 	fn into_endpoint(self) -> Result<Endpoint> {
-		let url = format!("ws://{self}");
 		Ok(Endpoint {
-			endpoint: Url::parse(&url).map_err(|_| Error::InvalidUrl(url))?,
+			endpoint: super::into_url("ws", &self)?,
 			config: Default::default(),
 			#[cfg(any(feature = "native-tls", feature = "rustls"))]
 			tls_config: None,
 			auth: Level::No,
 			username: String::new(),
 			password: String::new(),
+			ns: None,
+			db: None,
 		})
 	}
 }
@@ -66,15 +71,16 @@ impl IntoEndpoint<Wss> for &str {
 	type Client = Client;
 
 	fn into_endpoint(self) -> Result<Endpoint> {
-		let url = format!("wss://{self}");
 		Ok(Endpoint {
-			endpoint: Url::parse(&url).map_err(|_| Error::InvalidUrl(url))?,
+			endpoint: super::into_url("wss", self)?,
 			config: Default::default(),
 			#[cfg(any(feature = "native-tls", feature = "rustls"))]
 			tls_config: None,
 			auth: Level::No,
 			username: String::new(),
 			password: String::new(),
+			ns: None,
+			db: None,
 		})
 	}
 }
@@ -92,6 +98,8 @@ impl IntoEndpoint<Wss> for SocketAddr {
 			auth: Level::No,
 			username: String::new(),
 			password: String::new(),
+			ns: None,
+			db: None,
 		})
 	}
 }
@@ -100,19 +108,48 @@ impl IntoEndpoint<Wss> for String {
 	type Client = Client;
 
 	fn into_endpoint(self) -> Result<Endpoint> {
-		let url = format!("wss://{self}");
 		Ok(Endpoint {
-			endpoint: Url::parse(&url).map_err(|_| Error::InvalidUrl(url))?,
+			endpoint: super::into_url("wss", &self)?,
 			config: Default::default(),
 			#[cfg(any(feature = "native-tls", feature = "rustls"))]
 			tls_config: None,
 			auth: Level::No,
 			username: String::new(),
 			password: String::new(),
+			ns: None,
+			db: None,
 		})
 	}
 }
 
+impl<T> IntoEndpoint<Ws> for (T, Config)
+where
+	T: IntoEndpoint<Ws>,
+{
+	type Client = Client;
+
+	fn into_endpoint(self) -> Result<Endpoint> {
+		let (address, config) = self;
+		let mut endpoint = address.into_endpoint()?;
+		endpoint.config = config;
+		Ok(endpoint)
+	}
+}
+
+impl<T> IntoEndpoint<Wss> for (T, Config)
+where
+	T: IntoEndpoint<Wss>,
+{
+	type Client = Client;
+
+	fn into_endpoint(self) -> Result<Endpoint> {
+		let (address, config) = self;
+		let mut endpoint = address.into_endpoint()?;
+		endpoint.config = config;
+		Ok(endpoint)
+	}
+}
+
 #[cfg(feature = "native-tls")]
 #[cfg_attr(docsrs, doc(cfg(feature = "native-tls")))]
 impl<T> IntoEndpoint<Wss> for (T, native_tls::TlsConnector)
@@ -151,7 +151,9 @@ impl From<Value> for serde_json::Value {
 
 fn into_json(value: Value, simplify: bool) -> JsonValue {
 	use crate::sql;
+	use crate::sql::number::decimal_is_integer;
 	use crate::sql::Number;
+	use rust_decimal::prelude::ToPrimitive;
 
 	#[derive(Serialize)]
 	struct Array(Vec<JsonValue>);
@@ -353,6 +355,14 @@ fn into_json(value: Value, simplify: bool) -> JsonValue {
 		Value::Number(number) => match number {
 			Number::Int(int) => int.into(),
 			Number::Float(float) => float.into(),
+			// Integers which fit in a JSON number are returned as numbers
+			Number::Decimal(decimal) if decimal_is_integer(&decimal) => {
+				match (decimal.to_i64(), decimal.to_u64()) {
+					(Some(int), _) => int.into(),
+					(None, Some(int)) => int.into(),
+					(None, None) => json!(decimal),
+				}
+			}
 			Number::Decimal(decimal) => json!(decimal),
 		},
 		Value::Strand(strand) => strand.0.into(),
@@ -437,6 +447,7 @@ mod tests {
 		use geo::MultiPolygon;
 		use geo::Point;
 		use geo::Polygon;
+		use rust_decimal::prelude::ToPrimitive;
 		use rust_decimal::Decimal;
 		use serde_json::json;
 		use std::collections::BTreeMap;
@@ -517,10 +528,22 @@ mod tests {
 				let value = Value::Number(sql::Number::Decimal(num));
 
 				let simple_json = into_json(value.clone(), true);
-				assert_eq!(simple_json, json!(num.to_string()));
+				assert_eq!(simple_json, json!(num.to_i64().unwrap()));
 
 				let json = into_json(value.clone(), false);
-				assert_eq!(json, json!(num));
+				assert_eq!(json, json!(num.to_i64().unwrap()));
+
+				let response: Decimal = from_value(value).unwrap();
+				assert_eq!(response, num);
+			}
+			for num in [Decimal::from(u64::MAX), Decimal::MAX, Decimal::new(15, 1)] {
+				let value = Value::Number(sql::Number::Decimal(num));
+
+				let simple_json = into_json(value.clone(), true);
+				match num.to_u64() {
+					Some(int) if num.fract().is_zero() => assert_eq!(simple_json, json!(int)),
+					_ => assert_eq!(simple_json, json!(num)),
+				}
 
 				let response: Decimal = from_value(value).unwrap();
 				assert_eq!(response, num);
@@ -0,0 +1,94 @@
+use crate::cf::TableMutation;
+use crate::sql::object::Object;
+use crate::sql::thing::Thing;
+use crate::sql::value::Value;
+use crate::vs::Versionstamp;
+use std::collections::HashMap;
+
+/// The kind of change which was made to a record
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum ChangeKind {
+	Create,
+	Update,
+	Delete,
+}
+
+/// A change which was made to a record in a table with a change feed
+#[derive(Clone, Debug, PartialEq)]
+pub struct Change {
+	/// The versionstamp of the transaction which made the change
+	pub versionstamp: Versionstamp,
+	/// The kind of change which was made
+	pub kind: ChangeKind,
+	/// The id of the record which was changed
+	pub id: Thing,
+	/// The record after the change, or `NONE` if the record was deleted
+	pub value: Value,
+	/// The JSON Patch operations which transform the previous record into this one
+	pub diff: Value,
+}
+
+// Converts the change feed of a table into individual record changes.
+//
+// The table mutations must be pushed in order from the beginning of the retained
+// change feed, so that it is possible to tell whether each record was created or
+// updated. A record which was last changed before the oldest retained change set
+// is therefore reported as being created on its first retained change.
+pub(crate) struct Changes {
+	since: Option<Versionstamp>,
+	limit: usize,
+	previous: HashMap<Thing, Value>,
+	out: Vec<Change>,
+}
+
+impl Changes {
+	pub(crate) fn new(since: Option<Versionstamp>, limit: usize) -> Self {
+		Changes {
+			since,
+			limit,
+			previous: HashMap::new(),
+			out: Vec::new(),
+		}
+	}
+
+	// Whether the limit on the number of changes has been reached
+	pub(crate) fn is_full(&self) -> bool {
+		self.out.len() >= self.limit
+	}
+
+	// Converts the mutations made to the table in a single change set
+	pub(crate) fn push(&mut self, versionstamp: Versionstamp, mutations: Vec<TableMutation>) {
+		for mutation in mutations {
+			let (id, kind, value) = match mutation {
+				TableMutation::Set(id, value) => match self.previous.contains_key(&id) {
+					true => (id, ChangeKind::Update, value),
+					false => (id, ChangeKind::Create, value),
+				},
+				TableMutation::Del(id) => (id, ChangeKind::Delete, Value::None),
+			};
+			let before = match kind {
+				ChangeKind::Delete => self.previous.remove(&id),
+				_ => self.previous.insert(id.clone(), value.clone()),
+			};
+			if matches!(self.since, Some(since) if versionstamp < since) {
+				continue;
+			}
+			if self.is_full() {
+				return;
+			}
+			let before = before.unwrap_or_else(|| Value::Object(Object::default()));
+			let diff = before.diff(&value).into();
+			self.out.push(Change {
+				versionstamp,
+				kind,
+				id,
+				value,
+				diff,
+			});
+		}
+	}
+
+	pub(crate) fn finish(self) -> Vec<Change> {
+		self.out
+	}
+}
@@ -1,4 +1,5 @@
 use once_cell::sync::Lazy;
+use std::time::Duration;
 
 #[cfg(not(target_arch = "wasm32"))]
 #[allow(dead_code)]
@@ -12,6 +13,28 @@ pub static MAX_COMPUTATION_DEPTH: Lazy<u8> = Lazy::new(|| {
 	option_env!("SURREAL_MAX_COMPUTATION_DEPTH").and_then(|s| s.parse::<u8>().ok()).unwrap_or(120)
 });
 
+/// Specifies the default memory limit in bytes for each embedded script function.
+pub const SCRIPT_MEMORY_LIMIT: usize = 2_000_000;
+
+/// Specifies the maximum number of console log lines captured from embedded script functions for each statement.
+pub const SCRIPT_LOG_LIMIT: usize = 1_000;
+
+/// Specifies the maximum number of bytes of each console log line captured from embedded script functions.
+pub const SCRIPT_LOG_LINE_LIMIT: usize = 10_000;
+
+/// Specifies the maximum number of datetimes which can be generated by the time::interval function.
+pub const TIME_INTERVAL_LIMIT: usize = 100_000;
+
+/// Specifies how long to wait before retrying a transaction which conflicted with another transaction.
+/// The delay is doubled for each further attempt, up to [`TRANSACTION_RETRY_BACKOFF_LIMIT`].
+pub const TRANSACTION_RETRY_BACKOFF: Duration = Duration::from_millis(10);
+
+/// Specifies the longest time to wait between attempts when retrying a conflicting transaction.
+pub const TRANSACTION_RETRY_BACKOFF_LIMIT: Duration = Duration::from_secs(1);
+
+/// Specifies how often a datastore which is shutting down checks whether its transactions have finished.
+pub const SHUTDOWN_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
 /// Specifies the names of parameters which can not be specified in a query.
 pub const PROTECTED_PARAM_NAMES: &[&str] = &["auth", "scope", "token", "session"];
 
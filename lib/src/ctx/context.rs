@@ -36,6 +36,14 @@ pub struct Context<'a> {
 	notifications: Option<Sender<Notification>>,
 	// An optional query planner
 	query_planner: Option<&'a QueryPlanner<'a>>,
+	// An optional memory limit for embedded script functions
+	script_memory_limit: Option<usize>,
+	// An optional execution timeout for embedded script functions
+	script_timeout: Option<Duration>,
+	// The hosts which embedded script functions are allowed to fetch from
+	script_fetch_allowlist: Option<Arc<[String]>>,
+	// Stores the channel for console output from embedded script functions
+	script_logs: Option<Sender<String>>,
 }
 
 impl<'a> Default for Context<'a> {
@@ -65,6 +73,10 @@ impl<'a> Context<'a> {
 			cancelled: Arc::new(AtomicBool::new(false)),
 			notifications: None,
 			query_planner: None,
+			script_memory_limit: None,
+			script_timeout: None,
+			script_fetch_allowlist: None,
+			script_logs: None,
 		}
 	}
 
@@ -77,6 +89,10 @@ impl<'a> Context<'a> {
 			cancelled: Arc::new(AtomicBool::new(false)),
 			notifications: parent.notifications.clone(),
 			query_planner: parent.query_planner,
+			script_memory_limit: parent.script_memory_limit,
+			script_timeout: parent.script_timeout,
+			script_fetch_allowlist: parent.script_fetch_allowlist.clone(),
+			script_logs: parent.script_logs.clone(),
 		}
 	}
 
@@ -118,6 +134,31 @@ impl<'a> Context<'a> {
 		self.notifications = chn.cloned()
 	}
 
+	/// Set the memory limit in bytes for any embedded script
+	/// functions which are run within this context.
+	pub fn add_script_memory_limit(&mut self, limit: usize) {
+		self.script_memory_limit = Some(limit)
+	}
+
+	/// Set the maximum execution time for each embedded script function
+	/// which is run within this context. This is separate from any timeout
+	/// or deadline which is set on the context itself.
+	pub fn add_script_timeout(&mut self, timeout: Duration) {
+		self.script_timeout = Some(timeout)
+	}
+
+	/// Set the hosts which embedded script functions which are
+	/// run within this context are allowed to fetch from.
+	pub fn add_script_fetch_allowlist(&mut self, hosts: Arc<[String]>) {
+		self.script_fetch_allowlist = Some(hosts)
+	}
+
+	/// Add a channel for capturing the console output
+	/// of any embedded script functions.
+	pub fn add_script_logs(&mut self, chn: Option<&Sender<String>>) {
+		self.script_logs = chn.cloned()
+	}
+
 	/// Set the query planner
 	pub(crate) fn set_query_planner(&mut self, qp: &'a QueryPlanner) {
 		self.query_planner = Some(qp);
@@ -133,6 +174,26 @@ impl<'a> Context<'a> {
 		self.notifications.clone()
 	}
 
+	/// Get the memory limit in bytes for embedded script functions.
+	pub fn script_memory_limit(&self) -> usize {
+		self.script_memory_limit.unwrap_or(crate::cnf::SCRIPT_MEMORY_LIMIT)
+	}
+
+	/// Get the maximum execution time for embedded script functions, if any.
+	pub fn script_timeout(&self) -> Option<Duration> {
+		self.script_timeout
+	}
+
+	/// Get the hosts which embedded script functions can fetch from, if any.
+	pub fn script_fetch_allowlist(&self) -> Option<Arc<[String]>> {
+		self.script_fetch_allowlist.clone()
+	}
+
+	/// Get the channel for capturing embedded script console output, if any.
+	pub fn script_logs(&self) -> Option<&Sender<String>> {
+		self.script_logs.as_ref()
+	}
+
 	pub(crate) fn get_query_planner(&self) -> Option<&QueryPlanner> {
 		self.query_planner
 	}
@@ -1,4 +1,4 @@
-use crate::cnf::PROTECTED_PARAM_NAMES;
+use crate::cnf::{PROTECTED_PARAM_NAMES, SCRIPT_LOG_LIMIT};
 use crate::ctx::Context;
 use crate::dbs::response::Response;
 use crate::dbs::Level;
@@ -8,6 +8,7 @@ use crate::dbs::Transaction;
 use crate::dbs::{Auth, QueryType};
 use crate::err::Error;
 use crate::kvs::Datastore;
+use crate::sql::explain::Explain;
 use crate::sql::paths::DB;
 use crate::sql::paths::NS;
 use crate::sql::query::Query;
@@ -16,11 +17,15 @@ use crate::sql::value::Value;
 use channel::Receiver;
 use futures::lock::Mutex;
 use std::sync::Arc;
+use std::time::Duration;
 use tracing::instrument;
 use trice::Instant;
 
 pub(crate) struct Executor<'a> {
 	err: bool,
+	stats: bool,
+	explain: bool,
+	timeout: Option<Duration>,
 	kvs: &'a Datastore,
 	txn: Option<Transaction>,
 }
@@ -31,9 +36,30 @@ impl<'a> Executor<'a> {
 			kvs,
 			txn: None,
 			err: false,
+			stats: false,
+			explain: false,
+			timeout: None,
 		}
 	}
 
+	/// Record statistics for each statement response
+	pub fn with_stats(mut self, stats: bool) -> Executor<'a> {
+		self.stats = stats;
+		self
+	}
+
+	/// Output the query plan for each SELECT statement
+	pub fn with_explain(mut self, explain: bool) -> Executor<'a> {
+		self.explain = explain;
+		self
+	}
+
+	/// Apply a timeout to each statement without a timeout clause
+	pub fn with_timeout(mut self, timeout: Option<Duration>) -> Executor<'a> {
+		self.timeout = timeout;
+		self
+	}
+
 	fn txn(&self) -> Transaction {
 		self.txn.clone().expect("unreachable: txn was None after successful begin")
 	}
@@ -113,6 +139,8 @@ impl<'a> Executor<'a> {
 		Response {
 			time: v.time,
 			result: Err(Error::QueryCancelled),
+			rows: None,
+			logs: v.logs,
 			query_type: QueryType::Other,
 		}
 	}
@@ -130,6 +158,8 @@ impl<'a> Executor<'a> {
 						.unwrap_or(Error::QueryNotExecuted)),
 					Err(e) => Err(e),
 				},
+				rows: None,
+				logs: v.logs,
 				query_type: QueryType::Other,
 			},
 			_ => v,
@@ -146,13 +176,12 @@ impl<'a> Executor<'a> {
 	/// Flush notifications from a buffer channel (live queries) to the committed notification channel.
 	/// This is because we don't want to broadcast notifications to the user for failed transactions.
 	async fn flush(&self, ctx: &Context<'_>, rcv: Receiver<Notification>) {
-		if let Some(chn) = ctx.notifications() {
-			while let Ok(v) = rcv.try_recv() {
-				let _ = chn.send(v).await;
-			}
-		} else {
-			while rcv.try_recv().is_ok() {
-				// Ignore notification
+		while let Ok(v) = rcv.try_recv() {
+			// Send to the in-process subscriber to this live query
+			if let Some(v) = self.kvs.send_live(v).await {
+				if let Some(chn) = ctx.notifications() {
+					let _ = chn.send(v).await;
+				}
 			}
 		}
 	}
@@ -182,12 +211,26 @@ impl<'a> Executor<'a> {
 		let (send, recv) = channel::unbounded();
 		// Set the notification channel
 		let mut opt = opt.new_with_sender(send);
+		// Create a script console output channel
+		let (log_send, log_recv) = channel::bounded(SCRIPT_LOG_LIMIT);
+		// Set the script console output channel
+		ctx.add_script_logs(Some(&log_send));
 		// Initialise buffer of responses
 		let mut buf: Vec<Response> = vec![];
 		// Initialise array of responses
 		let mut out: Vec<Response> = vec![];
+		// Whether a RETURN statement has ended the current transaction block
+		let mut returned = false;
 		// Process all statements in query
-		for stm in qry.into_iter() {
+		for mut stm in qry.into_iter() {
+			// Skip any statements following a RETURN in a transaction
+			if returned && !matches!(stm, Statement::Commit(_) | Statement::Cancel(_)) {
+				continue;
+			}
+			// Explain the statement if requested
+			if let (true, Statement::Select(stm)) = (self.explain, &mut stm) {
+				stm.explain.get_or_insert(Explain(true));
+			}
 			// Log the statement
 			debug!("Executing: {}", stm);
 			// Reset errors
@@ -231,6 +274,7 @@ impl<'a> Executor<'a> {
 				}
 				// Cancel a running transaction
 				Statement::Cancel(_) => {
+					returned = false;
 					self.cancel(true).await;
 					self.clear(&ctx, recv.clone()).await;
 					buf = buf.into_iter().map(|v| self.buf_cancel(v)).collect();
@@ -241,6 +285,7 @@ impl<'a> Executor<'a> {
 				}
 				// Commit a running transaction
 				Statement::Commit(_) => {
+					returned = false;
 					let commit_error = self.commit(true).await.err();
 					buf = buf.into_iter().map(|v| self.buf_commit(v, &commit_error)).collect();
 					self.flush(&ctx, recv.clone()).await;
@@ -282,7 +327,7 @@ impl<'a> Executor<'a> {
 					Ok(Value::None)
 				}
 				// Process param definition statements
-				Statement::Set(mut stm) => {
+				Statement::Set(stm) => {
 					// Create a transaction
 					let loc = self.begin(stm.writeable()).await;
 					// Check the transaction
@@ -291,23 +336,31 @@ impl<'a> Executor<'a> {
 						true => Err(Error::TxFailure),
 						// The transaction began successfully
 						false => {
-							// Check if the variable is a protected variable
-							let res = match PROTECTED_PARAM_NAMES.contains(&stm.name.as_str()) {
-								// The variable isn't protected and can be stored
-								false => stm.compute(&ctx, &opt, &self.txn(), None).await,
+							// Check if any variable is a protected variable
+							let res = match stm
+								.names()
+								.iter()
+								.find(|v| PROTECTED_PARAM_NAMES.contains(&v.as_str()))
+							{
+								// The variables aren't protected and can be stored
+								None => stm
+									.compute(&ctx, &opt, &self.txn(), None)
+									.await
+									.and_then(|v| stm.bind(v)),
 								// The user tried to set a protected variable
-								true => Err(Error::InvalidParam {
-									// Move the parameter name, as we no longer need it
-									name: std::mem::take(&mut stm.name),
+								Some(name) => Err(Error::InvalidParam {
+									name: name.to_owned(),
 								}),
 							};
 							// Check the statement
 							match res {
-								Ok(val) => {
+								Ok(vars) => {
 									// Check if writeable
 									let writeable = stm.writeable();
-									// Set the parameter
-									ctx.add_value(stm.name, val);
+									// Set the parameters
+									for (name, val) in vars {
+										ctx.add_value(name, val);
+									}
 									// Finalise transaction, returning nothing unless it couldn't commit
 									if writeable {
 										match self.commit(loc).await {
@@ -356,8 +409,8 @@ impl<'a> Executor<'a> {
 							false => {
 								let mut ctx = Context::new(&ctx);
 								// Process the statement
-								let res = match stm.timeout() {
-									// There is a timeout clause
+								let res = match stm.timeout().or(self.timeout) {
+									// There is a timeout clause, or a session timeout
 									Some(timeout) => {
 										// Set statement timeout
 										ctx.add_timeout(timeout);
@@ -369,7 +422,7 @@ impl<'a> Executor<'a> {
 											false => res,
 										}
 									}
-									// There is no timeout clause
+									// There is no timeout
 									None => stm.compute(&ctx, &opt, &self.txn(), None).await,
 								};
 								// Catch global timeout
@@ -404,10 +457,21 @@ impl<'a> Executor<'a> {
 					}
 				},
 			};
+			// Count the returned rows
+			let rows = match (&res, self.stats) {
+				(Ok(Value::Array(v)), true) => Some(v.len()),
+				(Ok(Value::None), true) => Some(0),
+				(Ok(_), true) => Some(1),
+				_ => None,
+			};
+			// Collect any script console output
+			let logs = std::iter::from_fn(|| log_recv.try_recv().ok()).collect();
 			// Produce the response
 			let res = Response {
 				// Get the statement end time
 				time: now.elapsed(),
+				rows,
+				logs,
 				// TODO: Replace with `inspect_err` once stable.
 				result: res.map_err(|e| {
 					// Mark the error.
@@ -424,6 +488,7 @@ impl<'a> Executor<'a> {
 			if self.txn.is_some() {
 				if is_stm_output {
 					buf.clear();
+					returned = true;
 				}
 				buf.push(res);
 			} else {
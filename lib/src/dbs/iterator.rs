@@ -22,6 +22,7 @@ use async_recursion::async_recursion;
 use std::borrow::Cow;
 use std::cmp::Ordering;
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::mem;
 
 pub(crate) enum Iterable {
@@ -67,6 +68,8 @@ pub(crate) struct Iterator {
 	// Iterator output results
 	// TODO: Should be stored on disk / (mmap?)
 	results: Vec<Value>,
+	// Iterator output values already seen, when DISTINCT is specified
+	unique: BTreeSet<Value>,
 	// Iterator input values
 	entries: Vec<Iterable>,
 }
@@ -114,6 +117,8 @@ impl Iterator {
 			self.output_split(ctx, opt, txn, stm).await?;
 			// Process any GROUP clause
 			self.output_group(ctx, opt, txn, stm).await?;
+			// Process any DISTINCT clause
+			self.output_distinct(ctx, opt, txn, stm).await?;
 			// Process any ORDER clause
 			self.output_order(ctx, opt, txn, stm).await?;
 			// Process any START clause
@@ -291,6 +296,23 @@ impl Iterator {
 		Ok(())
 	}
 
+	#[inline]
+	async fn output_distinct(
+		&mut self,
+		_ctx: &Context<'_>,
+		_opt: &Options,
+		_txn: &Transaction,
+		stm: &Statement<'_>,
+	) -> Result<(), Error> {
+		// Results are only de-duplicated here when they
+		// could not be de-duplicated as they were streamed
+		if stm.distinct() && !self.streams_distinct(stm) {
+			let mut unique = BTreeSet::new();
+			self.results.retain(|v| unique.insert(v.clone()));
+		}
+		Ok(())
+	}
+
 	#[inline]
 	async fn output_order(
 		&mut self,
@@ -533,10 +555,19 @@ impl Iterator {
 				self.run.cancel();
 				return;
 			}
-			Ok(v) => self.results.push(v),
+			Ok(v) => {
+				// Skip any duplicate results
+				if stm.distinct() && self.streams_distinct(stm) && !self.unique.insert(v.clone()) {
+					return;
+				}
+				self.results.push(v)
+			}
 		}
 		// Check if we can exit
-		if stm.group().is_none() && stm.order().is_none() {
+		if stm.group().is_none()
+			&& stm.order().is_none()
+			&& (!stm.distinct() || self.streams_distinct(stm))
+		{
 			if let Some(l) = self.limit {
 				if let Some(s) = self.start {
 					if self.results.len() == l + s {
@@ -548,4 +579,11 @@ impl Iterator {
 			}
 		}
 	}
+
+	/// Check if DISTINCT results can be de-duplicated as they are received,
+	/// which is only possible when no later clause changes the result rows
+	#[inline]
+	fn streams_distinct(&self, stm: &Statement<'_>) -> bool {
+		stm.split().is_none() && stm.group().is_none()
+	}
 }
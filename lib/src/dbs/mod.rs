@@ -20,6 +20,7 @@ pub use self::notification::*;
 pub use self::options::*;
 pub use self::response::*;
 pub use self::session::*;
+pub use self::variables::Vars;
 
 pub(crate) use self::executor::*;
 pub(crate) use self::iterator::*;
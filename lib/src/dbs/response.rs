@@ -22,6 +22,10 @@ pub enum QueryType {
 pub struct Response {
 	pub time: Duration,
 	pub result: Result<Value, Error>,
+	/// The number of rows returned by the statement, if statistics are enabled on the session
+	pub rows: Option<usize>,
+	/// The console output from any embedded script functions run by the statement
+	pub logs: Vec<String>,
 	// Record the query type in case processing the response is necessary (such as tracking live queries).
 	pub query_type: QueryType,
 }
@@ -37,6 +41,29 @@ impl Response {
 	}
 }
 
+/// Helpers for working with the responses from a query set
+pub trait Responses {
+	/// Take all errors from the responses, paired with the index of the statement
+	/// which failed, in the order of the statements. The failed responses are left
+	/// in place with a [`Error::QueryNotExecuted`] error, so the index of every
+	/// statement is unchanged, and a failed result is never mistaken for a success.
+	fn take_errors(&mut self) -> Vec<(usize, Error)>;
+}
+
+impl Responses for [Response] {
+	fn take_errors(&mut self) -> Vec<(usize, Error)> {
+		let mut errors = Vec::new();
+		for (i, res) in self.iter_mut().enumerate() {
+			if res.result.is_err() {
+				if let Err(e) = std::mem::replace(&mut res.result, Err(Error::QueryNotExecuted)) {
+					errors.push((i, e));
+				}
+			}
+		}
+		errors
+	}
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub(crate) enum Status {
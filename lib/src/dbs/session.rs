@@ -1,7 +1,12 @@
+use crate::cnf::PROTECTED_PARAM_NAMES;
 use crate::ctx::Context;
 use crate::dbs::Auth;
+use crate::err::Error;
+use crate::sql::kind::Kind;
 use crate::sql::value::Value;
+use std::collections::BTreeMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 /// Specifies the current session information when processing a query.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
@@ -26,6 +31,14 @@ pub struct Session {
 	pub tk: Option<Value>,
 	/// The current scope authentication data
 	pub sd: Option<Value>,
+	/// The variables which are available to every query in this session
+	pub vars: BTreeMap<String, Value>,
+	/// Whether statistics are recorded for each query response
+	pub stats: bool,
+	/// Whether each SELECT statement returns its query plan instead of its results
+	pub explain: bool,
+	/// The timeout for each statement which does not specify its own timeout
+	pub query_timeout: Option<Duration>,
 }
 
 impl Session {
@@ -82,6 +95,97 @@ impl Session {
 		self.db = Some(db.to_owned());
 		self
 	}
+	/// Derive a session which selects a different namespace or database
+	///
+	/// The derived session keeps the authentication and every other setting
+	/// of this session. A `None` keeps the currently selected namespace or
+	/// database, and this session is left unchanged. As with a `USE`
+	/// statement, an error is returned if the authentication of this session
+	/// does not allow the namespace or database to be selected. The scope
+	/// and its authentication data are not kept when a different namespace
+	/// or database is selected.
+	pub fn scoped(&self, ns: Option<&str>, db: Option<&str>) -> Result<Session, Error> {
+		// Check that the namespace can be selected
+		if let Some(ns) = ns {
+			match &*self.au {
+				Auth::No | Auth::Kv => (),
+				Auth::Ns(v) | Auth::Db(v, _) if v == ns => (),
+				_ => {
+					return Err(Error::NsNotAllowed {
+						ns: ns.to_owned(),
+					})
+				}
+			}
+		}
+		// Check that the database can be selected
+		if let Some(db) = db {
+			match &*self.au {
+				Auth::No | Auth::Kv | Auth::Ns(_) => (),
+				Auth::Db(_, v) if v == db => (),
+				_ => {
+					return Err(Error::DbNotAllowed {
+						db: db.to_owned(),
+					})
+				}
+			}
+		}
+		let ns = ns.map(str::to_owned).or_else(|| self.ns.clone());
+		let db = db.map(str::to_owned).or_else(|| self.db.clone());
+		// The scope only applies to the namespace and database it is defined on
+		let unchanged = ns == self.ns && db == self.db;
+		Ok(Session {
+			sc: self.sc.clone().filter(|_| unchanged),
+			tk: self.tk.clone().filter(|_| unchanged),
+			sd: self.sd.clone().filter(|_| unchanged),
+			ns,
+			db,
+			..self.clone()
+		})
+	}
+	/// Enable or disable statistics for each query response
+	pub fn with_stats(mut self, stats: bool) -> Session {
+		self.stats = stats;
+		self
+	}
+	/// Enable or disable query plan output for each SELECT statement
+	///
+	/// When enabled, every top-level SELECT statement is run as if it were
+	/// specified with `EXPLAIN FULL`, returning the iterators which were used
+	/// and the number of records which were fetched, instead of the results.
+	pub fn with_explain(mut self, explain: bool) -> Session {
+		self.explain = explain;
+		self
+	}
+	/// Set a default timeout for the statements in each query
+	///
+	/// The timeout applies to every statement which does not specify its own
+	/// `TIMEOUT` clause, and a statement which exceeds it returns an error.
+	pub fn with_query_timeout(mut self, timeout: Duration) -> Session {
+		self.query_timeout = Some(timeout);
+		self
+	}
+	/// Set a variable for the session, coercing the value to the specified type
+	///
+	/// The variable is available as a parameter in every query run with this
+	/// session. An error is returned if the value can not be coerced to the
+	/// specified type, or if the variable name is protected.
+	pub fn set_var_typed<S>(&mut self, name: S, value: Value, kind: Kind) -> Result<(), Error>
+	where
+		S: Into<String>,
+	{
+		let name = name.into();
+		// Check if the variable is a protected variable
+		if PROTECTED_PARAM_NAMES.contains(&name.as_str()) {
+			return Err(Error::InvalidParam {
+				name,
+			});
+		}
+		// Check that the value is of the correct type
+		let value = value.coerce_to(&kind)?;
+		// Store the coerced value
+		self.vars.insert(name, value);
+		Ok(())
+	}
 	/// Retrieves the selected namespace
 	pub(crate) fn ns(&self) -> Option<Arc<str>> {
 		self.ns.as_deref().map(Into::into)
@@ -117,6 +221,10 @@ impl Session {
 			"tk".to_string() => self.tk.to_owned().into(),
 		});
 		ctx.add_value("session", val);
+		// Add session variables
+		for (key, val) in self.vars.iter() {
+			ctx.add_value(key.to_owned(), val.to_owned());
+		}
 		// Output context
 		ctx
 	}
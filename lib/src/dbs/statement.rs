@@ -210,6 +210,14 @@ impl<'a> Statement<'a> {
 			_ => false,
 		}
 	}
+	/// Returns whether the DISTINCT keyword was specified
+	#[inline]
+	pub fn distinct(&self) -> bool {
+		match self {
+			Statement::Select(v) => v.distinct,
+			_ => false,
+		}
+	}
 	/// Returns any EXPLAIN clause if specified
 	#[inline]
 	pub fn explain(&self) -> Option<&Explain> {
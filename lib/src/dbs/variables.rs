@@ -6,6 +6,34 @@ use std::collections::BTreeMap;
 
 pub(crate) type Variables = Option<BTreeMap<String, Value>>;
 
+/// A set of named parameters which can be bound to a query.
+///
+/// ```rust
+/// use surrealdb::dbs::Vars;
+///
+/// let vars = Vars::new().set("name", "Tobie").set("age", 33);
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Vars(BTreeMap<String, Value>);
+
+impl Vars {
+	/// Create an empty set of parameters
+	pub fn new() -> Vars {
+		Vars::default()
+	}
+	/// Bind a value to the parameter with this name, replacing any existing value
+	pub fn set(mut self, name: impl Into<String>, value: impl Into<Value>) -> Vars {
+		self.0.insert(name.into(), value.into());
+		self
+	}
+}
+
+impl From<Vars> for BTreeMap<String, Value> {
+	fn from(v: Vars) -> Self {
+		v.0
+	}
+}
+
 pub(crate) trait Attach {
 	fn attach(self, ctx: Context) -> Result<Context, Error>;
 }
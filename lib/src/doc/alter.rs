@@ -5,6 +5,7 @@ use crate::dbs::{Options, Transaction};
 use crate::doc::Document;
 use crate::err::Error;
 use crate::sql::data::Data;
+use crate::sql::operation::Patch;
 use crate::sql::operator::Operator;
 use crate::sql::value::Value;
 
@@ -25,7 +26,7 @@ impl<'a> Document<'a> {
 			match v {
 				Data::PatchExpression(data) => {
 					let data = data.compute(ctx, opt, txn, Some(&self.current)).await?;
-					self.current.doc.to_mut().patch(data)?
+					self.current.doc.to_mut().patch(&Patch(data.to_operations()?))?
 				}
 				Data::MergeExpression(data) => {
 					let data = data.compute(ctx, opt, txn, Some(&self.current)).await?;
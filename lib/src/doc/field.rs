@@ -1,9 +1,11 @@
 use crate::ctx::Context;
 use crate::dbs::Statement;
 use crate::dbs::{Options, Transaction};
-use crate::doc::Document;
+use crate::doc::{CursorDoc, Document};
 use crate::err::Error;
 use crate::sql::permission::Permission;
+use crate::sql::statements::DefineFieldStatement;
+use crate::sql::thing::Thing;
 use crate::sql::value::Value;
 
 impl<'a> Document<'a> {
@@ -30,71 +32,8 @@ impl<'a> Document<'a> {
 				let old = self.initial.doc.pick(&k);
 				// Get the input value
 				let inp = inp.pick(&k);
-				// Check for a TYPE clause
-				if let Some(kind) = &fd.kind {
-					if !val.is_none() {
-						val = val.coerce_to(kind).map_err(|e| match e {
-							// There was a conversion error
-							Error::CoerceTo {
-								from,
-								..
-							} => Error::FieldCheck {
-								thing: rid.to_string(),
-								field: fd.name.clone(),
-								value: from.to_string(),
-								check: kind.to_string(),
-							},
-							// There was a different error
-							e => e,
-						})?;
-					}
-				}
-				// Check for a VALUE clause
-				if let Some(expr) = &fd.value {
-					// Configure the context
-					let mut ctx = Context::new(ctx);
-					ctx.add_value("input", &inp);
-					ctx.add_value("value", &val);
-					ctx.add_value("after", &val);
-					ctx.add_value("before", &old);
-					// Process the VALUE clause
-					val = expr.compute(&ctx, opt, txn, Some(&self.current)).await?;
-				}
-				// Check for a TYPE clause
-				if let Some(kind) = &fd.kind {
-					val = val.coerce_to(kind).map_err(|e| match e {
-						// There was a conversion error
-						Error::CoerceTo {
-							from,
-							..
-						} => Error::FieldCheck {
-							thing: rid.to_string(),
-							field: fd.name.clone(),
-							value: from.to_string(),
-							check: kind.to_string(),
-						},
-						// There was a different error
-						e => e,
-					})?;
-				}
-				// Check for a ASSERT clause
-				if let Some(expr) = &fd.assert {
-					// Configure the context
-					let mut ctx = Context::new(ctx);
-					ctx.add_value("input", &inp);
-					ctx.add_value("value", &val);
-					ctx.add_value("after", &val);
-					ctx.add_value("before", &old);
-					// Process the ASSERT clause
-					if !expr.compute(&ctx, opt, txn, Some(&self.current)).await?.is_truthy() {
-						return Err(Error::FieldValue {
-							thing: rid.to_string(),
-							field: fd.name.clone(),
-							value: val.to_string(),
-							check: expr.to_string(),
-						});
-					}
-				}
+				// Check the TYPE, VALUE, and ASSERT clauses
+				val = coerce(fd, ctx, opt, txn, &self.current, rid, &inp, &old, val).await?;
 				// Check for a PERMISSIONS clause
 				if opt.perms && opt.auth.perms() {
 					// Get the permission clause
@@ -134,3 +73,86 @@ impl<'a> Document<'a> {
 		Ok(())
 	}
 }
+
+/// Processes the TYPE, VALUE, and ASSERT clauses of a
+/// field definition, returning the resulting field value.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn coerce(
+	fd: &DefineFieldStatement,
+	ctx: &Context<'_>,
+	opt: &Options,
+	txn: &Transaction,
+	doc: &CursorDoc<'_>,
+	rid: &Thing,
+	inp: &Value,
+	old: &Value,
+	mut val: Value,
+) -> Result<Value, Error> {
+	// Check for a TYPE clause
+	if let Some(kind) = &fd.kind {
+		if !val.is_none() {
+			val = val.coerce_to(kind).map_err(|e| match e {
+				// There was a conversion error
+				Error::CoerceTo {
+					from,
+					..
+				} => Error::FieldCheck {
+					thing: rid.to_string(),
+					field: fd.name.clone(),
+					value: from.to_string(),
+					check: kind.to_string(),
+				},
+				// There was a different error
+				e => e,
+			})?;
+		}
+	}
+	// Check for a VALUE clause
+	if let Some(expr) = &fd.value {
+		// Configure the context
+		let mut ctx = Context::new(ctx);
+		ctx.add_value("input", inp);
+		ctx.add_value("value", &val);
+		ctx.add_value("after", &val);
+		ctx.add_value("before", old);
+		// Process the VALUE clause
+		val = expr.compute(&ctx, opt, txn, Some(doc)).await?;
+	}
+	// Check for a TYPE clause
+	if let Some(kind) = &fd.kind {
+		val = val.coerce_to(kind).map_err(|e| match e {
+			// There was a conversion error
+			Error::CoerceTo {
+				from,
+				..
+			} => Error::FieldCheck {
+				thing: rid.to_string(),
+				field: fd.name.clone(),
+				value: from.to_string(),
+				check: kind.to_string(),
+			},
+			// There was a different error
+			e => e,
+		})?;
+	}
+	// Check for a ASSERT clause
+	if let Some(expr) = &fd.assert {
+		// Configure the context
+		let mut ctx = Context::new(ctx);
+		ctx.add_value("input", inp);
+		ctx.add_value("value", &val);
+		ctx.add_value("after", &val);
+		ctx.add_value("before", old);
+		// Process the ASSERT clause
+		if !expr.compute(&ctx, opt, txn, Some(doc)).await?.is_truthy() {
+			return Err(Error::FieldValue {
+				thing: rid.to_string(),
+				field: fd.name.clone(),
+				value: val.to_string(),
+				check: expr.to_string(),
+			});
+		}
+	}
+	// Return the field value
+	Ok(val)
+}
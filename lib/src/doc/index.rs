@@ -139,7 +139,7 @@ impl<'a> IndexOperation<'a> {
 		if let Some(n) = &self.n {
 			let key = self.get_non_unique_index_key(n);
 			if run.putc(key, self.rid, None).await.is_err() {
-				return self.err_index_exists(n);
+				return self.err_index_exists(self.rid, n);
 			}
 		}
 		Ok(())
@@ -167,16 +167,22 @@ impl<'a> IndexOperation<'a> {
 			if !n.is_all_none_or_null() {
 				let key = self.get_unique_index_key(n);
 				if run.putc(key, self.rid, None).await.is_err() {
-					return self.err_index_exists(n);
+					// Fetch the record which already holds this value
+					let key = self.get_unique_index_key(n);
+					let rid = match run.get(key).await? {
+						Some(v) => v.into(),
+						None => self.rid.to_owned(),
+					};
+					return self.err_index_exists(&rid, n);
 				}
 			}
 		}
 		Ok(())
 	}
 
-	fn err_index_exists(&self, n: &Array) -> Result<(), Error> {
+	fn err_index_exists(&self, rid: &Thing, n: &Array) -> Result<(), Error> {
 		Err(Error::IndexExists {
-			thing: self.rid.to_string(),
+			thing: rid.to_string(),
 			index: self.ix.name.to_string(),
 			value: match n.len() {
 				1 => n.first().unwrap().to_string(),
@@ -3,7 +3,6 @@ use crate::dbs::Statement;
 use crate::dbs::{Options, Transaction};
 use crate::doc::Document;
 use crate::err::Error;
-use crate::sql::idiom::Idiom;
 use crate::sql::output::Output;
 use crate::sql::paths::META;
 use crate::sql::permission::Permission;
@@ -24,9 +23,7 @@ impl<'a> Document<'a> {
 			Some(v) => match v {
 				Output::None => Err(Error::Ignore),
 				Output::Null => Ok(Value::Null),
-				Output::Diff => {
-					Ok(self.initial.doc.diff(self.current.doc.as_ref(), Idiom::default()).into())
-				}
+				Output::Diff => Ok(self.initial.doc.diff(&self.current.doc).into()),
 				Output::After => self.current.doc.compute(ctx, opt, txn, Some(&self.current)).await,
 				Output::Before => {
 					self.initial.doc.compute(ctx, opt, txn, Some(&self.initial)).await
@@ -35,7 +32,7 @@ impl<'a> Document<'a> {
 			},
 			None => match stm {
 				Statement::Live(s) => match s.expr.len() {
-					0 => Ok(self.initial.doc.diff(&self.current.doc, Idiom::default()).into()),
+					0 => Ok(self.initial.doc.diff(&self.current.doc).into()),
 					_ => s.expr.compute(ctx, opt, txn, Some(&self.current), false).await,
 				},
 				Statement::Select(s) => {
@@ -27,6 +27,8 @@ impl<'a> Document<'a> {
 		// Store the record data
 		let key = crate::key::thing::new(opt.ns(), opt.db(), &rid.tb, &rid.id);
 		run.set(key, self).await?;
+		// Track the record change
+		run.record_write(rid, &self.initial.doc, &self.current.doc);
 		// Carry on
 		Ok(())
 	}
@@ -0,0 +1,82 @@
+use crate::ctx::Context;
+use crate::dbs::{Options, Transaction};
+use crate::doc::field::coerce;
+use crate::doc::CursorDoc;
+use crate::err::Error;
+use crate::sql::id::Id;
+use crate::sql::idiom::Idiom;
+use crate::sql::statements::{DefineFieldStatement, DefineTableStatement};
+use crate::sql::thing::Thing;
+use crate::sql::value::Value;
+
+/// Validates and coerces a new record against a table definition and its
+/// field definitions, without storing the record. Every field is processed,
+/// and all of the errors are returned, rather than only the first.
+pub(crate) async fn validate(
+	ctx: &Context<'_>,
+	opt: &Options,
+	txn: &Transaction,
+	mut doc: Value,
+	tb: &DefineTableStatement,
+	fds: &[DefineFieldStatement],
+) -> Result<Value, Vec<Error>> {
+	// Get the record id, or generate one
+	let rid = match doc.rid() {
+		Value::Thing(v) if v.tb == tb.name.0 => v,
+		_ => Thing::from((tb.name.to_raw(), Id::rand())),
+	};
+	// The whole record is the user applied input
+	let inp = doc.clone();
+	// Only process the fields on this table
+	let fds: Vec<&DefineFieldStatement> = fds.iter().filter(|fd| fd.what == tb.name).collect();
+	// Collect any errors
+	let mut errors: Vec<Error> = vec![];
+	// Loop through all field statements
+	for fd in fds.iter() {
+		// Loop over each field in document
+		for (k, val) in doc.walk(&fd.name).into_iter() {
+			// Get the input value
+			let inp = inp.pick(&k);
+			// Check the TYPE, VALUE, and ASSERT clauses
+			let res = {
+				let cur = CursorDoc::new(None, Some(&rid), None, &doc);
+				coerce(fd, ctx, opt, txn, &cur, &rid, &inp, &Value::None, val).await
+			};
+			// Set the value of the field
+			let res = match res {
+				Ok(Value::None) => doc.del(ctx, opt, txn, &k).await,
+				Ok(val) => doc.set(ctx, opt, txn, &k, val).await,
+				Err(e) => Err(e),
+			};
+			// Store any error
+			if let Err(e) = res {
+				errors.push(e);
+			}
+		}
+	}
+	// Remove any undefined fields if the table is schemafull
+	if tb.full {
+		// Create a vector to store the keys
+		let mut keys: Vec<Idiom> = vec![];
+		// Loop through all field statements
+		for fd in fds.iter() {
+			match fd.flex {
+				false => keys.extend(doc.each(&fd.name)),
+				true => keys.extend(doc.every(Some(&fd.name), true, true)),
+			}
+		}
+		// Loop over every field in the document
+		for fd in doc.every(None, true, true).iter() {
+			if !keys.contains(fd) && !fd.is_id() && !fd.is_in() && !fd.is_out() && !fd.is_meta() {
+				if let Err(e) = doc.del(ctx, opt, txn, fd).await {
+					errors.push(e);
+				}
+			}
+		}
+	}
+	// Return the record or the errors
+	match errors.is_empty() {
+		true => Ok(doc),
+		false => Err(errors),
+	}
+}
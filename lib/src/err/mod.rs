@@ -1,5 +1,6 @@
 use crate::idx::ft::MatchRef;
 use crate::sql::idiom::Idiom;
+use crate::sql::statement::StatementKind;
 use crate::sql::value::Value;
 use crate::vs::Error as VersionstampError;
 use base64_lib::DecodeError as Base64Error;
@@ -10,6 +11,7 @@ use jsonwebtoken::errors::Error as JWTError;
 use serde::Serialize;
 use std::borrow::Cow;
 use std::string::FromUtf8Error;
+use std::time::Duration;
 use storekey::decode::Error as DecodeError;
 use storekey::encode::Error as EncodeError;
 use thiserror::Error;
@@ -31,6 +33,10 @@ pub enum Error {
 	#[error("There was a problem with the underlying datastore: {0}")]
 	Ds(String),
 
+	/// The datastore is shutting down, and no longer accepts queries or commits
+	#[error("The datastore is shutting down")]
+	DsShutdown,
+
 	/// There was a problem with a datastore transaction
 	#[error("There was a problem with a datastore transaction: {0}")]
 	Tx(String),
@@ -55,6 +61,10 @@ pub enum Error {
 	#[error("The key being inserted already exists")]
 	TxKeyAlreadyExists,
 
+	/// The transaction conflicted with another transaction and can be retried
+	#[error("Failed to commit transaction due to a read or write conflict. This transaction can be retried")]
+	TxRetryable,
+
 	/// The key exceeds a limit set by the KV store
 	#[error("Record id or key is too large")]
 	TxKeyTooLarge,
@@ -83,6 +93,13 @@ pub enum Error {
 	#[error("The SQL query was not parsed fully")]
 	QueryRemaining,
 
+	/// The SQL query was longer than the maximum query length
+	#[error("The SQL query is {len} bytes long, which exceeds the maximum of {max} bytes")]
+	QueryTooLarge {
+		len: usize,
+		max: usize,
+	},
+
 	/// There was an error with authentication
 	#[error("There was a problem with authentication")]
 	InvalidAuth,
@@ -95,12 +112,47 @@ pub enum Error {
 		sql: String,
 	},
 
+	/// The SQL query contained a statement which is not allowed
+	#[error("The {statement} statement on line {line} at character {char} is not allowed")]
+	StatementNotAllowed {
+		statement: StatementKind,
+		line: usize,
+		char: usize,
+	},
+
+	/// The SQL query contained a statement which writes data, when only reads are allowed
+	#[error("The {statement} statement on line {line} at character {char} writes data, which is not allowed")]
+	WriteNotAllowed {
+		statement: StatementKind,
+		line: usize,
+		char: usize,
+	},
+
+	/// There was an error with the provided YAML document
+	#[error("Parse error on line {line} when parsing YAML: {message}")]
+	InvalidYaml {
+		line: usize,
+		message: String,
+	},
+
 	/// There was an error with the provided JSON Patch
 	#[error("The JSON Patch contains invalid operations. {message}")]
 	InvalidPatch {
 		message: String,
 	},
 
+	/// There was an error with the provided binary diff
+	#[error("The binary diff is invalid. {message}")]
+	InvalidDelta {
+		message: String,
+	},
+
+	/// An identifier could not be serialized without quoting
+	#[error("The identifier '{value}' can not be serialized without quoting")]
+	UnquotedIdent {
+		value: String,
+	},
+
 	/// Remote HTTP request functions are not enabled
 	#[error("Remote HTTP request functions are not enabled")]
 	HttpDisabled,
@@ -111,6 +163,12 @@ pub enum Error {
 		name: String,
 	},
 
+	/// The value in a destructuring LET statement did not match its pattern
+	#[error("Unable to destructure the value in the LET statement, as {message}")]
+	InvalidDestructure {
+		message: String,
+	},
+
 	#[error("Found '{field}' in SELECT clause on line {line}, but field is not an aggregate function, and is not present in GROUP BY expression")]
 	InvalidField {
 		line: usize,
@@ -141,6 +199,10 @@ pub enum Error {
 		value: String,
 	},
 
+	/// A page of keys was requested with a limit of zero
+	#[error("The page limit must be greater than zero")]
+	InvalidPageLimit,
+
 	/// The START clause must evaluate to a positive integer
 	#[error("Found {value} but the START clause must evaluate to a positive integer")]
 	InvalidStart {
@@ -151,6 +213,24 @@ pub enum Error {
 	#[error("Problem with embedded script function. {message}")]
 	InvalidScript {
 		message: String,
+		/// The JavaScript stack trace, if one was available
+		stack: Option<String>,
+		/// The 1-based line within the function body where the error occurred
+		line: Option<usize>,
+	},
+
+	/// An embedded script function used more memory than it is allowed
+	#[error("Problem with embedded script function. The script exceeded the memory limit of {limit} bytes")]
+	ScriptMemoryLimit {
+		limit: usize,
+	},
+
+	/// An embedded script function ran for longer than it is allowed
+	#[error(
+		"Problem with embedded script function. The script exceeded the timeout of {timeout:?}"
+	)]
+	ScriptTimeout {
+		timeout: Duration,
 	},
 
 	/// There was a problem running the specified function
@@ -213,6 +293,12 @@ pub enum Error {
 		value: String,
 	},
 
+	/// The requested namespace access method does not exist
+	#[error("The namespace access method '{value}' does not exist")]
+	NaNotFound {
+		value: String,
+	},
+
 	/// The requested namespace login does not exist
 	#[error("The namespace login '{value}' does not exist")]
 	NlNotFound {
@@ -231,6 +317,12 @@ pub enum Error {
 		value: String,
 	},
 
+	/// The requested database access method does not exist
+	#[error("The database access method '{value}' does not exist")]
+	DaNotFound {
+		value: String,
+	},
+
 	/// The requested database login does not exist
 	#[error("The database login '{value}' does not exist")]
 	DlNotFound {
@@ -267,6 +359,12 @@ pub enum Error {
 		value: String,
 	},
 
+	/// The requested scope access method does not exist
+	#[error("The scope access method '{value}' does not exist")]
+	SaNotFound {
+		value: String,
+	},
+
 	/// The requested param does not exist
 	#[error("The param '${value}' does not exist")]
 	PaNotFound {
@@ -279,6 +377,27 @@ pub enum Error {
 		value: String,
 	},
 
+	/// A query references a table which is not defined in the schema
+	#[error("The table '{name}' in statement {statement} is not defined")]
+	SchemaTable {
+		name: String,
+		statement: usize,
+	},
+
+	/// A query references a field which is not defined in the schema
+	#[error("The field '{name}' on table '{table}' in statement {statement} is not defined")]
+	SchemaField {
+		name: String,
+		table: String,
+		statement: usize,
+	},
+
+	/// The requested table does not have a change feed
+	#[error("The table '{value}' does not have a change feed")]
+	TbChangefeedNotFound {
+		value: String,
+	},
+
 	/// The requested live query does not exist
 	#[error("The live query '{value}' does not exist")]
 	LvNotFound {
@@ -371,7 +490,7 @@ pub enum Error {
 		thing: String,
 	},
 
-	/// A database index entry for the specified record already exists
+	/// A database index entry for the specified value already exists on another record
 	#[error("Database index `{index}` already contains {value}, with record `{thing}`")]
 	IndexExists {
 		thing: String,
@@ -460,6 +579,10 @@ pub enum Error {
 	#[error("There was an error processing a value in parallel")]
 	Channel(String),
 
+	/// There was an error reading or writing data
+	#[error("There was an I/O error: {0}")]
+	Io(String),
+
 	/// Represents an underlying error with Serde encoding / decoding
 	#[error("Serde error: {0}")]
 	Serde(#[from] SerdeError),
@@ -532,6 +655,308 @@ pub enum Error {
 	CorruptedVersionstampInKey(#[from] VersionstampError),
 }
 
+/// The clause of a SELECT statement which referenced an invalid field
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum ClauseErrorKind {
+	/// The field was in the SELECT expression
+	Field,
+	/// The field was in the SPLIT ON clause
+	Split,
+	/// The field was in the ORDER BY clause
+	Order,
+	/// The field was in the GROUP BY clause
+	Group,
+}
+
+impl Error {
+	/// Returns the clause and the field which caused an invalid clause error
+	///
+	/// This returns `None` for every error which is not caused by a SELECT
+	/// statement clause referencing an invalid field.
+	pub fn clause_error(&self) -> Option<(ClauseErrorKind, &str)> {
+		match self {
+			Error::InvalidField {
+				field,
+				..
+			} => Some((ClauseErrorKind::Field, field)),
+			Error::InvalidSplit {
+				field,
+				..
+			} => Some((ClauseErrorKind::Split, field)),
+			Error::InvalidOrder {
+				field,
+				..
+			} => Some((ClauseErrorKind::Order, field)),
+			Error::InvalidGroup {
+				field,
+				..
+			} => Some((ClauseErrorKind::Group, field)),
+			_ => None,
+		}
+	}
+
+	/// Returns a stable, machine-readable code which identifies the kind of this error
+	///
+	/// Unlike the error variants and messages, which can change between versions,
+	/// these codes are part of the public API, and can be relied upon to branch on
+	/// the category of an error.
+	pub fn code(&self) -> &'static str {
+		match self {
+			Error::Ignore => "IGNORE",
+			Error::Unreachable => "UNREACHABLE",
+			Error::Ds(..) => "DS",
+			Error::DsShutdown => "DS_SHUTDOWN",
+			Error::Tx(..) => "TX",
+			Error::TxFailure => "TX_FAILURE",
+			Error::TxFinished => "TX_FINISHED",
+			Error::TxReadonly => "TX_READONLY",
+			Error::TxConditionNotMet => "TX_CONDITION_NOT_MET",
+			Error::TxKeyAlreadyExists => "TX_KEY_ALREADY_EXISTS",
+			Error::TxRetryable => "TX_RETRYABLE",
+			Error::TxKeyTooLarge => "TX_KEY_TOO_LARGE",
+			Error::TxValueTooLarge => "TX_VALUE_TOO_LARGE",
+			Error::TxTooLarge => "TX_TOO_LARGE",
+			Error::NsEmpty => "NS_EMPTY",
+			Error::DbEmpty => "DB_EMPTY",
+			Error::QueryEmpty => "QUERY_EMPTY",
+			Error::QueryRemaining => "QUERY_REMAINING",
+			Error::QueryTooLarge {
+				..
+			} => "QUERY_TOO_LARGE",
+			Error::InvalidAuth => "INVALID_AUTH",
+			Error::InvalidQuery {
+				..
+			} => "INVALID_QUERY",
+			Error::StatementNotAllowed {
+				..
+			}
+			| Error::WriteNotAllowed {
+				..
+			} => "STATEMENT_NOT_ALLOWED",
+			Error::InvalidYaml {
+				..
+			} => "INVALID_YAML",
+			Error::InvalidPatch {
+				..
+			} => "INVALID_PATCH",
+			Error::InvalidDelta {
+				..
+			} => "INVALID_DELTA",
+			Error::UnquotedIdent {
+				..
+			} => "UNQUOTED_IDENT",
+			Error::HttpDisabled => "HTTP_DISABLED",
+			Error::InvalidParam {
+				..
+			} => "INVALID_PARAM",
+			Error::InvalidDestructure {
+				..
+			} => "INVALID_DESTRUCTURE",
+			Error::InvalidField {
+				..
+			} => "INVALID_FIELD",
+			Error::InvalidSplit {
+				..
+			} => "INVALID_SPLIT",
+			Error::InvalidOrder {
+				..
+			} => "INVALID_ORDER",
+			Error::InvalidGroup {
+				..
+			} => "INVALID_GROUP",
+			Error::InvalidLimit {
+				..
+			} => "INVALID_LIMIT",
+			Error::InvalidPageLimit => "INVALID_LIMIT",
+			Error::InvalidStart {
+				..
+			} => "INVALID_START",
+			Error::InvalidScript {
+				..
+			} => "INVALID_SCRIPT",
+			Error::ScriptMemoryLimit {
+				..
+			} => "SCRIPT_MEMORY_LIMIT",
+			Error::ScriptTimeout {
+				..
+			} => "SCRIPT_TIMEOUT",
+			Error::InvalidFunction {
+				..
+			} => "INVALID_FUNCTION",
+			Error::InvalidArguments {
+				..
+			} => "INVALID_ARGUMENTS",
+			Error::QueryTimedout => "QUERY_TIMEDOUT",
+			Error::QueryCancelled => "QUERY_CANCELLED",
+			Error::QueryNotExecuted => "QUERY_NOT_EXECUTED",
+			Error::QueryNotExecutedDetail {
+				..
+			} => "QUERY_NOT_EXECUTED_DETAIL",
+			Error::QueryPermissions => "QUERY_PERMISSIONS",
+			Error::NsNotAllowed {
+				..
+			} => "NS_NOT_ALLOWED",
+			Error::DbNotAllowed {
+				..
+			} => "DB_NOT_ALLOWED",
+			Error::NsNotFound {
+				..
+			} => "NS_NOT_FOUND",
+			Error::NtNotFound {
+				..
+			} => "NT_NOT_FOUND",
+			Error::NaNotFound {
+				..
+			} => "NA_NOT_FOUND",
+			Error::NlNotFound {
+				..
+			} => "NL_NOT_FOUND",
+			Error::DbNotFound {
+				..
+			} => "DB_NOT_FOUND",
+			Error::DtNotFound {
+				..
+			} => "DT_NOT_FOUND",
+			Error::DaNotFound {
+				..
+			} => "DA_NOT_FOUND",
+			Error::DlNotFound {
+				..
+			} => "DL_NOT_FOUND",
+			Error::FcNotFound {
+				..
+			} => "FC_NOT_FOUND",
+			Error::ScNotFound {
+				..
+			} => "SC_NOT_FOUND",
+			Error::ClAlreadyExists {
+				..
+			} => "CL_ALREADY_EXISTS",
+			Error::NdNotFound {
+				..
+			} => "ND_NOT_FOUND",
+			Error::StNotFound {
+				..
+			} => "ST_NOT_FOUND",
+			Error::SaNotFound {
+				..
+			} => "SA_NOT_FOUND",
+			Error::PaNotFound {
+				..
+			} => "PA_NOT_FOUND",
+			Error::TbNotFound {
+				..
+			} => "TB_NOT_FOUND",
+			Error::SchemaTable {
+				..
+			} => "SCHEMA_TABLE",
+			Error::SchemaField {
+				..
+			} => "SCHEMA_FIELD",
+			Error::TbChangefeedNotFound {
+				..
+			} => "TB_CHANGEFEED_NOT_FOUND",
+			Error::LvNotFound {
+				..
+			} => "LV_NOT_FOUND",
+			Error::LqNotFound {
+				..
+			} => "LQ_NOT_FOUND",
+			Error::AzNotFound {
+				..
+			} => "AZ_NOT_FOUND",
+			Error::IxNotFound {
+				..
+			} => "IX_NOT_FOUND",
+			Error::RealtimeDisabled => "REALTIME_DISABLED",
+			Error::ComputationDepthExceeded => "COMPUTATION_DEPTH_EXCEEDED",
+			Error::CreateStatement {
+				..
+			} => "CREATE_STATEMENT",
+			Error::UpdateStatement {
+				..
+			} => "UPDATE_STATEMENT",
+			Error::RelateStatement {
+				..
+			} => "RELATE_STATEMENT",
+			Error::DeleteStatement {
+				..
+			} => "DELETE_STATEMENT",
+			Error::InsertStatement {
+				..
+			} => "INSERT_STATEMENT",
+			Error::LiveStatement {
+				..
+			} => "LIVE_STATEMENT",
+			Error::KillStatement {
+				..
+			} => "KILL_STATEMENT",
+			Error::TablePermissions {
+				..
+			} => "TABLE_PERMISSIONS",
+			Error::TableIsView {
+				..
+			} => "TABLE_IS_VIEW",
+			Error::RecordExists {
+				..
+			} => "RECORD_EXISTS",
+			Error::IndexExists {
+				..
+			} => "INDEX_EXISTS",
+			Error::FieldCheck {
+				..
+			} => "FIELD_CHECK",
+			Error::FieldValue {
+				..
+			} => "FIELD_VALUE",
+			Error::IdInvalid {
+				..
+			} => "ID_INVALID",
+			Error::CoerceTo {
+				..
+			} => "COERCE_TO",
+			Error::ConvertTo {
+				..
+			} => "CONVERT_TO",
+			Error::LengthInvalid {
+				..
+			} => "LENGTH_INVALID",
+			Error::TryAdd(..) => "TRY_ADD",
+			Error::TrySub(..) => "TRY_SUB",
+			Error::TryMul(..) => "TRY_MUL",
+			Error::TryDiv(..) => "TRY_DIV",
+			Error::TryPow(..) => "TRY_POW",
+			Error::TryNeg(..) => "TRY_NEG",
+			Error::TryFrom(..) => "TRY_FROM",
+			Error::Http(..) => "HTTP",
+			Error::Channel(..) => "CHANNEL",
+			Error::Io(..) => "IO",
+			Error::Serde(..) => "SERDE",
+			Error::Encode(..) => "ENCODE",
+			Error::Decode(..) => "DECODE",
+			Error::CorruptedIndex => "CORRUPTED_INDEX",
+			Error::NoIndexFoundForMatch {
+				..
+			} => "NO_INDEX_FOUND_FOR_MATCH",
+			Error::AnalyzerError(..) => "ANALYZER_ERROR",
+			Error::HighlightError(..) => "HIGHLIGHT_ERROR",
+			Error::Bincode(..) => "BINCODE",
+			Error::FstError(..) => "FST_ERROR",
+			Error::Utf8Error(..) => "UTF8_ERROR",
+			Error::FeatureNotYetImplemented {
+				..
+			} => "FEATURE_NOT_YET_IMPLEMENTED",
+			Error::DuplicatedMatchRef {
+				..
+			} => "DUPLICATED_MATCH_REF",
+			Error::TimestampOverflow(..) => "TIMESTAMP_OVERFLOW",
+			Error::Internal(..) => "INTERNAL",
+			Error::Unimplemented(..) => "UNIMPLEMENTED",
+			Error::CorruptedVersionstampInKey(..) => "CORRUPTED_VERSIONSTAMP_IN_KEY",
+		}
+	}
+}
+
 impl From<Error> for String {
 	fn from(e: Error) -> String {
 		e.to_string()
@@ -555,6 +980,7 @@ impl From<echodb::err::Error> for Error {
 	fn from(e: echodb::err::Error) -> Error {
 		match e {
 			echodb::err::Error::KeyAlreadyExists => Error::TxKeyAlreadyExists,
+			echodb::err::Error::ValNotExpectedValue => Error::TxConditionNotMet,
 			_ => Error::Tx(e.to_string()),
 		}
 	}
@@ -565,6 +991,7 @@ impl From<indxdb::err::Error> for Error {
 	fn from(e: indxdb::err::Error) -> Error {
 		match e {
 			indxdb::err::Error::KeyAlreadyExists => Error::TxKeyAlreadyExists,
+			indxdb::err::Error::ValNotExpectedValue => Error::TxConditionNotMet,
 			_ => Error::Tx(e.to_string()),
 		}
 	}
@@ -575,6 +1002,7 @@ impl From<tikv::Error> for Error {
 	fn from(e: tikv::Error) -> Error {
 		match e {
 			tikv::Error::DuplicateKeyInsertion => Error::TxKeyAlreadyExists,
+			tikv::Error::KeyError(ke) if ke.conflict.is_some() => Error::TxRetryable,
 			tikv::Error::KeyError(ke) if ke.abort.contains("KeyTooLarge") => Error::TxKeyTooLarge,
 			tikv::Error::RegionError(re) if re.raft_entry_too_large.is_some() => Error::TxTooLarge,
 			_ => Error::Tx(e.to_string()),
@@ -585,14 +1013,20 @@ impl From<tikv::Error> for Error {
 #[cfg(feature = "kv-speedb")]
 impl From<speedb::Error> for Error {
 	fn from(e: speedb::Error) -> Error {
-		Error::Tx(e.to_string())
+		match e.kind() {
+			speedb::ErrorKind::Busy | speedb::ErrorKind::TryAgain => Error::TxRetryable,
+			_ => Error::Tx(e.to_string()),
+		}
 	}
 }
 
 #[cfg(feature = "kv-rocksdb")]
 impl From<rocksdb::Error> for Error {
 	fn from(e: rocksdb::Error) -> Error {
-		Error::Tx(e.to_string())
+		match e.kind() {
+			rocksdb::ErrorKind::Busy | rocksdb::ErrorKind::TryAgain => Error::TxRetryable,
+			_ => Error::Tx(e.to_string()),
+		}
 	}
 }
 
@@ -608,6 +1042,12 @@ impl<T> From<channel::SendError<T>> for Error {
 	}
 }
 
+impl From<std::io::Error> for Error {
+	fn from(e: std::io::Error) -> Error {
+		Error::Io(e.to_string())
+	}
+}
+
 #[cfg(feature = "http")]
 impl From<reqwest::Error> for Error {
 	fn from(e: reqwest::Error) -> Error {
@@ -623,3 +1063,22 @@ impl Serialize for Error {
 		serializer.serialize_str(self.to_string().as_str())
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::sql::parse;
+
+	#[test]
+	fn error_codes() {
+		let err = parse("").unwrap_err();
+		assert_eq!(err.code(), "QUERY_EMPTY");
+		let err = parse("SELECT * FROM;").unwrap_err();
+		assert!(matches!(err, Error::InvalidQuery { .. }), "{err:?}");
+		assert_eq!(err.code(), "INVALID_QUERY");
+		let err = parse("SELECT id, info FROM person SPLIT tags").unwrap_err();
+		assert_eq!(err.code(), "INVALID_SPLIT");
+		assert_eq!(Error::QueryTimedout.code(), "QUERY_TIMEDOUT");
+		assert_eq!(Error::TxKeyAlreadyExists.code(), "TX_KEY_ALREADY_EXISTS");
+	}
+}
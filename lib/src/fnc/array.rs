@@ -12,6 +12,8 @@ use crate::sql::array::Transpose;
 use crate::sql::array::Union;
 use crate::sql::array::Uniq;
 use crate::sql::value::Value;
+use rand::seq::SliceRandom;
+use std::cmp::Ordering;
 
 pub fn add((mut array, value): (Array, Value)) -> Result<Value, Error> {
 	match value {
@@ -97,6 +99,11 @@ pub fn boolean_xor((lh, rh): (Array, Array)) -> Result<Value, Error> {
 	Ok(results.into())
 }
 
+pub fn chunk((array, size): (Array, i64)) -> Result<Value, Error> {
+	let size = chunk_size("array::chunk", size)?;
+	Ok(array.chunks(size).map(|v| Value::from(v.to_vec())).collect::<Vec<_>>().into())
+}
+
 pub fn clump((array, clump_size): (Array, i64)) -> Result<Value, Error> {
 	Ok(array.clump(clump_size as usize).into())
 }
@@ -152,8 +159,15 @@ pub fn first((array,): (Array,)) -> Result<Value, Error> {
 	}
 }
 
-pub fn flatten((array,): (Array,)) -> Result<Value, Error> {
-	Ok(array.flatten().into())
+pub fn flatten((mut array, depth): (Array, Option<i64>)) -> Result<Value, Error> {
+	for _ in 0..depth.unwrap_or(1) {
+		// Stop early once there is nothing left to flatten
+		if !array.iter().any(Value::is_array) {
+			break;
+		}
+		array = array.flatten();
+	}
+	Ok(array.into())
 }
 
 pub fn group((array,): (Array,)) -> Result<Value, Error> {
@@ -183,6 +197,15 @@ pub fn insert((mut array, value, index): (Array, Value, Option<i64>)) -> Result<
 	}
 }
 
+/// Inserts a value at the given position in an array. A negative index counts back
+/// from the end of the array, and an out-of-range index is clamped to the start or
+/// the end of the array.
+pub fn insert_at((mut array, index, value): (Array, i64, Value)) -> Result<Value, Error> {
+	let index = clamp_index(index, array.len(), array.len());
+	array.insert(index, value);
+	Ok(array.into())
+}
+
 pub fn intersect((array, other): (Array, Array)) -> Result<Value, Error> {
 	Ok(array.intersect(other).into())
 }
@@ -287,6 +310,19 @@ pub fn min((array,): (Array,)) -> Result<Value, Error> {
 	Ok(array.into_iter().min().unwrap_or_default())
 }
 
+/// Moves an element from one position in an array to another. Negative indexes count
+/// back from the end of the array, and out-of-range indexes are clamped to the first
+/// or the last element of the array.
+pub fn r#move((mut array, from, to): (Array, i64, i64)) -> Result<Value, Error> {
+	if let Some(max) = array.len().checked_sub(1) {
+		let from = clamp_index(from, array.len(), max);
+		let to = clamp_index(to, array.len(), max);
+		let value = array.remove(from);
+		array.insert(to, value);
+	}
+	Ok(array.into())
+}
+
 pub fn pop((mut array,): (Array,)) -> Result<Value, Error> {
 	Ok(array.pop().into())
 }
@@ -316,11 +352,37 @@ pub fn remove((mut array, mut index): (Array, i64)) -> Result<Value, Error> {
 	Ok(array.into())
 }
 
+/// Removes the element at the given position in an array. A negative index counts
+/// back from the end of the array, and an out-of-range index is clamped to the first
+/// or the last element of the array.
+pub fn remove_at((mut array, index): (Array, i64)) -> Result<Value, Error> {
+	if let Some(max) = array.len().checked_sub(1) {
+		array.remove(clamp_index(index, array.len(), max));
+	}
+	Ok(array.into())
+}
+
 pub fn reverse((mut array,): (Array,)) -> Result<Value, Error> {
 	array.reverse();
 	Ok(array.into())
 }
 
+/// Returns a number of randomly chosen elements from an array, without replacement.
+/// If more elements are requested than there are in the array, all of the elements
+/// are returned in a random order.
+pub fn sample((mut array, count): (Array, usize)) -> Result<Value, Error> {
+	let count = count.min(array.len());
+	let (chosen, _) = array.0.partial_shuffle(&mut rand::thread_rng(), count);
+	Ok(chosen.to_vec().into())
+}
+
+/// Returns the array without its first `count` elements. A negative count skips
+/// nothing, and a count beyond the length of the array returns an empty array.
+pub fn skip((array, count): (Array, i64)) -> Result<Value, Error> {
+	let count = count.clamp(0, array.len() as i64) as usize;
+	Ok(array.into_iter().skip(count).collect::<Vec<_>>().into())
+}
+
 pub fn slice((array, beg, lim): (Array, Option<isize>, Option<isize>)) -> Result<Value, Error> {
 	let skip = match beg {
 		Some(v) if v < 0 => array.len().saturating_sub(v.unsigned_abs()),
@@ -342,8 +404,24 @@ pub fn slice((array, beg, lim): (Array, Option<isize>, Option<isize>)) -> Result
 	.into())
 }
 
-pub fn sort((mut array, order): (Array, Option<Value>)) -> Result<Value, Error> {
+/// Sorts the array. The order may be given as `"asc"`, `"desc"`, `true` or `false`,
+/// and defaults to ascending. To sort by a field of each element, the key path is
+/// given as an object instead, followed by an optional direction of `"asc"`,
+/// `"desc"`, `true` or `false`, such as `array::sort($films, { key: 'rating' }, 'desc')`.
+pub fn sort(
+	(mut array, order, direction): (Array, Option<Value>, Option<Value>),
+) -> Result<Value, Error> {
 	match order {
+		// If a key is given, sort by the value at that path in each element
+		Some(Value::Object(o)) => match o.get("key") {
+			Some(Value::Strand(s)) => sort_by_key(array, s.as_str(), direction),
+			_ => Err(Error::InvalidArguments {
+				name: String::from("array::sort"),
+				message: String::from(
+					"The key must be given as an object with a string path, such as { key: 'rating' }.",
+				),
+			}),
+		},
 		// If "asc", sort ascending
 		Some(Value::Strand(s)) if s.as_str() == "asc" => {
 			array.sort_unstable();
@@ -372,10 +450,55 @@ pub fn sort((mut array, order): (Array, Option<Value>)) -> Result<Value, Error>
 	}
 }
 
+/// Sorts the array by the value at the `key` path of each element, keeping the
+/// original order of equal elements. Elements without a value at the path are
+/// always placed last, whichever direction the array is sorted in.
+fn sort_by_key(array: Array, key: &str, direction: Option<Value>) -> Result<Value, Error> {
+	let key = crate::sql::idiom(key).map_err(|_| Error::InvalidArguments {
+		name: String::from("array::sort"),
+		message: format!("The key '{key}' is not a valid field path."),
+	})?;
+	let asc = match direction {
+		None | Some(Value::Bool(true)) => true,
+		Some(Value::Bool(false)) => false,
+		Some(Value::Strand(s)) if s.as_str() == "asc" => true,
+		Some(Value::Strand(s)) if s.as_str() == "desc" => false,
+		Some(v) => {
+			return Err(Error::InvalidArguments {
+				name: String::from("array::sort"),
+				message: format!(
+					"The direction must be one of 'asc', 'desc', true or false, but found {v}."
+				),
+			})
+		}
+	};
+	let mut keyed: Vec<(Value, Value)> = array.into_iter().map(|v| (v.pick(&key), v)).collect();
+	keyed.sort_by(|(a, _), (b, _)| match (a.is_none(), b.is_none()) {
+		(true, true) => Ordering::Equal,
+		(true, false) => Ordering::Greater,
+		(false, true) => Ordering::Less,
+		(false, false) if asc => a.cmp(b),
+		(false, false) => b.cmp(a),
+	});
+	Ok(keyed.into_iter().map(|(_, v)| v).collect::<Vec<_>>().into())
+}
+
+/// Returns the first `count` elements of the array. A negative count takes nothing,
+/// and a count beyond the length of the array returns the whole array.
+pub fn take((array, count): (Array, i64)) -> Result<Value, Error> {
+	let count = count.clamp(0, array.len() as i64) as usize;
+	Ok(array.into_iter().take(count).collect::<Vec<_>>().into())
+}
+
 pub fn transpose((array,): (Array,)) -> Result<Value, Error> {
 	Ok(array.transpose().into())
 }
 
+pub fn windows((array, size): (Array, i64)) -> Result<Value, Error> {
+	let size = chunk_size("array::windows", size)?;
+	Ok(array.windows(size).map(|v| Value::from(v.to_vec())).collect::<Vec<_>>().into())
+}
+
 pub fn union((array, other): (Array, Array)) -> Result<Value, Error> {
 	Ok(array.union(other).into())
 }
@@ -397,9 +520,33 @@ pub mod sort {
 	}
 }
 
+/// Validates the size argument of a function which splits an array into sub-arrays.
+fn chunk_size(name: &str, size: i64) -> Result<usize, Error> {
+	match size {
+		s if s > 0 => Ok(s as usize),
+		_ => Err(Error::InvalidArguments {
+			name: String::from(name),
+			message: String::from("The second argument must be an integer greater than 0."),
+		}),
+	}
+}
+
+/// Resolves a possibly negative index against the length of an array, clamping
+/// the result to the range `0..=max`.
+fn clamp_index(index: i64, len: usize, max: usize) -> usize {
+	let index = match index {
+		i if i < 0 => len.saturating_sub(i.unsigned_abs() as usize),
+		i => i as usize,
+	};
+	index.min(max)
+}
+
 #[cfg(test)]
 mod tests {
-	use super::{at, first, join, last, slice};
+	use super::{
+		at, chunk, first, insert_at, join, last, r#move, remove_at, skip, slice, take, windows,
+	};
+	use crate::sql::test::Parse;
 	use crate::sql::{Array, Value};
 
 	#[test]
@@ -472,4 +619,81 @@ mod tests {
 		test(vec!["hello", "world"].into(), -2, "hello".into());
 		test(vec!["hello", "world"].into(), -3, Value::None);
 	}
+
+	#[test]
+	fn array_move() {
+		fn test(arr: Array, from: i64, to: i64, expected: Array) {
+			assert_eq!(r#move((arr, from, to)).unwrap(), expected.into());
+		}
+		test(vec!["a", "b", "c", "d"].into(), 0, 2, vec!["b", "c", "a", "d"].into());
+		test(vec!["a", "b", "c", "d"].into(), -1, 0, vec!["d", "a", "b", "c"].into());
+		test(vec!["a", "b", "c", "d"].into(), 1, 10, vec!["a", "c", "d", "b"].into());
+		test(Array::new(), 0, 1, Array::new());
+	}
+
+	#[test]
+	fn array_insert_at() {
+		fn test(arr: Array, i: i64, expected: Array) {
+			assert_eq!(insert_at((arr, i, "x".into())).unwrap(), expected.into());
+		}
+		test(vec!["a", "b"].into(), 2, vec!["a", "b", "x"].into());
+		test(vec!["a", "b"].into(), -1, vec!["a", "x", "b"].into());
+		test(vec!["a", "b"].into(), -5, vec!["x", "a", "b"].into());
+		test(Array::new(), 3, vec!["x"].into());
+	}
+
+	#[test]
+	fn array_remove_at() {
+		fn test(arr: Array, i: i64, expected: Array) {
+			assert_eq!(remove_at((arr, i)).unwrap(), expected.into());
+		}
+		test(vec!["a", "b", "c"].into(), -1, vec!["a", "b"].into());
+		test(vec!["a", "b", "c"].into(), 7, vec!["a", "b"].into());
+		test(Array::new(), 0, Array::new());
+	}
+
+	#[test]
+	fn array_take() {
+		fn test(arr: Array, n: i64, expected: Array) {
+			assert_eq!(take((arr, n)).unwrap(), expected.into());
+		}
+		test(vec!["a", "b", "c"].into(), 2, vec!["a", "b"].into());
+		test(vec!["a", "b", "c"].into(), 5, vec!["a", "b", "c"].into());
+		test(vec!["a", "b", "c"].into(), -1, Array::new());
+		test(Array::new(), 2, Array::new());
+	}
+
+	#[test]
+	fn array_skip() {
+		fn test(arr: Array, n: i64, expected: Array) {
+			assert_eq!(skip((arr, n)).unwrap(), expected.into());
+		}
+		test(vec!["a", "b", "c"].into(), 2, vec!["c"].into());
+		test(vec!["a", "b", "c"].into(), 5, Array::new());
+		test(vec!["a", "b", "c"].into(), -1, vec!["a", "b", "c"].into());
+		test(Array::new(), 2, Array::new());
+	}
+
+	#[test]
+	fn array_chunk() {
+		fn test(arr: Array, size: i64, expected: &str) {
+			assert_eq!(chunk((arr, size)).unwrap(), Value::parse(expected));
+		}
+		test(vec![1, 2, 3, 4].into(), 2, "[[1, 2], [3, 4]]");
+		test(vec![1, 2, 3, 4, 5].into(), 2, "[[1, 2], [3, 4], [5]]");
+		test(vec![1, 2, 3].into(), 5, "[[1, 2, 3]]");
+		test(Array::new(), 2, "[]");
+		assert!(chunk((vec![1, 2, 3].into(), 0)).is_err());
+	}
+
+	#[test]
+	fn array_windows() {
+		fn test(arr: Array, size: i64, expected: &str) {
+			assert_eq!(windows((arr, size)).unwrap(), Value::parse(expected));
+		}
+		test(vec![1, 2, 3, 4].into(), 2, "[[1, 2], [2, 3], [3, 4]]");
+		test(vec![1, 2, 3].into(), 3, "[[1, 2, 3]]");
+		test(vec![1, 2, 3].into(), 5, "[]");
+		assert!(windows((vec![1, 2, 3].into(), 0)).is_err());
+	}
 }
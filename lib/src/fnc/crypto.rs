@@ -1,5 +1,8 @@
 use crate::err::Error;
 use crate::sql::value::Value;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Nonce};
+use base64_lib::{engine::general_purpose::STANDARD_NO_PAD, Engine};
 use md5::Digest;
 use md5::Md5;
 use sha1::Sha1;
@@ -38,6 +41,60 @@ pub fn sha512((arg,): (String,)) -> Result<Value, Error> {
 	Ok(val.into())
 }
 
+/// The symmetric cipher used by the encrypt and decrypt functions.
+const AES_256_GCM: &str = "aes-256-gcm";
+
+/// The length in bytes of the nonce prepended to encrypted output.
+const NONCE_LENGTH: usize = 12;
+
+/// The length in bytes of the authentication tag appended to encrypted output.
+const TAG_LENGTH: usize = 16;
+
+fn cipher(name: &str, algo: &str, key: &str) -> Result<Aes256Gcm, Error> {
+	if !algo.eq_ignore_ascii_case(AES_256_GCM) {
+		return Err(Error::InvalidArguments {
+			name: name.to_owned(),
+			message: format!("The first argument must be '{AES_256_GCM}'."),
+		});
+	}
+	Aes256Gcm::new_from_slice(key.as_bytes()).map_err(|_| Error::InvalidArguments {
+		name: name.to_owned(),
+		message: "The key must be exactly 32 bytes long.".to_owned(),
+	})
+}
+
+/// Encrypts a string, returning the random nonce, the ciphertext, and the
+/// authentication tag, concatenated together and encoded as base64.
+pub fn encrypt((algo, key, text): (String, String, String)) -> Result<Value, Error> {
+	let cipher = cipher("crypto::encrypt", &algo, &key)?;
+	let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+	let data = cipher.encrypt(&nonce, text.as_bytes()).map_err(|_| Error::InvalidFunction {
+		name: "crypto::encrypt".to_owned(),
+		message: "The value could not be encrypted.".to_owned(),
+	})?;
+	let mut out = Vec::with_capacity(NONCE_LENGTH + data.len());
+	out.extend_from_slice(&nonce);
+	out.extend(data);
+	Ok(STANDARD_NO_PAD.encode(out).into())
+}
+
+/// Decrypts a value previously produced by `crypto::encrypt`, failing if
+/// the key is wrong or if the value has been modified in any way.
+pub fn decrypt((algo, key, blob): (String, String, String)) -> Result<Value, Error> {
+	let cipher = cipher("crypto::decrypt", &algo, &key)?;
+	let fail = || Error::InvalidFunction {
+		name: "crypto::decrypt".to_owned(),
+		message: "The value could not be decrypted.".to_owned(),
+	};
+	let blob = STANDARD_NO_PAD.decode(blob).map_err(|_| fail())?;
+	if blob.len() < NONCE_LENGTH + TAG_LENGTH {
+		return Err(fail());
+	}
+	let (nonce, data) = blob.split_at(NONCE_LENGTH);
+	let text = cipher.decrypt(Nonce::from_slice(nonce), data).map_err(|_| fail())?;
+	String::from_utf8(text).map(Value::from).map_err(|_| fail())
+}
+
 /// Allowed to cost this much more than default setting for each hash function.
 const COST_ALLOWANCE: u32 = 4;
 
@@ -211,3 +268,143 @@ pub mod scrypt {
 		Ok(hash.into())
 	}
 }
+
+pub mod totp {
+
+	use crate::err::Error;
+	use crate::sql::value::Value;
+	use chrono::Utc;
+	use data_encoding::BASE32_NOPAD;
+	use hmac::{Hmac, Mac};
+	use sha1::Sha1;
+	use subtle::{Choice, ConstantTimeEq};
+
+	/// The default number of seconds for which each code is valid
+	const PERIOD: u64 = 30;
+	/// The default number of digits in each code
+	const DIGITS: u32 = 6;
+	/// The default number of periods either side of the current period to accept codes from
+	const WINDOW: u64 = 1;
+	/// The maximum number of periods either side of the current period to accept codes from
+	const MAX_WINDOW: u64 = 10;
+
+	struct Options {
+		period: u64,
+		digits: u32,
+		window: u64,
+		time: u64,
+	}
+
+	fn options(name: &str, opts: Option<Value>) -> Result<Options, Error> {
+		let invalid = |message: &str| Error::InvalidArguments {
+			name: name.to_owned(),
+			message: message.to_owned(),
+		};
+		let mut out = Options {
+			period: PERIOD,
+			digits: DIGITS,
+			window: WINDOW,
+			time: Utc::now().timestamp().max(0) as u64,
+		};
+		let opts = match opts {
+			None | Some(Value::None | Value::Null) => return Ok(out),
+			Some(Value::Object(v)) => v,
+			Some(_) => return Err(invalid("The options must be an object.")),
+		};
+		for (k, v) in opts.iter() {
+			match (k.as_str(), v) {
+				("period", Value::Number(v)) if v.is_integer() && v.to_int() > 0 => {
+					out.period = v.to_int() as u64
+				}
+				("period", _) => return Err(invalid("The period must be a positive integer.")),
+				("digits", Value::Number(v)) if v.is_integer() && (6..=8).contains(&v.to_int()) => {
+					out.digits = v.to_int() as u32
+				}
+				("digits", _) => return Err(invalid("The digits must be an integer from 6 to 8.")),
+				("window", Value::Number(v))
+					if v.is_integer() && (0..=MAX_WINDOW as i64).contains(&v.to_int()) =>
+				{
+					out.window = v.to_int() as u64
+				}
+				("window", _) => {
+					return Err(invalid(&format!(
+						"The window must be an integer from 0 to {MAX_WINDOW}."
+					)))
+				}
+				("time", Value::Datetime(v)) => out.time = v.0.timestamp().max(0) as u64,
+				("time", _) => return Err(invalid("The time must be a datetime.")),
+				_ => return Err(invalid(&format!("The option '{k}' is not supported."))),
+			}
+		}
+		Ok(out)
+	}
+
+	fn secret(name: &str, secret: &str) -> Result<Vec<u8>, Error> {
+		let secret: String = secret
+			.chars()
+			.filter(|c| !c.is_whitespace() && *c != '=')
+			.map(|c| c.to_ascii_uppercase())
+			.collect();
+		match BASE32_NOPAD.decode(secret.as_bytes()) {
+			Ok(v) if !v.is_empty() => Ok(v),
+			_ => Err(Error::InvalidArguments {
+				name: name.to_owned(),
+				message: "The secret must be a valid base32 string.".to_owned(),
+			}),
+		}
+	}
+
+	/// Computes the HOTP code for a counter, as specified in RFC 4226.
+	fn code(key: &[u8], counter: u64, digits: u32) -> String {
+		// HMAC accepts keys of any length
+		let mut mac = Hmac::<Sha1>::new_from_slice(key).unwrap();
+		mac.update(&counter.to_be_bytes());
+		let hash = mac.finalize().into_bytes();
+		let offset = (hash[hash.len() - 1] & 0x0f) as usize;
+		let bin = u32::from_be_bytes([
+			hash[offset],
+			hash[offset + 1],
+			hash[offset + 2],
+			hash[offset + 3],
+		]) & 0x7fff_ffff;
+		let code = bin % 10u32.pow(digits);
+		format!("{code:0width$}", width = digits as usize)
+	}
+
+	/// Generates a TOTP code for a base32 encoded secret, as specified in RFC 6238.
+	pub fn generate((secret, opts): (String, Option<Value>)) -> Result<Value, Error> {
+		let opts = options("crypto::totp::generate", opts)?;
+		let key = self::secret("crypto::totp::generate", &secret)?;
+		Ok(code(&key, opts.time / opts.period, opts.digits).into())
+	}
+
+	/// Checks a TOTP code against a base32 encoded secret. Codes are accepted from
+	/// any period within the window either side of the current period, and are
+	/// compared in constant time.
+	pub fn verify((secret, token, opts): (String, String, Option<Value>)) -> Result<Value, Error> {
+		let opts = options("crypto::totp::verify", opts)?;
+		let key = self::secret("crypto::totp::verify", &secret)?;
+		let counter = opts.time / opts.period;
+		let mut valid = Choice::from(0);
+		for counter in counter.saturating_sub(opts.window)..=counter.saturating_add(opts.window) {
+			valid |= code(&key, counter, opts.digits).as_bytes().ct_eq(token.as_bytes());
+		}
+		Ok(bool::from(valid).into())
+	}
+
+	#[cfg(test)]
+	mod tests {
+		use super::code;
+
+		#[test]
+		fn rfc_6238_test_vectors() {
+			let key = b"12345678901234567890";
+			assert_eq!(code(key, 59 / 30, 8), "94287082");
+			assert_eq!(code(key, 1111111109 / 30, 8), "07081804");
+			assert_eq!(code(key, 1111111111 / 30, 8), "14050471");
+			assert_eq!(code(key, 1234567890 / 30, 8), "89005924");
+			assert_eq!(code(key, 2000000000 / 30, 8), "69279037");
+			assert_eq!(code(key, 20000000000 / 30, 8), "65353130");
+		}
+	}
+}
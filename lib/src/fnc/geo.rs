@@ -5,6 +5,7 @@ use geo::algorithm::bearing::HaversineBearing;
 use geo::algorithm::centroid::Centroid;
 use geo::algorithm::chamberlain_duquette_area::ChamberlainDuquetteArea;
 use geo::algorithm::haversine_distance::HaversineDistance;
+use geo::algorithm::simplify::Simplify;
 
 pub fn area((arg,): (Value,)) -> Result<Value, Error> {
 	match arg {
@@ -59,6 +60,34 @@ pub fn distance(points: (Value, Value)) -> Result<Value, Error> {
 	})
 }
 
+pub fn simplify((arg, tolerance): (Value, f64)) -> Result<Value, Error> {
+	if tolerance.is_nan() || tolerance < 0.0 {
+		return Err(Error::InvalidArguments {
+			name: String::from("geo::simplify"),
+			message: String::from("The tolerance must be a number greater than or equal to 0."),
+		});
+	}
+	Ok(match arg {
+		Value::Geometry(v) => simplify_geometry(v, &tolerance).into(),
+		_ => Value::None,
+	})
+}
+
+/// Simplifies a geometry using the Ramer-Douglas-Peucker algorithm.
+fn simplify_geometry(geometry: Geometry, epsilon: &f64) -> Geometry {
+	match geometry {
+		Geometry::Line(v) => Geometry::Line(v.simplify(epsilon)),
+		Geometry::Polygon(v) => Geometry::Polygon(v.simplify(epsilon)),
+		Geometry::MultiLine(v) => Geometry::MultiLine(v.simplify(epsilon)),
+		Geometry::MultiPolygon(v) => Geometry::MultiPolygon(v.simplify(epsilon)),
+		Geometry::Collection(v) => {
+			Geometry::Collection(v.into_iter().map(|v| simplify_geometry(v, epsilon)).collect())
+		}
+		// Points can not be simplified
+		v => v,
+	}
+}
+
 pub mod hash {
 
 	use crate::err::Error;
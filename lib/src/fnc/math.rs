@@ -30,8 +30,12 @@ pub fn bottom((array, c): (Vec<Number>, i64)) -> Result<Value, Error> {
 	}
 }
 
-pub fn ceil((arg,): (Number,)) -> Result<Value, Error> {
-	Ok(arg.ceil().into())
+pub fn ceil((arg, places): (Number, Option<i64>)) -> Result<Value, Error> {
+	Ok(match places {
+		Some(places) => arg.ceil_dp(places),
+		None => arg.ceil(),
+	}
+	.into())
 }
 
 pub fn fixed((arg, p): (Number, i64)) -> Result<Value, Error> {
@@ -45,8 +49,12 @@ pub fn fixed((arg, p): (Number, i64)) -> Result<Value, Error> {
 	}
 }
 
-pub fn floor((arg,): (Number,)) -> Result<Value, Error> {
-	Ok(arg.floor().into())
+pub fn floor((arg, places): (Number, Option<i64>)) -> Result<Value, Error> {
+	Ok(match places {
+		Some(places) => arg.floor_dp(places),
+		None => arg.floor(),
+	}
+	.into())
 }
 
 pub fn interquartile((mut array,): (Vec<Number>,)) -> Result<Value, Error> {
@@ -102,8 +110,14 @@ pub fn product((array,): (Vec<Number>,)) -> Result<Value, Error> {
 	Ok(array.into_iter().product::<Number>().into())
 }
 
-pub fn round((arg,): (Number,)) -> Result<Value, Error> {
-	Ok(arg.round().into())
+/// Rounds a number, optionally to a number of decimal places, with midpoints
+/// rounded away from zero. Negative places round to tens, hundreds, and so on.
+pub fn round((arg, places): (Number, Option<i64>)) -> Result<Value, Error> {
+	Ok(match places {
+		Some(places) => arg.round_dp(places),
+		None => arg.round(),
+	}
+	.into())
 }
 
 pub fn spread((array,): (Vec<Number>,)) -> Result<Value, Error> {
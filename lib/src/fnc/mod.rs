@@ -87,6 +87,7 @@ pub fn synchronous(ctx: &Context<'_>, name: &str, args: Vec<Value>) -> Result<Va
 		"array::boolean_not" => array::boolean_not,
 		"array::boolean_or" => array::boolean_or,
 		"array::boolean_xor" => array::boolean_xor,
+		"array::chunk" => array::chunk,
 		"array::clump" => array::clump,
 		"array::combine" => array::combine,
 		"array::complement" => array::complement,
@@ -99,6 +100,7 @@ pub fn synchronous(ctx: &Context<'_>, name: &str, args: Vec<Value>) -> Result<Va
 		"array::flatten" => array::flatten,
 		"array::group" => array::group,
 		"array::insert" => array::insert,
+		"array::insert_at" => array::insert_at,
 		"array::intersect" => array::intersect,
 		"array::join" => array::join,
 		"array::last" => array::last,
@@ -109,15 +111,21 @@ pub fn synchronous(ctx: &Context<'_>, name: &str, args: Vec<Value>) -> Result<Va
 		"array::matches" => array::matches,
 		"array::max" => array::max,
 		"array::min" => array::min,
+		"array::move" => array::r#move,
 		"array::pop" => array::pop,
 		"array::prepend" => array::prepend,
 		"array::push" => array::push,
 		"array::remove" => array::remove,
+		"array::remove_at" => array::remove_at,
 		"array::reverse" => array::reverse,
+		"array::sample" => array::sample,
+		"array::skip" => array::skip,
 		"array::slice" => array::slice,
 		"array::sort" => array::sort,
+		"array::take" => array::take,
 		"array::transpose" => array::transpose,
 		"array::union" => array::union,
+		"array::windows" => array::windows,
 		"array::sort::asc" => array::sort::asc,
 		"array::sort::desc" => array::sort::desc,
 		//
@@ -125,10 +133,14 @@ pub fn synchronous(ctx: &Context<'_>, name: &str, args: Vec<Value>) -> Result<Va
 		//
 		"count" => count::count,
 		//
+		"crypto::decrypt" => crypto::decrypt,
+		"crypto::encrypt" => crypto::encrypt,
 		"crypto::md5" => crypto::md5,
 		"crypto::sha1" => crypto::sha1,
 		"crypto::sha256" => crypto::sha256,
 		"crypto::sha512" => crypto::sha512,
+		"crypto::totp::generate" => crypto::totp::generate,
+		"crypto::totp::verify" => crypto::totp::verify,
 		//
 		"duration::days" => duration::days,
 		"duration::hours" => duration::hours,
@@ -155,6 +167,7 @@ pub fn synchronous(ctx: &Context<'_>, name: &str, args: Vec<Value>) -> Result<Va
 		"geo::bearing" => geo::bearing,
 		"geo::centroid" => geo::centroid,
 		"geo::distance" => geo::distance,
+		"geo::simplify" => geo::simplify,
 		"geo::hash::decode" => geo::hash::decode,
 		"geo::hash::encode" => geo::hash::encode,
 		//
@@ -237,12 +250,15 @@ pub fn synchronous(ctx: &Context<'_>, name: &str, args: Vec<Value>) -> Result<Va
 		//
 		"string::concat" => string::concat,
 		"string::contains" => string::contains,
+		"string::count" => string::count,
 		"string::endsWith" => string::ends_with,
 		"string::join" => string::join,
 		"string::len" => string::len,
 		"string::lowercase" => string::lowercase,
+		"string::matches" => string::matches,
 		"string::repeat" => string::repeat,
 		"string::replace" => string::replace,
+		"string::replace_regex" => string::replace_regex,
 		"string::reverse" => string::reverse,
 		"string::slice" => string::slice,
 		"string::slug" => string::slug,
@@ -253,8 +269,10 @@ pub fn synchronous(ctx: &Context<'_>, name: &str, args: Vec<Value>) -> Result<Va
 		"string::words" => string::words,
 		"string::distance::hamming" => string::distance::hamming,
 		"string::distance::levenshtein" => string::distance::levenshtein,
+		"string::fuzzy::search" => string::fuzzy::search,
 		"string::similarity::fuzzy" => string::similarity::fuzzy,
 		"string::similarity::jaro" => string::similarity::jaro,
+		"string::similarity::jaro_winkler" => string::similarity::jaro_winkler,
 		"string::similarity::smithwaterman" => string::similarity::smithwaterman,
 		//
 		"time::ceil" => time::ceil,
@@ -263,6 +281,7 @@ pub fn synchronous(ctx: &Context<'_>, name: &str, args: Vec<Value>) -> Result<Va
 		"time::format" => time::format,
 		"time::group" => time::group,
 		"time::hour" => time::hour,
+		"time::interval" => time::interval,
 		"time::max" => time::max,
 		"time::min" => time::min,
 		"time::minute" => time::minute,
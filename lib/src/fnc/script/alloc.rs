@@ -0,0 +1,73 @@
+use js::allocator::{Allocator, RawMemPtr, RustAllocator};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// An allocator which enforces the script memory limit itself,
+/// so that running out of memory can be detected reliably.
+pub struct LimitedAllocator {
+	/// The allocator which performs the allocations
+	inner: RustAllocator,
+	/// The maximum number of bytes which can be allocated
+	limit: usize,
+	/// The number of bytes which are currently allocated
+	used: usize,
+	/// Set when an allocation failed because of the limit
+	exceeded: Arc<AtomicBool>,
+}
+
+impl LimitedAllocator {
+	/// Create a new allocator with the specified memory limit
+	pub fn new(limit: usize, exceeded: Arc<AtomicBool>) -> Self {
+		Self {
+			inner: RustAllocator,
+			limit,
+			used: 0,
+			exceeded,
+		}
+	}
+	/// Check whether an allocation of the specified size would exceed the limit
+	fn reserve(&mut self, size: usize) -> bool {
+		if self.used.saturating_add(size) > self.limit {
+			self.exceeded.store(true, Ordering::Relaxed);
+			return false;
+		}
+		true
+	}
+}
+
+unsafe impl Allocator for LimitedAllocator {
+	fn alloc(&mut self, size: usize) -> RawMemPtr {
+		if !self.reserve(size) {
+			return std::ptr::null_mut();
+		}
+		let ptr = self.inner.alloc(size);
+		if !ptr.is_null() {
+			self.used += unsafe { RustAllocator::usable_size(ptr) };
+		}
+		ptr
+	}
+
+	unsafe fn dealloc(&mut self, ptr: RawMemPtr) {
+		self.used -= RustAllocator::usable_size(ptr);
+		self.inner.dealloc(ptr);
+	}
+
+	unsafe fn realloc(&mut self, ptr: RawMemPtr, new_size: usize) -> RawMemPtr {
+		if ptr.is_null() {
+			return self.alloc(new_size);
+		}
+		let old_size = RustAllocator::usable_size(ptr);
+		if new_size > old_size && !self.reserve(new_size - old_size) {
+			return std::ptr::null_mut();
+		}
+		let ptr = self.inner.realloc(ptr, new_size);
+		if !ptr.is_null() {
+			self.used = self.used - old_size + RustAllocator::usable_size(ptr);
+		}
+		ptr
+	}
+
+	unsafe fn usable_size(ptr: RawMemPtr) -> usize {
+		RustAllocator::usable_size(ptr)
+	}
+}
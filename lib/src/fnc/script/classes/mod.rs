@@ -2,12 +2,15 @@ use js::{Class, Ctx, Result};
 
 pub mod duration;
 pub mod record;
+pub mod text;
 pub mod uuid;
 
 pub fn init(ctx: &Ctx<'_>) -> Result<()> {
 	let globals = ctx.globals();
 	Class::<duration::Duration>::define(&globals)?;
 	Class::<record::Record>::define(&globals)?;
+	Class::<text::TextDecoder>::define(&globals)?;
+	Class::<text::TextEncoder>::define(&globals)?;
 	Class::<uuid::Uuid>::define(&globals)?;
 	Ok(())
 }
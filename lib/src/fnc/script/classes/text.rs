@@ -0,0 +1,138 @@
+//! TextEncoder and TextDecoder class implementations, supporting only UTF-8
+
+use js::{
+	class::Trace,
+	prelude::{Coerced, Opt},
+	ArrayBuffer, Ctx, Exception, Object, Result, TypedArray, Value,
+};
+
+/// The only encoding which is supported
+const ENCODING: &str = "utf-8";
+
+/// The byte order mark which is removed when decoding
+const BOM: &[u8] = b"\xEF\xBB\xBF";
+
+#[derive(Clone, Default, Trace)]
+#[js::class]
+pub struct TextEncoder {}
+
+#[js::methods]
+impl TextEncoder {
+	#[qjs(constructor)]
+	pub fn new() -> Self {
+		Self {}
+	}
+
+	#[qjs(get)]
+	pub fn encoding(&self) -> String {
+		ENCODING.to_owned()
+	}
+
+	pub fn encode<'js>(
+		&self,
+		ctx: Ctx<'js>,
+		input: Opt<Coerced<String>>,
+	) -> Result<TypedArray<'js, u8>> {
+		let input = input.into_inner().map(|v| v.0).unwrap_or_default();
+		TypedArray::new(ctx, input.into_bytes())
+	}
+
+	// Convert the object to a string
+	#[qjs(rename = "toString")]
+	pub fn js_to_string(&self) -> String {
+		String::from("[object TextEncoder]")
+	}
+}
+
+#[derive(Clone, Trace)]
+#[js::class]
+pub struct TextDecoder {
+	#[qjs(skip_trace)]
+	fatal: bool,
+	#[qjs(skip_trace)]
+	ignore_bom: bool,
+}
+
+#[js::methods]
+impl TextDecoder {
+	#[qjs(constructor)]
+	pub fn new<'js>(
+		ctx: Ctx<'js>,
+		label: Opt<Coerced<String>>,
+		options: Opt<Object<'js>>,
+	) -> Result<Self> {
+		if let Some(Coerced(label)) = label.into_inner() {
+			let label = label.trim().to_ascii_lowercase();
+			if !matches!(label.as_str(), "utf-8" | "utf8" | "unicode-1-1-utf-8") {
+				return Err(Exception::throw_range(
+					&ctx,
+					&format!("The encoding '{label}' is not supported"),
+				));
+			}
+		}
+		let (fatal, ignore_bom) = match options.into_inner() {
+			Some(obj) => (
+				obj.get::<_, Option<bool>>("fatal")?.unwrap_or_default(),
+				obj.get::<_, Option<bool>>("ignoreBOM")?.unwrap_or_default(),
+			),
+			None => (false, false),
+		};
+		Ok(Self {
+			fatal,
+			ignore_bom,
+		})
+	}
+
+	#[qjs(get)]
+	pub fn encoding(&self) -> String {
+		ENCODING.to_owned()
+	}
+
+	#[qjs(get)]
+	pub fn fatal(&self) -> bool {
+		self.fatal
+	}
+
+	#[qjs(get, rename = "ignoreBOM")]
+	pub fn ignore_bom(&self) -> bool {
+		self.ignore_bom
+	}
+
+	pub fn decode<'js>(&self, ctx: Ctx<'js>, input: Opt<Value<'js>>) -> Result<String> {
+		let Some(input) = input.into_inner() else {
+			return Ok(String::new());
+		};
+		let object = input.as_object().ok_or_else(|| {
+			Exception::throw_type(&ctx, "The input must be an ArrayBuffer or a TypedArray")
+		})?;
+		let bytes = if let Ok(x) = TypedArray::<u8>::from_object(object.clone()) {
+			x.as_bytes().map(<[u8]>::to_vec)
+		} else if let Ok(x) = TypedArray::<i8>::from_object(object.clone()) {
+			x.as_bytes().map(<[u8]>::to_vec)
+		} else if let Some(x) = ArrayBuffer::from_object(object.clone()) {
+			x.as_bytes().map(<[u8]>::to_vec)
+		} else {
+			return Err(Exception::throw_type(
+				&ctx,
+				"The input must be an ArrayBuffer or a TypedArray",
+			));
+		};
+		let bytes =
+			bytes.ok_or_else(|| Exception::throw_type(&ctx, "Buffer is already detached"))?;
+		let bytes = match self.ignore_bom {
+			false => bytes.strip_prefix(BOM).unwrap_or(&bytes),
+			true => &bytes,
+		};
+		match self.fatal {
+			true => String::from_utf8(bytes.to_vec())
+				.map_err(|_| Exception::throw_type(&ctx, "The encoded data was not valid UTF-8")),
+			false => Ok(String::from_utf8_lossy(bytes).into_owned()),
+		}
+	}
+
+	// Convert the object to a string
+	#[qjs(rename = "toString")]
+	pub fn js_to_string(&self) -> String {
+		String::from("[object TextDecoder]")
+	}
+}
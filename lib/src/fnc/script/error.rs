@@ -1,34 +1,75 @@
 use crate::err::Error;
 
+/// The file name given to the compiled script module
+pub(super) const SCRIPT_FILE: &str = "script";
+
 impl From<js::CaughtError<'_>> for Error {
 	fn from(e: js::CaughtError) -> Error {
 		match e {
 			js::CaughtError::Exception(e) => {
-				let line = e.line().unwrap_or(-1);
+				let stack = e.stack();
+				// The function body starts on the first line of the
+				// script module, so script lines map directly to lines
+				// within the function body.
+				let line = stack
+					.as_deref()
+					.and_then(stack_line)
+					.or_else(|| e.line().and_then(|v| usize::try_from(v).ok()).filter(|v| *v > 0));
 				Error::InvalidScript {
 					message: format!(
-						"An exception occurred{}: {}{}",
+						"An exception occurred{}: {}",
 						match e.file() {
-							Some(file) => format!(" at {file}:{line}"),
+							Some(file) => format!(" at {file}:{}", e.line().unwrap_or(-1)),
 							None => String::default(),
 						},
 						match e.message() {
 							Some(message) => message,
 							None => String::default(),
 						},
-						match e.stack() {
-							Some(stack) => format!("\n{stack}"),
-							None => String::default(),
-						}
 					),
+					stack,
+					line,
 				}
 			}
 			js::CaughtError::Error(js::Error::Unknown) => Error::InvalidScript {
 				message: "An unknown error occurred".to_string(),
+				stack: None,
+				line: None,
 			},
 			_ => Error::InvalidScript {
 				message: e.to_string(),
+				stack: None,
+				line: None,
 			},
 		}
 	}
 }
+
+/// Find the line of the first stack frame within the script module
+fn stack_line(stack: &str) -> Option<usize> {
+	let pat = format!("{SCRIPT_FILE}:");
+	stack.lines().find_map(|frame| {
+		let (_, pos) = frame.split_once(&pat)?;
+		let end = pos.find(|c: char| !c.is_ascii_digit()).unwrap_or(pos.len());
+		pos[..end].parse().ok()
+	})
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+
+	#[test]
+	fn stack_line_first_script_frame() {
+		let stack =
+			"    at <anonymous> (native)\n    at default (script:4)\n    at <eval> (script:1)\n";
+		assert_eq!(stack_line(stack), Some(4));
+	}
+
+	#[test]
+	fn stack_line_with_column() {
+		assert_eq!(stack_line("    at default (script:12:7)\n"), Some(12));
+		assert_eq!(stack_line("    at <anonymous> (native)\n"), None);
+	}
+}
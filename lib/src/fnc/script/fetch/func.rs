@@ -3,10 +3,11 @@
 use crate::fnc::script::fetch::{
 	body::{Body, BodyData, BodyKind},
 	classes::{self, Request, RequestInit, Response, ResponseInit, ResponseType},
-	RequestError,
+	util, RequestError,
 };
+use crate::fnc::script::modules::surrealdb::query::{QueryData, QUERY_DATA_PROP_NAME};
 use futures::TryStreamExt;
-use js::{function::Opt, Class, Ctx, Exception, Result, Value};
+use js::{class::OwnedBorrow, function::Opt, Class, Ctx, Exception, Result, Value};
 use reqwest::{
 	header::{HeaderValue, CONTENT_TYPE},
 	redirect, Body as ReqBody,
@@ -22,11 +23,34 @@ pub async fn fetch<'js>(
 	input: Value<'js>,
 	init: Opt<RequestInit<'js>>,
 ) -> Result<Response<'js>> {
+	// Fetch the allowed hosts, which are only available when running within a query.
+	let allowlist = ctx
+		.globals()
+		.get::<_, Option<OwnedBorrow<'js, QueryData<'js>>>>(QUERY_DATA_PROP_NAME)?
+		.and_then(|this| this.context.script_fetch_allowlist());
+	let Some(allowlist) = allowlist else {
+		return Err(Exception::throw_type(
+			&ctx,
+			"The 'fetch' function is disabled. In order to use 'fetch', configure the hosts which embedded scripts are allowed to fetch from.",
+		));
+	};
+
 	// Create a request from the input.
 	let js_req = Request::new(ctx.clone(), input, init)?;
 
 	let url = js_req.url;
 
+	// Ensure that the host is allowed.
+	if !util::is_allowed_host(&allowlist, &url) {
+		return Err(Exception::throw_type(
+			&ctx,
+			&format!(
+				"The host '{}' is not allowed to be fetched from",
+				url.host_str().unwrap_or_default()
+			),
+		));
+	}
+
 	let req = reqwest::Request::new(js_req.init.method, url.clone());
 
 	// SurrealDB Implementation keeps all javascript parts inside the context::with scope so this
@@ -39,6 +63,10 @@ pub async fn fetch<'js>(
 
 	// set the policy for redirecting requests.
 	let policy = redirect::Policy::custom(move |attempt| {
+		// Redirects must also be to an allowed host
+		if !util::is_allowed_host(&allowlist, attempt.url()) {
+			return attempt.error("redirect to a host which is not allowed");
+		}
 		match redirect {
 			classes::RequestRedirect::Follow => {
 				// Fetch spec limits redirect to a max of 20
@@ -119,10 +147,55 @@ pub async fn fetch<'js>(
 	Ok(response)
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "kv-mem"))]
 mod test {
 	use crate::fnc::script::fetch::test::create_test_context;
 
+	/// Create a test context which runs within a query, allowing fetches from the local host
+	macro_rules! create_query_test_context {
+		($ctx:ident => { $($t:tt)* }) => {
+			async {
+				let ds = crate::kvs::Datastore::new("memory").await.unwrap();
+				let txn = ds.transaction(false, false).await.unwrap();
+				let txn = std::sync::Arc::new(futures::lock::Mutex::new(txn));
+				let opt = crate::dbs::Options::default();
+				let mut context = crate::ctx::Context::background();
+				context.add_script_fetch_allowlist(std::sync::Arc::from([String::from("127.0.0.1")]));
+				create_test_context!($ctx => {
+					unsafe {
+						crate::fnc::script::main::create_query_data(&context, &opt, &txn, None, &$ctx)
+					}
+					.unwrap();
+					$($t)*
+				})
+				.await
+			}
+		};
+	}
+
+	#[tokio::test]
+	async fn test_fetch_disabled() {
+		use js::{promise::Promise, CatchResultExt, CaughtError};
+
+		create_test_context!(ctx => {
+			let res = ctx
+				.eval::<Promise<js::Value>,_>("fetch('http://127.0.0.1/hello')")
+				.catch(&ctx)
+				.unwrap()
+				.await
+				.catch(&ctx);
+			match res {
+				Ok(_) => panic!("didn't return an error when it should"),
+				Err(CaughtError::Exception(e)) => {
+					let message = e.message().expect("exception didn't have a message");
+					assert_eq!(message,"The 'fetch' function is disabled. In order to use 'fetch', configure the hosts which embedded scripts are allowed to fetch from.");
+				}
+				Err(_) => panic!("didn't return an exception"),
+			}
+		})
+		.await;
+	}
+
 	#[tokio::test]
 	async fn test_fetch_get() {
 		use js::{promise::Promise, CatchResultExt};
@@ -143,7 +216,7 @@ mod test {
 
 		let server_ref = &server;
 
-		create_test_context!(ctx => {
+		create_query_test_context!(ctx => {
 			ctx.globals().set("SERVER_URL",server_ref.uri()).unwrap();
 
 			ctx.eval::<Promise<()>,_>(r#"
@@ -185,7 +258,7 @@ mod test {
 
 		let server_ref = &server;
 
-		create_test_context!(ctx => {
+		create_query_test_context!(ctx => {
 			ctx.globals().set("SERVER_URL",server_ref.uri()).unwrap();
 
 			ctx.eval::<Promise<()>,_>(r#"
@@ -233,7 +306,7 @@ mod test {
 
 		let server_ref = &server;
 
-		create_test_context!(ctx => {
+		create_query_test_context!(ctx => {
 			ctx.globals().set("SERVER_URL",server_ref.uri()).unwrap();
 
 			ctx.eval::<Promise<()>,_>(r#"
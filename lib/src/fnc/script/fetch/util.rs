@@ -1,3 +1,21 @@
+use url::Url;
+
+/// Returns wether the host of the url is contained in the allowlist. Each entry in
+/// the allowlist is a host name, optionally followed by a port.
+pub fn is_allowed_host(allowlist: &[String], url: &Url) -> bool {
+	let Some(host) = url.host_str() else {
+		return false;
+	};
+	let port = url.port_or_known_default();
+	allowlist.iter().any(|entry| {
+		let (name, expected) = match entry.rsplit_once(':').map(|(n, p)| (n, p.parse::<u16>())) {
+			Some((name, Ok(p))) => (name, Some(p)),
+			_ => (entry.as_str(), None),
+		};
+		name.eq_ignore_ascii_case(host) && expected.map_or(true, |p| Some(p) == port)
+	})
+}
+
 /// Returns wether the status code is an null body status
 pub fn is_null_body_status(status: u16) -> bool {
 	matches!(status, 101 | 103 | 204 | 205 | 304)
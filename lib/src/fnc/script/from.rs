@@ -1,16 +1,19 @@
 use super::classes;
 use crate::sql::array::Array;
+use crate::sql::bytes::Bytes;
 use crate::sql::datetime::Datetime;
 use crate::sql::object::Object;
 use crate::sql::value::Value;
 use crate::sql::Id;
 use chrono::{TimeZone, Utc};
 use js::prelude::This;
+use js::ArrayBuffer;
 use js::Ctx;
 use js::Error;
 use js::Exception;
 use js::FromAtom;
 use js::FromJs;
+use js::TypedArray;
 
 fn check_nul(s: &str) -> Result<(), Error> {
 	if s.contains('\0') {
@@ -48,11 +51,9 @@ impl<'js> FromJs<'js> for Value {
 				// Extract the value as an object
 				let v = val.into_object().unwrap();
 				// Check to see if this object is an error
-				if v.is_error() {
-					let e: String = v.get("message")?;
-					let (Ok(e) | Err(e)) =
-						Exception::from_message(ctx.clone(), &e).map(|x| x.throw());
-					return Err(e);
+				if let Some(e) = Exception::from_object(v.clone()) {
+					// Rethrow the original error to keep its stack trace
+					return Err(e.throw());
 				}
 				// Check to see if this object is a record
 				if (v).instance_of::<classes::record::Record>() {
@@ -85,6 +86,20 @@ impl<'js> FromJs<'js> for Value {
 						None => Ok(Value::None),
 					};
 				}
+				// Check to see if this object is a byte array
+				if let Ok(v) = TypedArray::<u8>::from_object(v.clone()) {
+					return match v.as_bytes() {
+						Some(v) => Ok(Bytes::from(v.to_vec()).into()),
+						None => Ok(Value::None),
+					};
+				}
+				// Check to see if this object is an array buffer
+				if let Some(v) = ArrayBuffer::from_object(v.clone()) {
+					return match v.as_bytes() {
+						Some(v) => Ok(Bytes::from(v.to_vec()).into()),
+						None => Ok(Value::None),
+					};
+				}
 				// Check to see if this object is a date
 				let date: js::Object = ctx.globals().get("Date")?;
 				if (v).is_instance_of(&date) {
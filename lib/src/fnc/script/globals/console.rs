@@ -1,35 +1,70 @@
 // Specify the imports
+use crate::cnf::SCRIPT_LOG_LINE_LIMIT;
+use crate::fnc::script::modules::surrealdb::query::{QueryData, QUERY_DATA_PROP_NAME};
 use crate::sql::value::Value;
-use js::{prelude::Rest, Ctx, Object, Result};
+use js::{class::OwnedBorrow, prelude::Rest, Ctx, Object, Result};
 /// Log the input values as INFO
 #[js::function]
-pub fn log(args: Rest<Value>) {
-	info!("{}", args.iter().map(|v| v.to_raw_string()).collect::<Vec<String>>().join(" "));
+pub fn log<'js>(ctx: Ctx<'js>, args: Rest<Value>) {
+	let msg = join(args);
+	info!("{msg}");
+	capture(&ctx, "INFO", msg);
 }
 /// Log the input values as INFO
 #[js::function]
-pub fn info(args: Rest<Value>) {
-	info!("{}", args.iter().map(|v| v.to_raw_string()).collect::<Vec<String>>().join(" "));
+pub fn info<'js>(ctx: Ctx<'js>, args: Rest<Value>) {
+	let msg = join(args);
+	info!("{msg}");
+	capture(&ctx, "INFO", msg);
 }
 /// Log the input values as WARN
 #[js::function]
-pub fn warn(args: Rest<Value>) {
-	warn!("{}", args.iter().map(|v| v.to_raw_string()).collect::<Vec<String>>().join(" "));
+pub fn warn<'js>(ctx: Ctx<'js>, args: Rest<Value>) {
+	let msg = join(args);
+	warn!("{msg}");
+	capture(&ctx, "WARN", msg);
 }
 /// Log the input values as ERROR
 #[js::function]
-pub fn error(args: Rest<Value>) {
-	error!("{}", args.iter().map(|v| v.to_raw_string()).collect::<Vec<String>>().join(" "));
+pub fn error<'js>(ctx: Ctx<'js>, args: Rest<Value>) {
+	let msg = join(args);
+	error!("{msg}");
+	capture(&ctx, "ERROR", msg);
 }
 /// Log the input values as DEBUG
 #[js::function]
-pub fn debug(args: Rest<Value>) {
-	debug!("{}", args.iter().map(|v| v.to_raw_string()).collect::<Vec<String>>().join(" "));
+pub fn debug<'js>(ctx: Ctx<'js>, args: Rest<Value>) {
+	let msg = join(args);
+	debug!("{msg}");
+	capture(&ctx, "DEBUG", msg);
 }
 /// Log the input values as TRACE
 #[js::function]
-pub fn trace(args: Rest<Value>) {
-	trace!("{}", args.iter().map(|v| v.to_raw_string()).collect::<Vec<String>>().join(" "));
+pub fn trace<'js>(ctx: Ctx<'js>, args: Rest<Value>) {
+	let msg = join(args);
+	trace!("{msg}");
+	capture(&ctx, "TRACE", msg);
+}
+/// Join the input values into a single log line
+fn join(args: Rest<Value>) -> String {
+	args.iter().map(|v| v.to_raw_string()).collect::<Vec<String>>().join(" ")
+}
+/// Send the log line to the query response, if it is being captured.
+/// Any lines over the capture limit are dropped, and long lines are truncated.
+fn capture(ctx: &Ctx<'_>, level: &str, mut msg: String) {
+	if let Ok(this) = ctx.globals().get::<_, OwnedBorrow<QueryData>>(QUERY_DATA_PROP_NAME) {
+		if let Some(chn) = this.context.script_logs() {
+			if msg.len() > SCRIPT_LOG_LINE_LIMIT {
+				let mut end = SCRIPT_LOG_LINE_LIMIT;
+				while !msg.is_char_boundary(end) {
+					end -= 1;
+				}
+				msg.truncate(end);
+				msg.push_str("...");
+			}
+			let _ = chn.try_send(format!("[{level}] {msg}"));
+		}
+	}
 }
 
 pub fn console<'js>(ctx: &Ctx<'js>) -> Result<Object<'js>> {
@@ -3,6 +3,7 @@ use crate::sql::number::decimal_is_integer;
 use crate::sql::number::Number;
 use crate::sql::value::Value;
 use js::Array;
+use js::ArrayBuffer;
 use js::Class;
 use js::Ctx;
 use js::Error;
@@ -55,6 +56,7 @@ impl<'js> IntoJs<'js> for &Value {
 				},
 			)?
 			.into_value()),
+			Value::Bytes(v) => ArrayBuffer::new(ctx.clone(), v.to_vec())?.into_js(ctx),
 			Value::Array(v) => {
 				let x = Array::new(ctx.clone())?;
 				for (i, v) in v.iter().enumerate() {
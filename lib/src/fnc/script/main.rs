@@ -1,4 +1,6 @@
+use super::alloc::LimitedAllocator;
 use super::classes;
+use super::error;
 use super::fetch;
 use super::globals;
 use super::modules;
@@ -21,6 +23,9 @@ use js::Class;
 use js::Ctx;
 use js::Function;
 use js::Module;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use trice::Instant;
 
 /// Insert query data into the context,
 ///
@@ -65,15 +70,28 @@ pub async fn run(
 	if context.is_done() {
 		return Ok(Value::None);
 	}
+	// Explicitly set max memory size
+	let limit = context.script_memory_limit();
+	let exhausted = Arc::new(AtomicBool::new(false));
+	let allocator = LimitedAllocator::new(limit, exhausted.clone());
 	// Create an JavaScript context
-	let run = js::AsyncRuntime::new().unwrap();
+	let run = js::AsyncRuntime::new_with_alloc(allocator).unwrap();
 	// Explicitly set max stack size to 256 KiB
 	run.set_max_stack_size(262_144).await;
-	// Explicitly set max memory size to 2 MB
-	run.set_memory_limit(2_000_000).await;
-	// Ensure scripts are cancelled with context
+	// Ensure scripts are cancelled with context,
+	// or when the script execution timeout elapses
 	let cancellation = context.cancellation();
-	let handler = Box::new(move || cancellation.is_done());
+	let timeout = context.script_timeout();
+	let deadline = timeout.map(|v| Instant::now() + v);
+	let timedout = Arc::new(AtomicBool::new(false));
+	let expired = timedout.clone();
+	let handler = Box::new(move || {
+		if matches!(deadline, Some(v) if Instant::now() >= v) {
+			expired.store(true, Ordering::Relaxed);
+			return true;
+		}
+		cancellation.is_done()
+	});
 	run.set_interrupt_handler(Some(handler)).await;
 	// Create an execution context
 	let ctx = js::AsyncContext::full(&run).await.unwrap();
@@ -107,7 +125,7 @@ pub async fn run(
 			// Register the special SurrealDB types as classes
 			classes::init(&ctx)?;
 			// Attempt to compile the script
-			let res = ctx.clone().compile("script", src)?;
+			let res = ctx.clone().compile(error::SCRIPT_FILE, src)?;
 			// Attempt to fetch the main export
 			let fnc = res.get::<_, Function>("default")?;
 			// Extract the doc if any
@@ -117,7 +135,17 @@ pub async fn run(
 			promise.await
 		}.await;
 
-		res.catch(&ctx).map_err(Error::from)
+		res.catch(&ctx).map_err(|e| match e {
+			// The script ran for too long
+			_ if timedout.load(Ordering::Relaxed) => Error::ScriptTimeout {
+				timeout: timeout.unwrap_or_default(),
+			},
+			// The runtime ran out of memory
+			_ if exhausted.load(Ordering::Relaxed) => Error::ScriptMemoryLimit {
+				limit,
+			},
+			e => Error::from(e),
+		})
 	})
 	.await
 }
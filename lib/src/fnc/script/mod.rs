@@ -2,6 +2,7 @@
 
 pub use main::run;
 
+mod alloc;
 mod classes;
 mod error;
 mod from;
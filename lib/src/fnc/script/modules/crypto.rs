@@ -0,0 +1,110 @@
+use crate::sql::uuid::Uuid;
+use crate::sql::value::Value;
+use js::{
+	module::{Declarations, Exports, ModuleDef},
+	ArrayBuffer, Ctx, Exception, Function, Object, Result, TypedArray,
+};
+use rand::Rng;
+use sha1::Sha1;
+use sha2::{Digest, Sha256, Sha384, Sha512};
+
+/// The maximum number of random bytes which can be generated at once
+const MAX_RANDOM_BYTES: usize = 65_536;
+
+/// Generate a random version 4 UUID
+#[js::function]
+pub fn random_uuid() -> Value {
+	Uuid::new_v4().into()
+}
+/// Generate an array of cryptographically secure random bytes
+#[js::function]
+pub fn random_bytes<'js>(ctx: Ctx<'js>, size: i32) -> Result<TypedArray<'js, u8>> {
+	let size = match usize::try_from(size) {
+		Ok(v) if v <= MAX_RANDOM_BYTES => v,
+		_ => {
+			return Err(Exception::throw_range(
+				&ctx,
+				&format!("The number of bytes must be between 0 and {MAX_RANDOM_BYTES}"),
+			))
+		}
+	};
+	let mut bytes = vec![0u8; size];
+	rand::thread_rng().fill(&mut bytes[..]);
+	TypedArray::new(ctx, bytes)
+}
+/// Compute the digest of a string, ArrayBuffer, or TypedArray
+#[js::function]
+pub async fn digest<'js>(
+	ctx: Ctx<'js>,
+	algorithm: String,
+	data: js::Value<'js>,
+) -> Result<ArrayBuffer<'js>> {
+	let data = bytes(&ctx, data)?;
+	let hash = match algorithm.to_ascii_uppercase().as_str() {
+		"SHA-1" => Sha1::digest(&data).to_vec(),
+		"SHA-256" => Sha256::digest(&data).to_vec(),
+		"SHA-384" => Sha384::digest(&data).to_vec(),
+		"SHA-512" => Sha512::digest(&data).to_vec(),
+		_ => {
+			return Err(Exception::throw_type(
+				&ctx,
+				&format!("The digest algorithm '{algorithm}' is not supported"),
+			))
+		}
+	};
+	ArrayBuffer::new(ctx, hash)
+}
+/// Get the bytes of a string, ArrayBuffer, or TypedArray
+fn bytes<'js>(ctx: &Ctx<'js>, data: js::Value<'js>) -> Result<Vec<u8>> {
+	if let Some(v) = data.as_string() {
+		return Ok(v.to_string()?.into_bytes());
+	}
+	let bytes = data.as_object().and_then(|v| {
+		if let Ok(x) = TypedArray::<u8>::from_object(v.clone()) {
+			Some(x.as_bytes().map(<[u8]>::to_vec))
+		} else if let Ok(x) = TypedArray::<i8>::from_object(v.clone()) {
+			Some(x.as_bytes().map(<[u8]>::to_vec))
+		} else {
+			ArrayBuffer::from_object(v.clone()).map(|x| x.as_bytes().map(<[u8]>::to_vec))
+		}
+	});
+	match bytes {
+		Some(Some(v)) => Ok(v),
+		Some(None) => Err(Exception::throw_type(ctx, "Buffer is already detached")),
+		None => Err(Exception::throw_type(
+			ctx,
+			"The data must be a string, an ArrayBuffer, or a TypedArray",
+		)),
+	}
+}
+
+pub struct Package;
+
+impl ModuleDef for Package {
+	fn declare(declare: &mut Declarations) -> Result<()> {
+		declare.declare("default")?;
+		declare.declare("randomUUID")?;
+		declare.declare("randomBytes")?;
+		declare.declare("subtle")?;
+		Ok(())
+	}
+
+	fn evaluate<'js>(ctx: &Ctx<'js>, exports: &mut Exports<'js>) -> Result<()> {
+		let default = Object::new(ctx.clone())?;
+		let random_uuid = Function::new(ctx.clone(), js_random_uuid)?.with_name("randomUUID")?;
+		exports.export("randomUUID", random_uuid.clone())?;
+		default.set("randomUUID", random_uuid)?;
+
+		let random_bytes = Function::new(ctx.clone(), js_random_bytes)?.with_name("randomBytes")?;
+		exports.export("randomBytes", random_bytes.clone())?;
+		default.set("randomBytes", random_bytes)?;
+
+		let subtle = Object::new(ctx.clone())?;
+		subtle.set("digest", Function::new(ctx.clone(), js_digest)?.with_name("digest")?)?;
+		exports.export("subtle", subtle.clone())?;
+		default.set("subtle", subtle)?;
+
+		exports.export("default", default)?;
+		Ok(())
+	}
+}
@@ -1,14 +1,16 @@
+pub mod crypto;
 pub mod os;
 pub mod surrealdb;
 
 use js::loader::{BuiltinResolver, ModuleLoader};
 
 pub fn resolver() -> BuiltinResolver {
-	BuiltinResolver::default().with_module("os").with_module("surrealdb")
+	BuiltinResolver::default().with_module("crypto").with_module("os").with_module("surrealdb")
 }
 
 pub fn loader() -> ModuleLoader {
 	ModuleLoader::default()
+		.with_module("crypto", crypto::Package)
 		.with_module("os", os::Package)
 		.with_module("surrealdb", surrealdb::Package)
 }
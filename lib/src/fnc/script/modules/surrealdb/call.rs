@@ -0,0 +1,27 @@
+use js::{class::OwnedBorrow, prelude::Rest, Ctx, Exception, Result};
+
+use super::query::{QueryData, QUERY_DATA_PROP_NAME};
+use crate::{
+	ctx::Context,
+	sql::{Function, Value},
+};
+
+/// The function which runs a user-defined function.
+///
+/// The function name can be specified with or without the `fn::` prefix.
+#[js::function]
+pub async fn call<'js>(ctx: Ctx<'js>, name: String, args: Rest<Value>) -> Result<Value> {
+	let this = ctx.globals().get::<_, OwnedBorrow<'js, QueryData<'js>>>(QUERY_DATA_PROP_NAME)?;
+
+	let name = match name.strip_prefix("fn::") {
+		Some(name) => name.to_owned(),
+		None => name,
+	};
+
+	let context = Context::new(this.context);
+	let value = Function::Custom(name, args.0)
+		.compute(&context, this.opt, this.txn, this.doc)
+		.await
+		.map_err(|e| Exception::throw_message(&ctx, &e.to_string()))?;
+	Result::Ok(value)
+}
@@ -16,6 +16,7 @@ impl_module_def!(
 	"boolean_not" => run,
 	"boolean_or" => run,
 	"boolean_xor" => run,
+	"chunk" => run,
 	"clump" => run,
 	"combine" => run,
 	"complement" => run,
@@ -28,6 +29,7 @@ impl_module_def!(
 	"flatten" => run,
 	"group" => run,
 	"insert" => run,
+	"insert_at" => run,
 	"intersect" => run,
 	"join" => run,
 	"last" => run,
@@ -38,13 +40,19 @@ impl_module_def!(
 	"matches" => run,
 	"max" => run,
 	"min" => run,
+	"move" => run,
 	"pop" => run,
 	"push" => run,
 	"prepend" => run,
 	"remove" => run,
+	"remove_at" => run,
 	"reverse" => run,
+	"sample" => run,
+	"skip" => run,
 	"slice" => run,
 	"sort" => (sort::Package),
+	"take" => run,
 	"transpose" => run,
-	"union" => run
+	"union" => run,
+	"windows" => run
 );
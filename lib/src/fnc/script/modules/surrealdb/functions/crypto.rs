@@ -5,12 +5,15 @@ mod argon2;
 mod bcrypt;
 mod pbkdf2;
 mod scrypt;
+mod totp;
 
 pub struct Package;
 
 impl_module_def!(
 	Package,
 	"crypto",
+	"decrypt" => run,
+	"encrypt" => run,
 	"md5" => run,
 	"sha1" => run,
 	"sha256" => run,
@@ -18,5 +21,6 @@ impl_module_def!(
 	"argon2" => (argon2::Package),
 	"bcrypt" => (bcrypt::Package),
 	"pbkdf2" => (pbkdf2::Package),
-	"scrypt" => (scrypt::Package)
+	"scrypt" => (scrypt::Package),
+	"totp" => (totp::Package)
 );
@@ -12,5 +12,6 @@ impl_module_def!(
 	"bearing" => run,
 	"centroid" => run,
 	"distance" => run,
-	"hash" => (hash::Package)
+	"hash" => (hash::Package),
+	"simplify" => run
 );
@@ -2,6 +2,7 @@ use super::run;
 use crate::fnc::script::modules::impl_module_def;
 
 mod distance;
+mod fuzzy;
 mod similarity;
 pub struct Package;
 
@@ -10,13 +11,17 @@ impl_module_def!(
 	"string",
 	"concat" => run,
 	"contains" => run,
+	"count" => run,
 	"distance" => (distance::Package),
 	"endsWith" => run,
+	"fuzzy" => (fuzzy::Package),
 	"join" => run,
 	"len" => run,
 	"lowercase" => run,
+	"matches" => run,
 	"repeat" => run,
 	"replace" => run,
+	"replace_regex" => run,
 	"reverse" => run,
 	"similarity" => (similarity::Package),
 	"slice" => run,
@@ -14,6 +14,7 @@ impl_module_def!(
 	"format" => run,
 	"group" => run,
 	"hour" => run,
+	"interval" => run,
 	"max" => run,
 	"min" => run,
 	"mins" => run,
@@ -1,8 +1,10 @@
 use crate::fnc::script::modules::impl_module_def;
 use js::{module::ModuleDef, Class, Ctx, Function, Module, Result, Value};
 
+mod call;
 mod functions;
 pub mod query;
+mod session;
 
 pub struct Package;
 
@@ -12,6 +14,8 @@ impl ModuleDef for Package {
 		decls.declare("functions")?;
 		decls.declare("version")?;
 		decls.declare("query")?;
+		decls.declare("call")?;
+		decls.declare("session")?;
 		decls.declare("Query")?;
 		Ok(())
 	}
@@ -30,6 +34,14 @@ impl ModuleDef for Package {
 		exports.export("query", query_func.clone())?;
 		default.set("query", query_func)?;
 
+		let call_func = Function::new(ctx.clone(), call::js_call)?.with_name("call")?;
+		exports.export("call", call_func.clone())?;
+		default.set("call", call_func)?;
+
+		let session = session::session(ctx)?;
+		exports.export("session", session.clone())?;
+		default.set("session", session)?;
+
 		let query_object = impl_module_def!(
 			ctx,
 			"surrealdb",
@@ -1,4 +1,5 @@
 use std::collections::BTreeMap;
+use std::time::Duration;
 
 use crate::sql::{self, subquery::Subquery, Value as SurValue};
 
@@ -30,7 +31,9 @@ impl QueryVariables {
 		let mut res = Self::new();
 
 		// TODO Set and Map,
-		if let Some(array) = val.as_array() {
+		if val.is_undefined() || val.is_null() {
+			// no variables were specified
+		} else if let Some(array) = val.as_array() {
 			// a sequence<sequence<String>>;
 			for v in array.iter::<Array>() {
 				let v = match v {
@@ -90,6 +93,41 @@ impl<'js> FromJs<'js> for QueryVariables {
 	}
 }
 
+#[derive(Default, Clone)]
+pub struct QueryOptions {
+	/// The maximum duration of the query
+	pub timeout: Option<Duration>,
+}
+
+impl QueryOptions {
+	pub fn from_value<'js>(ctx: &Ctx<'js>, val: Value<'js>) -> Result<Self> {
+		static INVALID_ERROR: &str = "Query options must be an object";
+		static INVALID_TIMEOUT: &str =
+			"Query timeout must be a positive number of milliseconds or a Duration";
+		let Some(obj) = val.as_object() else {
+			return Err(Exception::throw_type(ctx, INVALID_ERROR));
+		};
+		let timeout = match obj.get::<_, SurValue>("timeout")? {
+			SurValue::None | SurValue::Null => None,
+			SurValue::Duration(v) => Some(v.0),
+			SurValue::Number(v) => match Duration::try_from_secs_f64(v.to_float() / 1000.0) {
+				Ok(v) => Some(v),
+				Err(_) => return Err(Exception::throw_type(ctx, INVALID_TIMEOUT)),
+			},
+			_ => return Err(Exception::throw_type(ctx, INVALID_TIMEOUT)),
+		};
+		Ok(QueryOptions {
+			timeout,
+		})
+	}
+}
+
+impl<'js> FromJs<'js> for QueryOptions {
+	fn from_js(ctx: &Ctx<'js>, value: Value<'js>) -> Result<Self> {
+		QueryOptions::from_value(ctx, value)
+	}
+}
+
 #[js::methods]
 impl Query {
 	#[qjs(constructor)]
@@ -110,6 +148,8 @@ impl Query {
 		format!("{}", self.query)
 	}
 
+	/// Bind a variable to the query. The `Record`, `Duration`, `Uuid`, and `Date`
+	/// types are bound as the corresponding SurrealQL values, rather than strings.
 	pub fn bind(&mut self, key: Coerced<String>, value: SurValue) {
 		self.vars.get_or_insert_with(BTreeMap::new).insert(key.0, value);
 	}
@@ -8,8 +8,10 @@ use crate::{
 	ctx::Context,
 	dbs::{Attach, Options, Transaction},
 	doc::CursorDoc,
+	err::Error,
 	sql::Value as SurValue,
 };
+use trice::Instant;
 
 #[allow(clippy::module_inception)]
 mod classes;
@@ -55,6 +57,7 @@ pub async fn query<'js>(
 	ctx: Ctx<'js>,
 	query: Value<'js>,
 	variables: Opt<classes::QueryVariables>,
+	options: Opt<classes::QueryOptions>,
 ) -> Result<SurValue> {
 	let this = ctx.globals().get::<_, OwnedBorrow<'js, QueryData<'js>>>(QUERY_DATA_PROP_NAME)?;
 
@@ -69,17 +72,30 @@ pub async fn query<'js>(
 		query_store.insert(classes::Query::new(ctx.clone(), query_text, variables)?)
 	};
 
-	let context = Context::new(this.context);
+	let options = options.into_inner().unwrap_or_default();
+
+	let mut context = Context::new(this.context);
+	if let Some(timeout) = options.timeout {
+		match Instant::now().checked_add(timeout) {
+			Some(deadline) => context.add_deadline(deadline),
+			None => return Err(Exception::throw_range(&ctx, "Query timeout is too large")),
+		}
+	}
 	let context = query
 		.clone()
 		.vars
 		.attach(context)
 		.map_err(|e| Exception::throw_message(&ctx, &e.to_string()))?;
 
-	let value = query
-		.query
-		.compute(&context, this.opt, this.txn, this.doc)
-		.await
-		.map_err(|e| Exception::throw_message(&ctx, &e.to_string()))?;
+	let value = query.query.compute(&context, this.opt, this.txn, this.doc).await;
+
+	// Catch the query timeout
+	if context.is_timedout() {
+		let e = Exception::from_message(ctx.clone(), &Error::QueryTimedout.to_string())?;
+		e.as_object().set("name", "TimeoutError")?;
+		return Err(e.throw());
+	}
+
+	let value = value.map_err(|e| Exception::throw_message(&ctx, &e.to_string()))?;
 	Result::Ok(value)
 }
@@ -0,0 +1,56 @@
+use js::{class::OwnedBorrow, Ctx, Function, Object, Result};
+
+use super::query::{QueryData, QUERY_DATA_PROP_NAME};
+use crate::{
+	dbs::Auth,
+	sql::{
+		paths::{DB, ID, IP, NS, OR, SC, SD, TK},
+		Part,
+	},
+};
+
+/// Create a read-only object describing the session which is running the script.
+pub fn session<'js>(ctx: &Ctx<'js>) -> Result<Object<'js>> {
+	let this = ctx.globals().get::<_, OwnedBorrow<'js, QueryData<'js>>>(QUERY_DATA_PROP_NAME)?;
+	let session = this.context.value("session").cloned().unwrap_or_default();
+
+	let fields: [(&str, &[Part]); 8] = [
+		("ns", NS.as_ref()),
+		("db", DB.as_ref()),
+		("id", ID.as_ref()),
+		("ip", IP.as_ref()),
+		("origin", OR.as_ref()),
+		("sc", SC.as_ref()),
+		("sd", SD.as_ref()),
+		("tk", TK.as_ref()),
+	];
+
+	let object = Object::new(ctx.clone())?;
+	for (name, path) in fields {
+		object.set(name, session.pick(path))?;
+	}
+	let level = match this.opt.auth.as_ref() {
+		Auth::No => "no",
+		Auth::Kv => "kv",
+		Auth::Ns(_) => "ns",
+		Auth::Db(_, _) => "db",
+		Auth::Sc(_, _, _) => "sc",
+	};
+	object.set("level", level)?;
+
+	freeze(ctx, &object)?;
+	Ok(object)
+}
+
+/// Freeze an object, and every object nested within it.
+fn freeze<'js>(ctx: &Ctx<'js>, object: &Object<'js>) -> Result<()> {
+	for prop in object.props::<String, js::Value>() {
+		let (_, value) = prop?;
+		if let Some(nested) = value.as_object() {
+			freeze(ctx, nested)?;
+		}
+	}
+	let freeze: Function = ctx.globals().get::<_, Object>("Object")?.get("freeze")?;
+	freeze.call::<_, js::Value>((object.clone(),))?;
+	Ok(())
+}
@@ -1,6 +1,7 @@
 use crate::err::Error;
 use crate::fnc::util::string;
 use crate::sql::value::Value;
+use unicode_segmentation::UnicodeSegmentation;
 
 /// Returns `true` if a string of this length is too much to allocate.
 fn limit(name: &str, n: usize) -> Result<(), Error> {
@@ -25,6 +26,40 @@ pub fn contains((val, check): (String, String)) -> Result<Value, Error> {
 	Ok(val.contains(&check).into())
 }
 
+pub fn count((val, sub, opts): (String, String, Option<Value>)) -> Result<Value, Error> {
+	let invalid = |message: &str| Error::InvalidArguments {
+		name: String::from("string::count"),
+		message: message.to_owned(),
+	};
+	if sub.is_empty() {
+		return Err(invalid("The substring to count must not be empty."));
+	}
+	let overlapping = match opts {
+		None | Some(Value::None | Value::Null) => false,
+		Some(Value::Object(v)) => match v.get("overlapping") {
+			None => false,
+			Some(Value::Bool(v)) => *v,
+			Some(_) => return Err(invalid("The overlapping option must be a boolean.")),
+		},
+		Some(_) => return Err(invalid("The options must be an object.")),
+	};
+	let num = match overlapping {
+		true => {
+			// Restart the search after the first character of each match
+			let step = sub.chars().next().map(char::len_utf8).unwrap_or(1);
+			let mut num = 0;
+			let mut pos = 0;
+			while let Some(i) = val[pos..].find(&sub) {
+				num += 1;
+				pos += i + step;
+			}
+			num
+		}
+		false => val.matches(&sub).count(),
+	};
+	Ok((num as i64).into())
+}
+
 pub fn ends_with((val, chr): (String, String)) -> Result<Value, Error> {
 	Ok(val.ends_with(&chr).into())
 }
@@ -59,6 +94,11 @@ pub fn lowercase((string,): (String,)) -> Result<Value, Error> {
 	Ok(string.to_lowercase().into())
 }
 
+pub fn matches((val, pattern): (String, Value)) -> Result<Value, Error> {
+	let regex = string::pattern::regex("string::matches", pattern)?;
+	Ok(regex.is_match(&val).into())
+}
+
 pub fn repeat((val, num): (String, usize)) -> Result<Value, Error> {
 	limit("string::repeat", val.len().saturating_mul(num))?;
 	Ok(val.repeat(num).into())
@@ -75,8 +115,15 @@ pub fn replace((val, old, new): (String, String, String)) -> Result<Value, Error
 	Ok(val.replace(&old, &new).into())
 }
 
+pub fn replace_regex((val, pattern, new): (String, Value, String)) -> Result<Value, Error> {
+	let regex = string::pattern::regex("string::replace_regex", pattern)?;
+	let out = regex.replace_all(&val, new.as_str());
+	limit("string::replace_regex", out.len())?;
+	Ok(out.into_owned().into())
+}
+
 pub fn reverse((string,): (String,)) -> Result<Value, Error> {
-	Ok(string.chars().rev().collect::<String>().into())
+	Ok(string.graphemes(true).rev().collect::<String>().into())
 }
 
 pub fn slice((val, beg, lim): (String, Option<isize>, Option<isize>)) -> Result<Value, Error> {
@@ -151,20 +198,56 @@ pub mod distance {
 	}
 }
 
+pub mod fuzzy {
+
+	use crate::err::Error;
+	use crate::fnc::util::string::fuzzy::Fuzzy;
+	use crate::sql::{Array, Object, Value};
+
+	/// Returns the strings in an array which fuzzy match the search text,
+	/// along with their scores, ordered from the best match to the worst.
+	pub fn search((text, candidates): (String, Array)) -> Result<Value, Error> {
+		let mut matches: Vec<(i64, String)> = candidates
+			.into_iter()
+			.filter_map(|v| match v {
+				Value::Strand(v) if v.as_str().fuzzy_match(&text) => {
+					Some((v.as_str().fuzzy_score(&text), v.0))
+				}
+				_ => None,
+			})
+			.collect();
+		// Sort by descending score, keeping the original order of equal scores
+		matches.sort_by(|(a, _), (b, _)| b.cmp(a));
+		Ok(matches
+			.into_iter()
+			.map(|(score, value)| {
+				let mut obj = Object::default();
+				obj.insert("value".to_owned(), value.into());
+				obj.insert("score".to_owned(), score.into());
+				Value::from(obj)
+			})
+			.collect::<Vec<Value>>()
+			.into())
+	}
+}
+
 pub mod similarity {
 
 	use crate::err::Error;
 	use crate::fnc::util::string::fuzzy::Fuzzy;
+	use crate::fnc::util::string::jaro;
 	use crate::sql::Value;
 
 	pub fn fuzzy((a, b): (String, String)) -> Result<Value, Error> {
 		Ok(a.as_str().fuzzy_score(b.as_str()).into())
 	}
 
-	pub fn jaro((_, _): (String, String)) -> Result<Value, Error> {
-		Err(Error::FeatureNotYetImplemented {
-			feature: "string::similarity::jaro() function",
-		})
+	pub fn jaro((a, b): (String, String)) -> Result<Value, Error> {
+		Ok(jaro::jaro(&a, &b).into())
+	}
+
+	pub fn jaro_winkler((a, b): (String, String)) -> Result<Value, Error> {
+		Ok(jaro::jaro_winkler(&a, &b).into())
 	}
 
 	pub fn smithwaterman((a, b): (String, String)) -> Result<Value, Error> {
@@ -174,7 +257,8 @@ pub mod similarity {
 
 #[cfg(test)]
 mod tests {
-	use super::{contains, slice};
+	use super::{contains, count, slice};
+	use crate::sql::test::Parse;
 	use crate::sql::Value;
 
 	#[test]
@@ -216,4 +300,23 @@ mod tests {
 		test("好世界", "世", true);
 		test("好世界", "你好", false);
 	}
+
+	#[test]
+	fn string_count() {
+		fn test(base: &str, sub: &str, overlapping: bool, expected: i64) {
+			let opts = Value::parse(&format!("{{ overlapping: {overlapping} }}"));
+			assert_eq!(
+				count((base.to_string(), sub.to_string(), Some(opts))).unwrap(),
+				Value::from(expected)
+			);
+		}
+
+		test("", "a", false, 0);
+		test("abcde", "f", false, 0);
+		test("abab", "ab", false, 2);
+		test("aaa", "aa", false, 1);
+		test("aaa", "aa", true, 2);
+		test("好好好", "好好", true, 2);
+		assert!(count(("abc".to_string(), "".to_string(), None)).is_err());
+	}
 }
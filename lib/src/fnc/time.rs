@@ -1,3 +1,4 @@
+use crate::cnf::TIME_INTERVAL_LIMIT;
 use crate::err::Error;
 use crate::sql::datetime::Datetime;
 use crate::sql::duration::Duration;
@@ -118,6 +119,44 @@ pub fn hour((val,): (Option<Datetime>,)) -> Result<Value, Error> {
 	})
 }
 
+pub fn interval((start, end, step): (Datetime, Datetime, Duration)) -> Result<Value, Error> {
+	// Check the order of the start and end datetimes
+	if *start > *end {
+		return Err(Error::InvalidArguments {
+			name: String::from("time::interval"),
+			message: String::from(
+				"The first argument must be a datetime which is not after the second argument.",
+			),
+		});
+	}
+	// Check for a zero or unrepresentable duration
+	let step = match chrono::Duration::from_std(*step) {
+		Ok(d) if !d.is_zero() => d,
+		_ => {
+			return Err(Error::InvalidArguments {
+				name: String::from("time::interval"),
+				message: String::from("The third argument must be a duration greater than zero."),
+			})
+		}
+	};
+	// Generate each datetime, including the end datetime
+	let mut out = Vec::new();
+	let mut cur = Some(*start);
+	while let Some(v) = cur.filter(|v| v <= &*end) {
+		if out.len() >= TIME_INTERVAL_LIMIT {
+			return Err(Error::InvalidArguments {
+				name: String::from("time::interval"),
+				message: format!(
+					"The interval can not contain more than {TIME_INTERVAL_LIMIT} datetimes."
+				),
+			});
+		}
+		out.push(Value::from(v));
+		cur = v.checked_add_signed(step);
+	}
+	Ok(out.into())
+}
+
 pub fn max((array,): (Vec<Datetime>,)) -> Result<Value, Error> {
 	Ok(match array.into_iter().max() {
 		Some(v) => v.into(),
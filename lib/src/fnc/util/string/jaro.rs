@@ -0,0 +1,77 @@
+/// Calculates the Jaro similarity of two strings, between 0 (no similarity) and 1 (identical)
+pub fn jaro(a: &str, b: &str) -> f64 {
+	let a: Vec<char> = a.chars().collect();
+	let b: Vec<char> = b.chars().collect();
+	// Two empty strings are identical
+	if a.is_empty() && b.is_empty() {
+		return 1.0;
+	}
+	// An empty string has nothing in common
+	if a.is_empty() || b.is_empty() {
+		return 0.0;
+	}
+	// Characters only match within this distance of each other
+	let range = (a.len().max(b.len()) / 2).saturating_sub(1);
+	let mut a_matched = vec![false; a.len()];
+	let mut b_matched = vec![false; b.len()];
+	let mut matches = 0usize;
+	for (i, ac) in a.iter().enumerate() {
+		let end = (i + range + 1).min(b.len());
+		let beg = i.saturating_sub(range).min(end);
+		for (bm, bc) in b_matched[beg..end].iter_mut().zip(&b[beg..end]) {
+			if !*bm && bc == ac {
+				a_matched[i] = true;
+				*bm = true;
+				matches += 1;
+				break;
+			}
+		}
+	}
+	// There are no matching characters
+	if matches == 0 {
+		return 0.0;
+	}
+	// Count the matching characters which are out of order
+	let a_chars = a.iter().zip(a_matched).filter(|(_, m)| *m).map(|(c, _)| c);
+	let b_chars = b.iter().zip(b_matched).filter(|(_, m)| *m).map(|(c, _)| c);
+	let transpositions = a_chars.zip(b_chars).filter(|(a, b)| a != b).count() / 2;
+	// Calculate the similarity
+	let m = matches as f64;
+	(m / a.len() as f64 + m / b.len() as f64 + (m - transpositions as f64) / m) / 3.0
+}
+
+/// Calculates the Jaro-Winkler similarity of two strings, which favours strings sharing a prefix
+pub fn jaro_winkler(a: &str, b: &str) -> f64 {
+	let sim = jaro(a, b);
+	// Count the common prefix, up to a maximum of 4 characters
+	let prefix = a.chars().zip(b.chars()).take(4).take_while(|(a, b)| a == b).count();
+	sim + prefix as f64 * 0.1 * (1.0 - sim)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{jaro, jaro_winkler};
+
+	fn round(v: f64) -> f64 {
+		(v * 1000.0).round() / 1000.0
+	}
+
+	#[test]
+	fn jaro_similarity() {
+		assert_eq!(jaro("", ""), 1.0);
+		assert_eq!(jaro("", "text"), 0.0);
+		assert_eq!(jaro("text", "text"), 1.0);
+		assert_eq!(jaro("abc", "xyz"), 0.0);
+		assert_eq!(jaro("abcdefgh", "h"), 0.0);
+		assert_eq!(round(jaro("martha", "marhta")), 0.944);
+		assert_eq!(round(jaro("dixon", "dicksonx")), 0.767);
+	}
+
+	#[test]
+	fn jaro_winkler_similarity() {
+		assert_eq!(jaro_winkler("text", "text"), 1.0);
+		assert_eq!(jaro_winkler("abc", "xyz"), 0.0);
+		assert_eq!(round(jaro_winkler("martha", "marhta")), 0.961);
+		assert_eq!(round(jaro_winkler("dixon", "dicksonx")), 0.813);
+	}
+}
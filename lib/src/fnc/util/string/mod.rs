@@ -1,2 +1,4 @@
 pub mod fuzzy;
+pub mod jaro;
+pub mod pattern;
 pub mod slug;
@@ -0,0 +1,65 @@
+use crate::err::Error;
+use crate::sql::value::Value;
+use lru::LruCache;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::num::NonZeroUsize;
+use std::sync::Mutex;
+
+/// The number of compiled regular expressions which are kept for reuse
+const CACHE_SIZE: usize = 1000;
+
+static CACHE: Lazy<Mutex<LruCache<String, Regex>>> =
+	Lazy::new(|| Mutex::new(LruCache::new(NonZeroUsize::new(CACHE_SIZE).unwrap())));
+
+/// Returns the regular expression for a regex literal, or compiles a string pattern
+pub fn regex(name: &str, pattern: Value) -> Result<Regex, Error> {
+	match pattern {
+		Value::Regex(v) => Ok(v.regex().clone()),
+		Value::Strand(v) => compile(name, &v),
+		_ => Err(Error::InvalidArguments {
+			name: name.to_owned(),
+			message: String::from("The pattern must be a regex or a string."),
+		}),
+	}
+}
+
+/// Compiles a string pattern, reusing the regular expression if it was compiled recently
+fn compile(name: &str, pattern: &str) -> Result<Regex, Error> {
+	// Check if the pattern was compiled recently
+	if let Some(regex) = CACHE.lock().unwrap().get(pattern) {
+		return Ok(regex.clone());
+	}
+	// Compile the pattern without holding the lock
+	let regex = Regex::new(pattern).map_err(|_| Error::InvalidArguments {
+		name: name.to_owned(),
+		message: format!("The pattern '{pattern}' is not a valid regular expression."),
+	})?;
+	// Store the compiled pattern for reuse
+	CACHE.lock().unwrap().put(pattern.to_owned(), regex.clone());
+	Ok(regex)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{compile, regex};
+	use crate::sql::value::Value;
+
+	#[test]
+	fn compile_cached() {
+		let a = compile("string::matches", "^[a-z]+$").unwrap();
+		let b = compile("string::matches", "^[a-z]+$").unwrap();
+		assert_eq!(a.as_str(), b.as_str());
+		assert!(b.is_match("test"));
+	}
+
+	#[test]
+	fn compile_invalid() {
+		let res = regex("string::matches", Value::from("[a-z"));
+		assert_eq!(
+			res.unwrap_err().to_string(),
+			"Incorrect arguments for function string::matches(). The pattern '[a-z' is not a valid regular expression."
+		);
+		assert!(regex("string::matches", Value::from(1)).is_err());
+	}
+}
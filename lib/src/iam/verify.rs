@@ -122,8 +122,20 @@ pub async fn token(kvs: &Datastore, session: &mut Session, auth: String) -> Resu
 				None => Value::None,
 			};
 			// Get the scope token
-			let de = tx.get_st(&ns, &db, &sc, &tk).await?;
-			let cf = config(de.kind, de.code)?;
+			let (kind, code) = match tx.get_st(&ns, &db, &sc, &tk).await {
+				// Fall back to a scope access method with this name
+				Err(Error::StNotFound {
+					..
+				}) => {
+					let de = tx.get_sa(&ns, &db, &sc, &tk).await?;
+					(de.kind, de.code)
+				}
+				v => {
+					let de = v?;
+					(de.kind, de.code)
+				}
+			};
+			let cf = config(kind, code)?;
 			// Verify the token
 			decode::<Claims>(auth, &cf.0, &cf.1)?;
 			// Log the success
@@ -179,8 +191,20 @@ pub async fn token(kvs: &Datastore, session: &mut Session, auth: String) -> Resu
 			// Create a new readonly transaction
 			let mut tx = kvs.transaction(false, false).await?;
 			// Get the database token
-			let de = tx.get_dt(&ns, &db, &tk).await?;
-			let cf = config(de.kind, de.code)?;
+			let (kind, code) = match tx.get_dt(&ns, &db, &tk).await {
+				// Fall back to a database access method with this name
+				Err(Error::DtNotFound {
+					..
+				}) => {
+					let de = tx.get_da(&ns, &db, &tk).await?;
+					(de.kind, de.code)
+				}
+				v => {
+					let de = v?;
+					(de.kind, de.code)
+				}
+			};
+			let cf = config(kind, code)?;
 			// Verify the token
 			decode::<Claims>(auth, &cf.0, &cf.1)?;
 			// Log the success
@@ -228,8 +252,20 @@ pub async fn token(kvs: &Datastore, session: &mut Session, auth: String) -> Resu
 			// Create a new readonly transaction
 			let mut tx = kvs.transaction(false, false).await?;
 			// Get the namespace token
-			let de = tx.get_nt(&ns, &tk).await?;
-			let cf = config(de.kind, de.code)?;
+			let (kind, code) = match tx.get_nt(&ns, &tk).await {
+				// Fall back to a namespace access method with this name
+				Err(Error::NtNotFound {
+					..
+				}) => {
+					let de = tx.get_na(&ns, &tk).await?;
+					(de.kind, de.code)
+				}
+				v => {
+					let de = v?;
+					(de.kind, de.code)
+				}
+			};
+			let cf = config(kind, code)?;
 			// Verify the token
 			decode::<Claims>(auth, &cf.0, &cf.1)?;
 			// Log the success
@@ -1,3 +1,4 @@
+pub mod ac;
 pub mod all;
 pub mod az;
 pub mod fc;
@@ -2,3 +2,4 @@ pub mod all;
 pub mod hb;
 pub mod nd;
 pub mod ns;
+pub mod tl;
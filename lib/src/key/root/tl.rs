@@ -0,0 +1,62 @@
+//! Stores the expiry time of a key which was set with a time-to-live
+//!
+//! The expiring key is appended to the prefix as-is, so that the expiry
+//! times for a range of keys can be fetched with a single range scan.
+use crate::kvs::Key;
+
+const PREFIX: &[u8] = b"/!tl";
+
+pub fn new(key: &[u8]) -> Key {
+	let mut k = PREFIX.to_vec();
+	k.extend_from_slice(key);
+	k
+}
+
+pub fn prefix() -> Key {
+	let mut k = PREFIX.to_vec();
+	k.push(0x00);
+	k
+}
+
+pub fn suffix() -> Key {
+	let mut k = PREFIX.to_vec();
+	k.push(0xff);
+	k
+}
+
+/// Check if a key stores the expiry time of another key
+pub fn is(key: &[u8]) -> bool {
+	key.starts_with(PREFIX)
+}
+
+/// Get the expiring key from an expiry key
+pub fn key(key: &[u8]) -> &[u8] {
+	&key[PREFIX.len()..]
+}
+
+/// Decode a stored expiry time, in milliseconds since the unix epoch
+pub fn expiry(val: &[u8]) -> u64 {
+	match <[u8; 8]>::try_from(val) {
+		Ok(v) => u64::from_be_bytes(v),
+		Err(_) => u64::MAX,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn key() {
+		use super::*;
+		let val = new(b"/*testns\0");
+		assert_eq!(val, b"/!tl/*testns\0");
+		assert!(is(&val));
+		assert_eq!(super::key(&val), b"/*testns\0");
+	}
+
+	#[test]
+	fn expiry() {
+		let val = 1_000_u64.to_be_bytes();
+		assert_eq!(super::expiry(&val), 1_000);
+		assert_eq!(super::expiry(b"invalid"), u64::MAX);
+	}
+}
@@ -0,0 +1,72 @@
+//! Stores a DEFINE ACCESS ON SCOPE config definition
+use derive::Key;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Key)]
+pub struct Ac<'a> {
+	__: u8,
+	_a: u8,
+	pub ns: &'a str,
+	_b: u8,
+	pub db: &'a str,
+	_c: u8,
+	pub sc: &'a str,
+	_d: u8,
+	_e: u8,
+	_f: u8,
+	pub ac: &'a str,
+}
+
+pub fn new<'a>(ns: &'a str, db: &'a str, sc: &'a str, ac: &'a str) -> Ac<'a> {
+	Ac::new(ns, db, sc, ac)
+}
+
+pub fn prefix(ns: &str, db: &str, sc: &str) -> Vec<u8> {
+	let mut k = super::all::new(ns, db, sc).encode().unwrap();
+	k.extend_from_slice(&[b'!', b'a', b'c', 0x00]);
+	k
+}
+
+pub fn suffix(ns: &str, db: &str, sc: &str) -> Vec<u8> {
+	let mut k = super::all::new(ns, db, sc).encode().unwrap();
+	k.extend_from_slice(&[b'!', b'a', b'c', 0xff]);
+	k
+}
+
+impl<'a> Ac<'a> {
+	pub fn new(ns: &'a str, db: &'a str, sc: &'a str, ac: &'a str) -> Self {
+		Self {
+			__: b'/',
+			_a: b'*',
+			ns,
+			_b: b'*',
+			db,
+			_c: super::CHAR,
+			sc,
+			_d: b'!',
+			_e: b'a',
+			_f: b'c',
+			ac,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn key() {
+		use super::*;
+		#[rustfmt::skip]
+		let val = Ac::new(
+			"testns",
+			"testdb",
+			"testsc",
+			"testac",
+		);
+		let enc = Ac::encode(&val).unwrap();
+		assert_eq!(enc, b"/*testns\0*testdb\0\xb1testsc\0!actestac\0");
+
+		let dec = Ac::decode(&enc).unwrap();
+		assert_eq!(val, dec);
+	}
+}
@@ -1,5 +1,9 @@
 use super::tx::Transaction;
+use super::KvStore;
 use crate::cf;
+use crate::cf::Change;
+use crate::cnf::SHUTDOWN_POLL_INTERVAL;
+use crate::cnf::{TRANSACTION_RETRY_BACKOFF, TRANSACTION_RETRY_BACKOFF_LIMIT};
 use crate::ctx::Context;
 use crate::dbs::node::Timestamp;
 use crate::dbs::Attach;
@@ -11,17 +15,38 @@ use crate::dbs::Session;
 use crate::dbs::Variables;
 use crate::err::Error;
 use crate::key::root::hb::Hb;
+#[cfg(any(
+	feature = "kv-mem",
+	feature = "kv-tikv",
+	feature = "kv-rocksdb",
+	feature = "kv-speedb",
+	feature = "kv-fdb",
+	feature = "kv-indxdb",
+))]
+use crate::opt::Config;
 use crate::sql;
+use crate::sql::statements::{DefineFieldStatement, DefineTableStatement, LiveStatement};
 use crate::sql::Value;
-use crate::sql::{Query, Uuid};
+use crate::sql::{Query, Statement, StatementKind, Statements, Uuid};
+use crate::vs::Versionstamp;
 use channel::Receiver;
 use channel::Sender;
+use channel::TrySendError;
+use futures::io::{AsyncRead, AsyncReadExt};
 use futures::lock::Mutex;
+use futures::Stream;
+use std::collections::BTreeMap;
 use std::fmt;
+use std::future::Future;
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::sync::RwLock;
 use std::time::Duration;
 use tracing::instrument;
 use tracing::trace;
+use tracing::warn;
+use trice::Instant;
 
 /// Used for cluster logic to move LQ data to LQ cleanup code
 /// Not a stored struct; Used only in this module
@@ -34,6 +59,19 @@ pub struct LqValue {
 	pub lq: Uuid,
 }
 
+/// A parsed SQL query, which can be run many times with different variables.
+#[derive(Clone, Debug)]
+pub struct Prepared {
+	query: Query,
+}
+
+impl Prepared {
+	/// Retrieve the parsed SQL query
+	pub fn query(&self) -> &Query {
+		&self.query
+	}
+}
+
 /// The underlying datastore instance which stores the dataset.
 #[allow(dead_code)]
 pub struct Datastore {
@@ -47,8 +85,54 @@ pub struct Datastore {
 	query_timeout: Option<Duration>,
 	// The maximum duration timeout for running multiple statements in a transaction
 	transaction_timeout: Option<Duration>,
+	// The maximum length in bytes of a query which can be parsed
+	max_query_length: Option<usize>,
 	// Whether this datastore enables live query notifications to subscribers
 	notification_channel: Option<(Sender<Notification>, Receiver<Notification>)>,
+	// The in-process subscribers to live queries, keyed by the live query id,
+	// along with the namespace and database of each live query
+	live_streams: RwLock<BTreeMap<Uuid, (String, String, Sender<Notification>)>>,
+	// The maximum memory in bytes which each embedded script function can use
+	script_memory_limit: Option<usize>,
+	// The maximum duration for running each embedded script function
+	script_timeout: Option<Duration>,
+	// The hosts which each embedded script function is allowed to fetch from
+	script_fetch_allowlist: Option<Arc<[String]>>,
+	// The shutdown state which is shared with each transaction
+	shutdown: Arc<Shutdown>,
+	// Whether any keys may have been set with a time-to-live
+	expiry: Arc<AtomicBool>,
+}
+
+/// The shutdown state of a datastore, shared with each of its transactions
+#[derive(Default)]
+pub(super) struct Shutdown {
+	// Whether new queries and transactions are rejected
+	draining: AtomicBool,
+	// Whether unfinished transactions are prevented from committing
+	closed: AtomicBool,
+	// The number of transactions which have not yet finished
+	inflight: AtomicUsize,
+}
+
+/// Marks a transaction as in-flight until it is committed, cancelled, or dropped
+pub(super) struct InFlight(Arc<Shutdown>);
+
+impl InFlight {
+	fn new(shutdown: &Arc<Shutdown>) -> InFlight {
+		shutdown.inflight.fetch_add(1, Ordering::SeqCst);
+		InFlight(shutdown.clone())
+	}
+	/// Check if the datastore was shut down before this transaction finished
+	pub(super) fn closed(&self) -> bool {
+		self.0.closed.load(Ordering::SeqCst)
+	}
+}
+
+impl Drop for InFlight {
+	fn drop(&mut self) {
+		self.0.inflight.fetch_sub(1, Ordering::SeqCst);
+	}
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -65,6 +149,7 @@ pub(super) enum Inner {
 	TiKV(super::tikv::Datastore),
 	#[cfg(feature = "kv-fdb")]
 	FoundationDB(super::fdb::Datastore),
+	Custom(Box<dyn KvStore>),
 }
 
 impl fmt::Display for Datastore {
@@ -83,6 +168,7 @@ impl fmt::Display for Datastore {
 			Inner::TiKV(_) => write!(f, "tikv"),
 			#[cfg(feature = "kv-fdb")]
 			Inner::FoundationDB(_) => write!(f, "fdb"),
+			Inner::Custom(_) => write!(f, "custom"),
 			#[allow(unreachable_patterns)]
 			_ => unreachable!(),
 		}
@@ -132,6 +218,40 @@ impl Datastore {
 		Self::new_full(path, id).await
 	}
 
+	/// Creates a new datastore instance, applying the datastore-level options
+	/// from the specified [`Config`], such as strict mode and the default timeouts
+	///
+	/// ```rust,no_run
+	/// # use surrealdb::kvs::Datastore;
+	/// # use surrealdb::err::Error;
+	/// # use surrealdb::opt::Config;
+	/// # #[tokio::main]
+	/// # async fn main() -> Result<(), Error> {
+	/// let config = Config::new().strict();
+	/// let ds = Datastore::new_with_config("memory", config).await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	#[cfg(any(
+		feature = "kv-mem",
+		feature = "kv-tikv",
+		feature = "kv-rocksdb",
+		feature = "kv-speedb",
+		feature = "kv-fdb",
+		feature = "kv-indxdb",
+	))]
+	pub async fn new_with_config(path: &str, config: Config) -> Result<Datastore, Error> {
+		let ds = Datastore::new(path)
+			.await?
+			.with_strict_mode(config.strict)
+			.with_query_timeout(config.query_timeout)
+			.with_transaction_timeout(config.transaction_timeout);
+		Ok(match config.notifications {
+			true => ds.with_notifications(),
+			false => ds,
+		})
+	}
+
 	// For testing
 	pub async fn new_full(path: &str, node_id: Uuid) -> Result<Datastore, Error> {
 		// Initiate the desired datastore
@@ -238,14 +358,52 @@ impl Datastore {
 			}
 		};
 		// Set the properties on the datastore
-		inner.map(|inner| Self {
-			id: node_id,
+		let ds = inner.map(|inner| Self::from_inner(inner, node_id))?;
+		// Check for keys which were set with a time-to-live
+		ds.check_expiry().await?;
+		Ok(ds)
+	}
+
+	/// Creates a new datastore instance using a custom storage engine
+	///
+	/// ```rust,ignore
+	/// use surrealdb::kvs::Datastore;
+	///
+	/// let ds = Datastore::new_with_store(MyStore::default());
+	/// ```
+	pub fn new_with_store(store: impl KvStore) -> Datastore {
+		let ds = Self::from_inner(Inner::Custom(Box::new(store)), Uuid::new_v4());
+		// The store may already hold keys which were set with a time-to-live
+		ds.expiry.store(true, Ordering::Relaxed);
+		ds
+	}
+
+	// Set the default properties on the datastore
+	fn from_inner(inner: Inner, id: Uuid) -> Datastore {
+		Self {
+			id,
 			inner,
 			strict: false,
 			query_timeout: None,
 			transaction_timeout: None,
+			max_query_length: None,
 			notification_channel: None,
-		})
+			live_streams: RwLock::new(BTreeMap::new()),
+			script_memory_limit: None,
+			script_timeout: None,
+			script_fetch_allowlist: None,
+			shutdown: Arc::new(Shutdown::default()),
+			expiry: Arc::new(AtomicBool::new(false)),
+		}
+	}
+
+	// Check whether the datastore holds any keys which were set with a time-to-live
+	async fn check_expiry(&self) -> Result<(), Error> {
+		let mut tx = self.transaction(false, false).await?;
+		let res = tx.scan(crate::key::root::tl::prefix()..crate::key::root::tl::suffix(), 1).await;
+		tx.cancel().await?;
+		self.expiry.store(!res?.is_empty(), Ordering::Relaxed);
+		Ok(())
 	}
 
 	/// Specify whether this Datastore should run in strict mode
@@ -272,6 +430,33 @@ impl Datastore {
 		self
 	}
 
+	/// Set the maximum length in bytes of a query which this Datastore will parse
+	pub fn with_max_query_length(mut self, length: Option<usize>) -> Self {
+		self.max_query_length = length;
+		self
+	}
+
+	/// Set a memory limit in bytes for each embedded script function run by this Datastore
+	pub fn with_script_memory_limit(mut self, limit: Option<usize>) -> Self {
+		self.script_memory_limit = limit;
+		self
+	}
+
+	/// Set a timeout for each embedded script function run by this Datastore
+	pub fn with_script_timeout(mut self, duration: Option<Duration>) -> Self {
+		self.script_timeout = duration;
+		self
+	}
+
+	/// Set the hosts which each embedded script function run by this Datastore is allowed
+	/// to fetch from. Each entry is a host name, optionally followed by a port, such as
+	/// `api.example.com` or `localhost:8080`. When no allowlist is set, the `fetch` function
+	/// is disabled in embedded scripts. This requires the `http` feature to be enabled.
+	pub fn with_script_fetch_allowlist(mut self, hosts: Option<Vec<String>>) -> Self {
+		self.script_fetch_allowlist = hosts.map(Arc::from);
+		self
+	}
+
 	/// Creates a new datastore instance
 	///
 	/// Use this for clustered environments.
@@ -471,6 +656,52 @@ impl Datastore {
 		tx.set_hb(timestamp, node_id.0).await
 	}
 
+	/// Delete keys which were set with a time-to-live, and which have expired
+	///
+	/// No transaction is started unless keys have been set with a time-to-live.
+	pub async fn compact_expired(&self) -> Result<u32, Error> {
+		if !self.expiry.load(Ordering::Relaxed) {
+			return Ok(0);
+		}
+		let mut tx = self.transaction(true, false).await?;
+		match tx.compact_expired(1000).await {
+			Ok(num) => {
+				tx.commit().await?;
+				trace!("Deleted {num} expired keys");
+				Ok(num)
+			}
+			Err(e) => {
+				tx.cancel().await?;
+				Err(e)
+			}
+		}
+	}
+
+	/// Check that the underlying key-value store is responding
+	///
+	/// ```rust,no_run
+	/// use surrealdb::kvs::Datastore;
+	/// use surrealdb::err::Error;
+	///
+	/// #[tokio::main]
+	/// async fn main() -> Result<(), Error> {
+	///     let ds = Datastore::new("memory").await?;
+	///     ds.health().await?;
+	///     Ok(())
+	/// }
+	/// ```
+	pub async fn health(&self) -> Result<(), Error> {
+		let mut tx = self.transaction(false, false).await?;
+		let res = tx.exi(crate::key::root::all::new()).await;
+		tx.cancel().await?;
+		res.map(|_| ())
+	}
+
+	/// The version of the SurrealDB engine which this datastore is running
+	pub fn version(&self) -> &'static str {
+		crate::env::VERSION
+	}
+
 	// -----
 	// End cluster helpers, storage functions here
 	// -----
@@ -491,6 +722,10 @@ impl Datastore {
 	/// ```
 	pub async fn transaction(&self, write: bool, lock: bool) -> Result<Transaction, Error> {
 		#![allow(unused_variables)]
+		// Reject new transactions once shutdown has begun
+		if self.shutdown.draining.load(Ordering::SeqCst) {
+			return Err(Error::DsShutdown);
+		}
 		let inner = match &self.inner {
 			#[cfg(feature = "kv-mem")]
 			Inner::Mem(v) => {
@@ -522,6 +757,10 @@ impl Datastore {
 				let tx = v.transaction(write, lock).await?;
 				super::tx::Inner::FoundationDB(tx)
 			}
+			Inner::Custom(v) => {
+				let tx = v.transaction(write, lock).await?;
+				super::tx::Inner::Custom(tx)
+			}
 			#[allow(unreachable_patterns)]
 			_ => unreachable!(),
 		};
@@ -531,9 +770,68 @@ impl Datastore {
 			inner,
 			cache: super::cache::Cache::default(),
 			cf: cf::Writer::new(),
+			clock: None,
+			changes: None,
+			inflight: Some(InFlight::new(&self.shutdown)),
+			expiry: self.expiry.clone(),
 		})
 	}
 
+	/// Run a function within a new transaction, retrying it on conflicts
+	///
+	/// The function is passed a fresh transaction on each attempt, and is
+	/// responsible for committing it. If the function fails because the
+	/// transaction conflicted with another transaction, it is run again,
+	/// up to `retries` more times, waiting exponentially longer between
+	/// each attempt. Any other error is returned immediately.
+	///
+	/// ```rust,no_run
+	/// use surrealdb::kvs::Datastore;
+	/// use surrealdb::err::Error;
+	///
+	/// #[tokio::main]
+	/// async fn main() -> Result<(), Error> {
+	///     let ds = Datastore::new("file://database.db").await?;
+	///     ds.transact_with_retry(true, false, 3, |mut tx| async move {
+	///         tx.set("key", "val").await?;
+	///         tx.commit().await
+	///     })
+	///     .await?;
+	///     Ok(())
+	/// }
+	/// ```
+	pub async fn transact_with_retry<F, R, T>(
+		&self,
+		write: bool,
+		lock: bool,
+		retries: u32,
+		mut f: F,
+	) -> Result<T, Error>
+	where
+		F: FnMut(Transaction) -> R,
+		R: Future<Output = Result<T, Error>>,
+	{
+		let mut attempt = 0;
+		loop {
+			let tx = self.transaction(write, lock).await?;
+			match f(tx).await {
+				Err(Error::TxRetryable) if attempt < retries => {
+					// Back off before trying again, doubling the delay each time
+					let delay = TRANSACTION_RETRY_BACKOFF
+						.saturating_mul(1 << attempt.min(16))
+						.min(TRANSACTION_RETRY_BACKOFF_LIMIT);
+					attempt += 1;
+					trace!("Retrying transaction after conflict in {delay:?} (attempt {attempt} of {retries})");
+					#[cfg(target_arch = "wasm32")]
+					wasmtimer::tokio::sleep(delay).await;
+					#[cfg(not(target_arch = "wasm32"))]
+					tokio::time::sleep(delay).await;
+				}
+				res => return res,
+			}
+		}
+	}
+
 	/// Parse and execute an SQL query
 	///
 	/// ```rust,no_run
@@ -558,11 +856,134 @@ impl Datastore {
 		vars: Variables,
 	) -> Result<Vec<Response>, Error> {
 		// Parse the SQL query text
-		let ast = sql::parse(txt)?;
+		let ast = self.parse(txt)?;
 		// Process the AST
 		self.process(ast, sess, vars).await
 	}
 
+	/// Parse and execute an SQL query, with the specified parameters bound to it
+	///
+	/// Each entry in `vars` is available within the query as a `$name` parameter.
+	/// The parameters can be given as a map, or built up with [`Vars`](crate::dbs::Vars).
+	///
+	/// ```rust,no_run
+	/// use surrealdb::kvs::Datastore;
+	/// use surrealdb::err::Error;
+	/// use surrealdb::dbs::{Session, Vars};
+	///
+	/// #[tokio::main]
+	/// async fn main() -> Result<(), Error> {
+	///     let ds = Datastore::new("memory").await?;
+	///     let ses = Session::for_kv().with_ns("test").with_db("test");
+	///     let ast = "SELECT * FROM person WHERE name = $name;";
+	///     let res = ds.execute_with(ast, &ses, Vars::new().set("name", "Tobie")).await?;
+	///     Ok(())
+	/// }
+	/// ```
+	#[instrument(skip_all)]
+	pub async fn execute_with(
+		&self,
+		txt: &str,
+		sess: &Session,
+		vars: impl Into<BTreeMap<String, Value>>,
+	) -> Result<Vec<Response>, Error> {
+		// Parse the SQL query text
+		let ast = self.parse(txt)?;
+		// Process the AST
+		self.process(ast, sess, Some(vars.into())).await
+	}
+
+	/// Parse an SQL query once, so that it can be run many times without being parsed again
+	///
+	/// ```rust,no_run
+	/// use surrealdb::kvs::Datastore;
+	/// use surrealdb::err::Error;
+	/// use surrealdb::dbs::Session;
+	///
+	/// #[tokio::main]
+	/// async fn main() -> Result<(), Error> {
+	///     let ds = Datastore::new("memory").await?;
+	///     let ses = Session::for_kv().with_ns("test").with_db("test");
+	///     let prep = ds.prepare("SELECT * FROM person WHERE age > $age;")?;
+	///     let res = ds.run(&prep, &ses, Some([("age".into(), 18.into())].into())).await?;
+	///     let res = ds.run(&prep, &ses, Some([("age".into(), 65.into())].into())).await?;
+	///     Ok(())
+	/// }
+	/// ```
+	pub fn prepare(&self, txt: &str) -> Result<Prepared, Error> {
+		// Parse the SQL query text
+		let query = self.parse(txt)?;
+		// Return the prepared query
+		Ok(Prepared {
+			query,
+		})
+	}
+
+	/// Execute a prepared SQL query, with the specified variables
+	///
+	/// ```rust,no_run
+	/// use surrealdb::kvs::Datastore;
+	/// use surrealdb::err::Error;
+	/// use surrealdb::dbs::Session;
+	///
+	/// #[tokio::main]
+	/// async fn main() -> Result<(), Error> {
+	///     let ds = Datastore::new("memory").await?;
+	///     let ses = Session::for_kv();
+	///     let prep = ds.prepare("USE NS test DB test; SELECT * FROM person;")?;
+	///     let res = ds.run(&prep, &ses, None).await?;
+	///     Ok(())
+	/// }
+	/// ```
+	#[instrument(skip_all)]
+	pub async fn run(
+		&self,
+		prep: &Prepared,
+		sess: &Session,
+		vars: Variables,
+	) -> Result<Vec<Response>, Error> {
+		// Process the AST
+		self.process(prep.query.clone(), sess, vars).await
+	}
+
+	/// Parse an SQL query, and return the kind of each top-level statement, without executing it
+	///
+	/// ```rust,no_run
+	/// use surrealdb::kvs::Datastore;
+	/// use surrealdb::err::Error;
+	/// use surrealdb::dbs::Session;
+	///
+	/// #[tokio::main]
+	/// async fn main() -> Result<(), Error> {
+	///     let ds = Datastore::new("memory").await?;
+	///     let ses = Session::for_kv();
+	///     let ast = "USE NS test DB test; SELECT * FROM person;";
+	///     let res = ds.inspect(ast, &ses)?;
+	///     Ok(())
+	/// }
+	/// ```
+	pub fn inspect(&self, txt: &str, _sess: &Session) -> Result<Vec<StatementKind>, Error> {
+		// Parse the SQL query text
+		let ast = self.parse(txt)?;
+		// Classify each statement
+		Ok(ast.iter().map(Statement::kind).collect())
+	}
+
+	/// Parse an SQL query, checking that it is within the maximum query length
+	fn parse(&self, txt: &str) -> Result<Query, Error> {
+		// Check the length before parsing
+		if let Some(max) = self.max_query_length {
+			if txt.len() > max {
+				return Err(Error::QueryTooLarge {
+					len: txt.len(),
+					max,
+				});
+			}
+		}
+		// Parse the SQL query text
+		sql::parse(txt)
+	}
+
 	/// Execute a pre-parsed SQL query
 	///
 	/// ```rust,no_run
@@ -587,6 +1008,10 @@ impl Datastore {
 		sess: &Session,
 		vars: Variables,
 	) -> Result<Vec<Response>, Error> {
+		// Reject new queries once shutdown has begun
+		if self.shutdown.draining.load(Ordering::SeqCst) {
+			return Err(Error::DsShutdown);
+		}
 		// Create a new query options
 		let opt = Options::default()
 			.with_id(self.id.0)
@@ -596,7 +1021,10 @@ impl Datastore {
 			.with_auth(sess.au.clone())
 			.with_strict(self.strict);
 		// Create a new query executor
-		let mut exe = Executor::new(self);
+		let mut exe = Executor::new(self)
+			.with_stats(sess.stats)
+			.with_explain(sess.explain)
+			.with_timeout(sess.query_timeout);
 		// Create a default context
 		let mut ctx = Context::default();
 		// Set the global query timeout
@@ -607,6 +1035,18 @@ impl Datastore {
 		if let Some(channel) = &self.notification_channel {
 			ctx.add_notifications(Some(&channel.0));
 		}
+		// Set the embedded script memory limit
+		if let Some(limit) = self.script_memory_limit {
+			ctx.add_script_memory_limit(limit);
+		}
+		// Set the embedded script timeout
+		if let Some(timeout) = self.script_timeout {
+			ctx.add_script_timeout(timeout);
+		}
+		// Set the embedded script fetch allowlist
+		if let Some(hosts) = &self.script_fetch_allowlist {
+			ctx.add_script_fetch_allowlist(hosts.clone());
+		}
 		// Start an execution context
 		let ctx = sess.context(ctx);
 		// Store the query variables
@@ -662,6 +1102,18 @@ impl Datastore {
 		if let Some(channel) = &self.notification_channel {
 			ctx.add_notifications(Some(&channel.0));
 		}
+		// Set the embedded script memory limit
+		if let Some(limit) = self.script_memory_limit {
+			ctx.add_script_memory_limit(limit);
+		}
+		// Set the embedded script timeout
+		if let Some(timeout) = self.script_timeout {
+			ctx.add_script_timeout(timeout);
+		}
+		// Set the embedded script fetch allowlist
+		if let Some(hosts) = &self.script_fetch_allowlist {
+			ctx.add_script_fetch_allowlist(hosts.clone());
+		}
 		// Start an execution context
 		let ctx = sess.context(ctx);
 		// Store the query variables
@@ -677,6 +1129,131 @@ impl Datastore {
 		Ok(res)
 	}
 
+	/// Validate a record against a table and its field definitions, without storing it
+	///
+	/// The record is checked and coerced using the `TYPE`, `VALUE`, and `ASSERT`
+	/// clauses of each field, and any undefined fields are removed if the table is
+	/// schemafull. All of the invalid fields are reported, rather than only the first.
+	///
+	/// ```rust,no_run
+	/// use surrealdb::kvs::Datastore;
+	/// use surrealdb::err::Error;
+	/// use surrealdb::dbs::Session;
+	/// use surrealdb::sql::statements::{DefineFieldStatement, DefineTableStatement};
+	/// use surrealdb::sql::Value;
+	///
+	/// async fn ingest(
+	///     ds: &Datastore,
+	///     tb: &DefineTableStatement,
+	///     fds: &[DefineFieldStatement],
+	///     val: Value,
+	/// ) -> Result<Value, Vec<Error>> {
+	///     let ses = Session::for_kv().with_ns("test").with_db("test");
+	///     ds.validate_record(&ses, val, tb, fds).await
+	/// }
+	/// ```
+	#[instrument(skip_all)]
+	pub async fn validate_record(
+		&self,
+		sess: &Session,
+		val: Value,
+		tb: &DefineTableStatement,
+		fds: &[DefineFieldStatement],
+	) -> Result<Value, Vec<Error>> {
+		// Create a new query options
+		let opt = Options::default()
+			.with_id(self.id.0)
+			.with_ns(sess.ns())
+			.with_db(sess.db())
+			.with_live(sess.live())
+			.with_auth(sess.au.clone())
+			.with_strict(self.strict);
+		// Start a new read transaction
+		let txn = self.transaction(false, false).await.map_err(|e| vec![e])?;
+		//
+		let txn = Arc::new(Mutex::new(txn));
+		// Create a default context
+		let mut ctx = Context::default();
+		// Set the global query timeout
+		if let Some(timeout) = self.query_timeout {
+			ctx.add_timeout(timeout);
+		}
+		// Start an execution context
+		let ctx = sess.context(ctx);
+		// Validate the record
+		let res = crate::doc::validate::validate(&ctx, &opt, &txn, val, tb, fds).await;
+		// Cancel the transaction
+		txn.lock().await.cancel().await.map_err(|e| vec![e])?;
+		// Return result
+		res
+	}
+
+	/// Read the changes which have been made to the records in a table with a change feed
+	///
+	/// Changes are returned in the order in which they were made, starting from the
+	/// change with the given versionstamp, if specified. Only the changes which are
+	/// still retained by the table change feed are returned.
+	///
+	/// ```rust,no_run
+	/// use surrealdb::kvs::Datastore;
+	/// use surrealdb::err::Error;
+	///
+	/// #[tokio::main]
+	/// async fn main() -> Result<(), Error> {
+	///     let ds = Datastore::new("memory").await?;
+	///     let changes = ds.changes("test", "test", "person", None, Some(100)).await?;
+	///     Ok(())
+	/// }
+	/// ```
+	pub async fn changes(
+		&self,
+		ns: &str,
+		db: &str,
+		tb: &str,
+		since: Option<Versionstamp>,
+		limit: Option<u32>,
+	) -> Result<Vec<Change>, Error> {
+		// Start a new read transaction
+		let mut txn = self.transaction(false, false).await?;
+		// Read the table change feed
+		let res = async {
+			// Check that the table has a change feed
+			if txn.get_tb(ns, db, tb).await?.changefeed.is_none() {
+				return Err(Error::TbChangefeedNotFound {
+					value: tb.to_owned(),
+				});
+			}
+			// Convert the retained change sets into record changes
+			let mut out = cf::changes::Changes::new(since, limit.unwrap_or(100) as usize);
+			// Page through the change feed from the oldest retained change set
+			let beg = crate::key::change::prefix_ts(ns, db, crate::vs::u64_to_versionstamp(0));
+			let end = crate::key::change::suffix(ns, db);
+			let (mut res, mut next) = txn.scan_page(beg.clone()..end.clone(), 1000).await?;
+			loop {
+				for (k, v) in res {
+					let key = crate::key::change::Cf::decode(&k)?;
+					if key.tb == tb {
+						let muts: cf::TableMutations = v.into();
+						out.push(key.vs, muts.1);
+					}
+				}
+				match next {
+					Some(cursor) if !out.is_full() => {
+						(res, next) =
+							txn.scan_page_from(beg.clone()..end.clone(), cursor, 1000).await?;
+					}
+					_ => break,
+				}
+			}
+			Ok(out.finish())
+		}
+		.await;
+		// Cancel the transaction
+		txn.cancel().await?;
+		// Return result
+		res
+	}
+
 	/// Subscribe to live notifications
 	///
 	/// ```rust,no_run
@@ -701,6 +1278,112 @@ impl Datastore {
 		self.notification_channel.as_ref().map(|v| v.1.clone())
 	}
 
+	/// Start a live query, and subscribe to its notifications in-process
+	///
+	/// A notification is sent for each record in the table which is created,
+	/// updated, or deleted, and which matches the `WHERE` clause of the live
+	/// query. These notifications are sent to the returned stream, rather than
+	/// to the channel returned by [`Datastore::notifications`]. Once the stream
+	/// is dropped, the live query is removed when its next notification is
+	/// sent. If the stream falls more than 100 notifications behind, further
+	/// notifications are discarded until it catches up, so that a slow
+	/// subscriber never holds up the queries which send notifications.
+	///
+	/// ```rust,no_run
+	/// use futures::StreamExt;
+	/// use surrealdb::kvs::Datastore;
+	/// use surrealdb::err::Error;
+	/// use surrealdb::sql::{parse, Statement};
+	///
+	/// #[tokio::main]
+	/// async fn main() -> Result<(), Error> {
+	///     let ds = Datastore::new("memory").await?;
+	///     let ast = parse("LIVE SELECT * FROM person WHERE age >= 18")?;
+	///     if let Some(Statement::Live(stm)) = ast.iter().next() {
+	///         let mut stream = ds.live("test", "test", stm.clone()).await?;
+	///         while let Some(v) = stream.next().await {
+	///             println!("Received notification: {v}");
+	///         }
+	///     }
+	///     Ok(())
+	/// }
+	/// ```
+	#[instrument(skip(self))]
+	pub async fn live(
+		&self,
+		ns: &str,
+		db: &str,
+		stm: LiveStatement,
+	) -> Result<impl Stream<Item = Notification>, Error> {
+		let id = stm.id.clone();
+		// Subscribe before the live query is started
+		let (send, recv) = channel::bounded(100);
+		self.live_streams.write().unwrap().insert(id.clone(), (ns.to_owned(), db.to_owned(), send));
+		// Start the live query
+		let mut ses = Session::for_kv().with_ns(ns).with_db(db);
+		ses.rt = true;
+		let ast = Query(Statements(vec![Statement::Live(stm)]));
+		let res = match self.process(ast, &ses, None).await {
+			Ok(mut res) => res.remove(0).result,
+			Err(e) => Err(e),
+		};
+		// Unsubscribe if the live query could not be started
+		if let Err(e) = res {
+			self.live_streams.write().unwrap().remove(&id);
+			return Err(e);
+		}
+		Ok(recv)
+	}
+
+	/// Send a notification to the in-process subscriber to its live query
+	///
+	/// The notification is returned if its live query has no in-process
+	/// subscriber, so that it can be sent to the notification channel.
+	pub(crate) async fn send_live(&self, v: Notification) -> Option<Notification> {
+		// Retrieve the in-process subscriber to this live query
+		let (ns, db, chn) = match self.live_streams.read().unwrap().get(&v.id) {
+			Some(s) => s.clone(),
+			None => return Some(v),
+		};
+		match chn.try_send(v) {
+			Ok(()) => {}
+			// The subscriber is behind, so this notification is discarded
+			Err(TrySendError::Full(v)) => {
+				trace!("Discarding notification for the live query {}", v.id);
+			}
+			// The subscriber has dropped its stream, so the live query is removed
+			Err(TrySendError::Closed(v)) => match self.kill_live(&v.id, &ns, &db).await {
+				Ok(()) => {
+					self.live_streams.write().unwrap().remove(&v.id);
+				}
+				Err(e) => {
+					warn!("Failed to remove the live query {}: {e}", v.id);
+				}
+			},
+		}
+		None
+	}
+
+	/// Remove a live query which was started on this node
+	async fn kill_live(&self, id: &Uuid, ns: &str, db: &str) -> Result<(), Error> {
+		// Start a new writeable transaction
+		let mut txn = self.transaction(true, false).await?;
+		// Fetch the live query key if it exists
+		let key = crate::key::node::lq::new(self.id.0, id.0, ns, db);
+		if let Some(val) = txn.get(key).await? {
+			if let Ok(tb) = std::str::from_utf8(&val) {
+				// Delete the node live query
+				let key = crate::key::node::lq::new(self.id.0, id.0, ns, db);
+				txn.del(key).await?;
+				// Delete the table live query
+				let key = crate::key::table::lq::new(ns, db, tb, id.0);
+				txn.del(key).await?;
+			}
+		}
+		// Commit the transaction
+		txn.commit().await
+	}
+
 	/// Performs a full database export as SQL
 	#[instrument(skip(self, chn))]
 	pub async fn export(&self, ns: String, db: String, chn: Sender<Vec<u8>>) -> Result<(), Error> {
@@ -711,4 +1394,122 @@ impl Datastore {
 		// Everything ok
 		Ok(())
 	}
+
+	/// Performs a full database export as SQL, writing the output to the specified writer
+	///
+	/// ```rust,no_run
+	/// use surrealdb::kvs::Datastore;
+	/// use surrealdb::err::Error;
+	///
+	/// #[tokio::main]
+	/// async fn main() -> Result<(), Error> {
+	///     let ds = Datastore::new("memory").await?;
+	///     let mut buf = Vec::new();
+	///     ds.export_to("test", "test", &mut buf).await?;
+	///     Ok(())
+	/// }
+	/// ```
+	#[instrument(skip(self, writer))]
+	pub async fn export_to(&self, ns: &str, db: &str, mut writer: impl Write) -> Result<(), Error> {
+		// Create a channel for the exported SQL
+		let (snd, rcv) = channel::bounded(10);
+		// Process the export
+		let export = self.export(ns.to_owned(), db.to_owned(), snd);
+		// Write the exported SQL as it is received
+		let output = async move {
+			while let Ok(v) = rcv.recv().await {
+				writer.write_all(&v)?;
+			}
+			writer.flush()?;
+			Ok::<(), Error>(())
+		};
+		// Wait for both to finish
+		let (export, output) = futures::join!(export, output);
+		// Writing errors cause the export to fail, so check them first
+		output?;
+		export
+	}
+
+	/// Imports an SQL export into the specified namespace and database, reading the SQL from the specified reader
+	///
+	/// ```rust,no_run
+	/// use surrealdb::kvs::Datastore;
+	/// use surrealdb::err::Error;
+	///
+	/// #[tokio::main]
+	/// async fn main() -> Result<(), Error> {
+	///     let ds = Datastore::new("memory").await?;
+	///     let sql = "OPTION IMPORT; CREATE person:tobie SET name = 'Tobie';";
+	///     ds.import("test", "test", sql.as_bytes()).await?;
+	///     Ok(())
+	/// }
+	/// ```
+	#[instrument(skip(self, reader))]
+	pub async fn import(
+		&self,
+		ns: &str,
+		db: &str,
+		mut reader: impl AsyncRead + Unpin,
+	) -> Result<(), Error> {
+		// Read the exported SQL without blocking the executor
+		let mut sql = String::new();
+		reader.read_to_string(&mut sql).await?;
+		// Import with root authentication
+		let sess = Session::for_kv().with_ns(ns).with_db(db);
+		// Execute the exported SQL
+		let res = self.execute(&sql, &sess, None).await?;
+		// Fail with the first statement error
+		match res.into_iter().find_map(|v| v.result.err()) {
+			Some(e) => Err(e),
+			None => Ok(()),
+		}
+	}
+
+	/// Gracefully shut down the datastore
+	///
+	/// New queries and transactions are rejected as soon as shutdown begins. Any
+	/// transactions which are already running are given until the timeout to finish,
+	/// after which they are rolled back when they attempt to commit. Finally the
+	/// storage engine is flushed.
+	///
+	/// ```rust,no_run
+	/// use std::time::Duration;
+	/// use surrealdb::kvs::Datastore;
+	/// use surrealdb::err::Error;
+	///
+	/// #[tokio::main]
+	/// async fn main() -> Result<(), Error> {
+	///     let ds = Datastore::new("file://database.db").await?;
+	///     ds.shutdown(Duration::from_secs(10)).await?;
+	///     Ok(())
+	/// }
+	/// ```
+	#[instrument(skip(self))]
+	pub async fn shutdown(&self, timeout: Duration) -> Result<(), Error> {
+		// Stop accepting new queries and transactions
+		self.shutdown.draining.store(true, Ordering::SeqCst);
+		// Wait for the in-flight transactions to finish
+		let deadline = Instant::now() + timeout;
+		while self.shutdown.inflight.load(Ordering::SeqCst) > 0 {
+			if Instant::now() >= deadline {
+				trace!("Rolling back the unfinished transactions");
+				break;
+			}
+			#[cfg(target_arch = "wasm32")]
+			wasmtimer::tokio::sleep(SHUTDOWN_POLL_INTERVAL).await;
+			#[cfg(not(target_arch = "wasm32"))]
+			tokio::time::sleep(SHUTDOWN_POLL_INTERVAL).await;
+		}
+		// Prevent any unfinished transactions from committing
+		self.shutdown.closed.store(true, Ordering::SeqCst);
+		// Flush the storage engine
+		match &self.inner {
+			#[cfg(feature = "kv-rocksdb")]
+			Inner::RocksDB(v) => v.flush(),
+			#[cfg(feature = "kv-speedb")]
+			Inner::SpeeDB(v) => v.flush(),
+			#[allow(unreachable_patterns)]
+			_ => Ok(()),
+		}
+	}
 }
@@ -140,6 +140,9 @@ impl Transaction {
 		};
 		match r {
 			Ok(_r) => {}
+			Err(e) if e.is_retryable() => {
+				return Err(Error::TxRetryable);
+			}
 			Err(e) => {
 				return Err(Error::Tx(format!("Transaction commit error: {}", e)));
 			}
@@ -462,4 +465,15 @@ impl Transaction {
 		}
 		Ok(res)
 	}
+	/// Retrieve a range of keys from the databases, without their values
+	pub async fn keys<K>(&mut self, rng: Range<K>, limit: u32) -> Result<Vec<Key>, Error>
+	where
+		K: Into<Key>,
+	{
+		// FoundationDB has no keys-only range read, so drop the values
+		let res = self.scan(rng, limit).await?;
+		let res = res.into_iter().map(|kv| kv.0).collect();
+		// Return result
+		Ok(res)
+	}
 }
@@ -3,6 +3,7 @@
 use crate::err::Error;
 use crate::kvs::Key;
 use crate::kvs::Val;
+use crate::kvs::{KvStore, KvTransaction, StoreFuture};
 use crate::vs::{try_to_u64_be, u64_to_versionstamp, Versionstamp};
 use std::ops::Range;
 
@@ -286,4 +287,83 @@ impl Transaction {
 		// Return result
 		Ok(res)
 	}
+	/// Retrieve a range of keys from the databases, without their values
+	pub fn keys<K>(&mut self, rng: Range<K>, limit: u32) -> Result<Vec<Key>, Error>
+	where
+		K: Into<Key>,
+	{
+		// Check to see if transaction is closed
+		if self.ok {
+			return Err(Error::TxFinished);
+		}
+		// Convert the range to bytes
+		let rng: Range<Key> = Range {
+			start: rng.start.into(),
+			end: rng.end.into(),
+		};
+		// Scan the keys
+		let res = self.tx.scan(rng, limit)?;
+		let res = res.into_iter().map(|kv| kv.0).collect();
+		// Return result
+		Ok(res)
+	}
+}
+
+impl KvStore for Datastore {
+	fn transaction(&self, write: bool, lock: bool) -> StoreFuture<'_, Box<dyn KvTransaction>> {
+		Box::pin(async move {
+			let tx = Datastore::transaction(self, write, lock).await?;
+			Ok(Box::new(tx) as Box<dyn KvTransaction>)
+		})
+	}
+}
+
+impl KvTransaction for Transaction {
+	fn closed(&self) -> bool {
+		Transaction::closed(self)
+	}
+	fn cancel(&mut self) -> StoreFuture<'_, ()> {
+		Box::pin(async move { Transaction::cancel(self) })
+	}
+	fn commit(&mut self) -> StoreFuture<'_, ()> {
+		Box::pin(async move { Transaction::commit(self) })
+	}
+	fn get(&mut self, key: Key) -> StoreFuture<'_, Option<Val>> {
+		Box::pin(async move { Transaction::get(self, key) })
+	}
+	fn set(&mut self, key: Key, val: Val) -> StoreFuture<'_, ()> {
+		Box::pin(async move { Transaction::set(self, key, val) })
+	}
+	fn del(&mut self, key: Key) -> StoreFuture<'_, ()> {
+		Box::pin(async move { Transaction::del(self, key) })
+	}
+	fn scan(&mut self, rng: Range<Key>, limit: u32) -> StoreFuture<'_, Vec<(Key, Val)>> {
+		Box::pin(async move { Transaction::scan(self, rng, limit) })
+	}
+	fn exi(&mut self, key: Key) -> StoreFuture<'_, bool> {
+		Box::pin(async move { Transaction::exi(self, key) })
+	}
+	fn put(&mut self, key: Key, val: Val) -> StoreFuture<'_, ()> {
+		Box::pin(async move { Transaction::put(self, key, val) })
+	}
+	fn putc(&mut self, key: Key, val: Val, chk: Option<Val>) -> StoreFuture<'_, ()> {
+		Box::pin(async move { Transaction::putc(self, key, val, chk) })
+	}
+	fn delc(&mut self, key: Key, chk: Option<Val>) -> StoreFuture<'_, ()> {
+		Box::pin(async move { Transaction::delc(self, key, chk) })
+	}
+	fn keys(&mut self, rng: Range<Key>, limit: u32) -> StoreFuture<'_, Vec<Key>> {
+		Box::pin(async move { Transaction::keys(self, rng, limit) })
+	}
+	fn get_timestamp(&mut self, key: Key) -> StoreFuture<'_, Versionstamp> {
+		Box::pin(async move { Transaction::get_timestamp(self, key) })
+	}
+	fn get_versionstamped_key(
+		&mut self,
+		ts_key: Key,
+		prefix: Key,
+		suffix: Key,
+	) -> StoreFuture<'_, Key> {
+		Box::pin(Transaction::get_versionstamped_key(self, ts_key, prefix, suffix))
+	}
 }
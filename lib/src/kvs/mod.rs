@@ -12,6 +12,8 @@
 //! - `speedb`: [SpeedyDB](https://github.com/speedb-io/speedb) fork of rocksDB making it faster (Redis is using speedb but this is not acid transactions)
 //! - `tikv`: [TiKV](https://github.com/tikv/tikv) a distributed, and transactional key-value database
 //! - `mem`: in-memory database
+//!
+//! Custom storage engines can be used by implementing the [`KvStore`] and [`KvTransaction`] traits.
 mod cache;
 mod ds;
 mod fdb;
@@ -20,6 +22,7 @@ mod kv;
 mod mem;
 mod rocksdb;
 mod speedb;
+mod store;
 mod tikv;
 mod tx;
 
@@ -28,4 +31,7 @@ mod tests;
 
 pub use self::ds::*;
 pub use self::kv::*;
+pub use self::store::*;
 pub use self::tx::*;
+pub use crate::cf::{Change, ChangeKind};
+pub use crate::vs::Versionstamp;
@@ -3,6 +3,7 @@
 use crate::err::Error;
 use crate::kvs::Key;
 use crate::kvs::Val;
+use crate::kvs::{KvStore, KvTransaction, StoreFuture};
 use crate::vs::{try_to_u64_be, u64_to_versionstamp, Versionstamp};
 use futures::lock::Mutex;
 use speedb::{OptimisticTransactionDB, OptimisticTransactionOptions, ReadOptions, WriteOptions};
@@ -36,6 +37,10 @@ impl Datastore {
 			db: Arc::pin(OptimisticTransactionDB::open_default(path)?),
 		})
 	}
+	/// Flush any buffered writes to disk
+	pub(crate) fn flush(&self) -> Result<(), Error> {
+		Ok(self.db.flush()?)
+	}
 	/// Start a new transaction
 	pub async fn transaction(&self, write: bool, _: bool) -> Result<Transaction, Error> {
 		// Activate the snapshot options
@@ -373,4 +378,111 @@ impl Transaction {
 		// Return result
 		Ok(res)
 	}
+	/// Retrieve a range of keys from the databases, without their values
+	pub async fn keys<K>(&mut self, rng: Range<K>, limit: u32) -> Result<Vec<Key>, Error>
+	where
+		K: Into<Key>,
+	{
+		// Check to see if transaction is closed
+		if self.ok {
+			return Err(Error::TxFinished);
+		}
+		// Get the transaction
+		let tx = self.tx.lock().await;
+		let tx = tx.as_ref().unwrap();
+		// Convert the range to bytes
+		let rng: Range<Key> = Range {
+			start: rng.start.into(),
+			end: rng.end.into(),
+		};
+		// Create result set
+		let mut res = vec![];
+		// Set the key range
+		let beg = rng.start.as_slice();
+		let end = rng.end.as_slice();
+		// Set the ReadOptions with the snapshot
+		let mut ro = ReadOptions::default();
+		ro.set_snapshot(&tx.snapshot());
+		// Create the iterator
+		let mut iter = tx.raw_iterator_opt(ro);
+		// Seek to the start key
+		iter.seek(&rng.start);
+		// Scan the keys in the iterator
+		while iter.valid() {
+			// Check the scan limit
+			if res.len() < limit as usize {
+				// Get the key, without reading the value
+				if let Some(k) = iter.key() {
+					if k >= beg && k < end {
+						res.push(k.to_vec());
+						iter.next();
+						continue;
+					}
+				}
+			}
+			// Exit
+			break;
+		}
+		// Return result
+		Ok(res)
+	}
+}
+
+impl KvStore for Datastore {
+	fn transaction(&self, write: bool, lock: bool) -> StoreFuture<'_, Box<dyn KvTransaction>> {
+		Box::pin(async move {
+			let tx = Datastore::transaction(self, write, lock).await?;
+			Ok(Box::new(tx) as Box<dyn KvTransaction>)
+		})
+	}
+}
+
+impl KvTransaction for Transaction {
+	fn closed(&self) -> bool {
+		Transaction::closed(self)
+	}
+	fn cancel(&mut self) -> StoreFuture<'_, ()> {
+		Box::pin(Transaction::cancel(self))
+	}
+	fn commit(&mut self) -> StoreFuture<'_, ()> {
+		Box::pin(Transaction::commit(self))
+	}
+	fn get(&mut self, key: Key) -> StoreFuture<'_, Option<Val>> {
+		Box::pin(Transaction::get(self, key))
+	}
+	fn set(&mut self, key: Key, val: Val) -> StoreFuture<'_, ()> {
+		Box::pin(Transaction::set(self, key, val))
+	}
+	fn del(&mut self, key: Key) -> StoreFuture<'_, ()> {
+		Box::pin(Transaction::del(self, key))
+	}
+	fn scan(&mut self, rng: Range<Key>, limit: u32) -> StoreFuture<'_, Vec<(Key, Val)>> {
+		Box::pin(Transaction::scan(self, rng, limit))
+	}
+	fn exi(&mut self, key: Key) -> StoreFuture<'_, bool> {
+		Box::pin(Transaction::exi(self, key))
+	}
+	fn put(&mut self, key: Key, val: Val) -> StoreFuture<'_, ()> {
+		Box::pin(Transaction::put(self, key, val))
+	}
+	fn putc(&mut self, key: Key, val: Val, chk: Option<Val>) -> StoreFuture<'_, ()> {
+		Box::pin(Transaction::putc(self, key, val, chk))
+	}
+	fn delc(&mut self, key: Key, chk: Option<Val>) -> StoreFuture<'_, ()> {
+		Box::pin(Transaction::delc(self, key, chk))
+	}
+	fn keys(&mut self, rng: Range<Key>, limit: u32) -> StoreFuture<'_, Vec<Key>> {
+		Box::pin(Transaction::keys(self, rng, limit))
+	}
+	fn get_timestamp(&mut self, key: Key) -> StoreFuture<'_, Versionstamp> {
+		Box::pin(Transaction::get_timestamp(self, key))
+	}
+	fn get_versionstamped_key(
+		&mut self,
+		ts_key: Key,
+		prefix: Key,
+		suffix: Key,
+	) -> StoreFuture<'_, Key> {
+		Box::pin(Transaction::get_versionstamped_key(self, ts_key, prefix, suffix))
+	}
 }
@@ -0,0 +1,117 @@
+//! The extension point for plugging a custom storage engine into a [`Datastore`](super::Datastore).
+//!
+//! A custom storage engine only needs to provide the core transaction operations. The
+//! remaining operations have default implementations built on top of these, which can
+//! be overridden when the storage engine supports them natively.
+use super::Key;
+use super::Val;
+use crate::err::Error;
+use crate::vs::{try_to_u64_be, u64_to_versionstamp, Versionstamp};
+use std::future::Future;
+use std::ops::Range;
+use std::pin::Pin;
+
+/// The future returned by the operations of a custom storage engine.
+pub type StoreFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T, Error>> + Send + 'a>>;
+
+/// A key-value storage engine which can be used by a [`Datastore`](super::Datastore).
+///
+/// ```rust,ignore
+/// let ds = Datastore::new_with_store(MyStore::default());
+/// ```
+pub trait KvStore: Send + Sync + 'static {
+	/// Start a new transaction
+	fn transaction(&self, write: bool, lock: bool) -> StoreFuture<'_, Box<dyn KvTransaction>>;
+}
+
+/// A transaction against a custom key-value storage engine.
+pub trait KvTransaction: Send + Sync {
+	/// Check if closed
+	fn closed(&self) -> bool;
+	/// Cancel a transaction
+	fn cancel(&mut self) -> StoreFuture<'_, ()>;
+	/// Commit a transaction
+	fn commit(&mut self) -> StoreFuture<'_, ()>;
+	/// Fetch a key from the database
+	fn get(&mut self, key: Key) -> StoreFuture<'_, Option<Val>>;
+	/// Insert or update a key in the database
+	fn set(&mut self, key: Key, val: Val) -> StoreFuture<'_, ()>;
+	/// Delete a key
+	fn del(&mut self, key: Key) -> StoreFuture<'_, ()>;
+	/// Retrieve a range of keys from the database
+	fn scan(&mut self, rng: Range<Key>, limit: u32) -> StoreFuture<'_, Vec<(Key, Val)>>;
+	/// Check if a key exists
+	fn exi(&mut self, key: Key) -> StoreFuture<'_, bool> {
+		Box::pin(async move { Ok(self.get(key).await?.is_some()) })
+	}
+	/// Insert a key if it doesn't exist in the database
+	fn put(&mut self, key: Key, val: Val) -> StoreFuture<'_, ()> {
+		Box::pin(async move {
+			match self.get(key.clone()).await? {
+				Some(_) => Err(Error::TxKeyAlreadyExists),
+				None => self.set(key, val).await,
+			}
+		})
+	}
+	/// Insert a key if the current value matches a condition
+	fn putc(&mut self, key: Key, val: Val, chk: Option<Val>) -> StoreFuture<'_, ()> {
+		Box::pin(async move {
+			match (self.get(key.clone()).await?, chk) {
+				(Some(v), Some(w)) if v == w => self.set(key, val).await,
+				(None, None) => self.set(key, val).await,
+				_ => Err(Error::TxConditionNotMet),
+			}
+		})
+	}
+	/// Delete a key if the current value matches a condition
+	fn delc(&mut self, key: Key, chk: Option<Val>) -> StoreFuture<'_, ()> {
+		Box::pin(async move {
+			match (self.get(key.clone()).await?, chk) {
+				(Some(v), Some(w)) if v == w => self.del(key).await,
+				(None, None) => self.del(key).await,
+				_ => Err(Error::TxConditionNotMet),
+			}
+		})
+	}
+	/// Retrieve a range of keys from the database, without their values
+	fn keys(&mut self, rng: Range<Key>, limit: u32) -> StoreFuture<'_, Vec<Key>> {
+		Box::pin(async move {
+			let res = self.scan(rng, limit).await?;
+			Ok(res.into_iter().map(|(k, _)| k).collect())
+		})
+	}
+	/// Obtain a new change timestamp for a key
+	fn get_timestamp(&mut self, key: Key) -> StoreFuture<'_, Versionstamp> {
+		Box::pin(async move {
+			// Increment the timestamp stored in the "last-write-timestamp" key
+			let ver = match self.get(key.clone()).await? {
+				Some(prev) => {
+					let array: [u8; 10] = match prev.as_slice().try_into() {
+						Ok(ba) => ba,
+						Err(e) => return Err(Error::Ds(e.to_string())),
+					};
+					try_to_u64_be(array)? + 1
+				}
+				None => 1,
+			};
+			let verbytes = u64_to_versionstamp(ver);
+			self.set(key, verbytes.to_vec()).await?;
+			Ok(verbytes)
+		})
+	}
+	/// Obtain a new key that is suffixed with the change timestamp
+	fn get_versionstamped_key(
+		&mut self,
+		ts_key: Key,
+		prefix: Key,
+		suffix: Key,
+	) -> StoreFuture<'_, Key> {
+		Box::pin(async move {
+			let ts = self.get_timestamp(ts_key).await?;
+			let mut k = prefix;
+			k.extend_from_slice(&ts);
+			k.extend_from_slice(&suffix);
+			Ok(k)
+		})
+	}
+}
@@ -20,6 +20,7 @@ mod mem {
 	include!("raw.rs");
 	include!("snapshot.rs");
 	include!("tb.rs");
+	include!("record_changes.rs");
 	include!("multireader.rs");
 }
 
@@ -47,6 +48,7 @@ mod rocksdb {
 	include!("raw.rs");
 	include!("snapshot.rs");
 	include!("tb.rs");
+	include!("record_changes.rs");
 	include!("multireader.rs");
 	include!("multiwriter_different_keys.rs");
 	include!("multiwriter_same_keys_conflict.rs");
@@ -76,6 +78,7 @@ mod speedb {
 	include!("raw.rs");
 	include!("snapshot.rs");
 	include!("tb.rs");
+	include!("record_changes.rs");
 	include!("multireader.rs");
 	include!("multiwriter_different_keys.rs");
 	include!("multiwriter_same_keys_conflict.rs");
@@ -109,6 +112,7 @@ mod tikv {
 	include!("raw.rs");
 	include!("snapshot.rs");
 	include!("tb.rs");
+	include!("record_changes.rs");
 	include!("multireader.rs");
 	include!("multiwriter_different_keys.rs");
 	include!("multiwriter_same_keys_conflict.rs");
@@ -142,6 +146,7 @@ mod fdb {
 	include!("raw.rs");
 	include!("snapshot.rs");
 	include!("tb.rs");
+	include!("record_changes.rs");
 	include!("multireader.rs");
 	include!("multiwriter_different_keys.rs");
 	include!("multiwriter_same_keys_allow.rs");
@@ -137,7 +137,8 @@ async fn putc() {
 	tx.cancel().await.unwrap();
 	// Create a writeable transaction
 	let mut tx = ds.transaction(true, false).await.unwrap();
-	assert!(tx.putc("test", "tre", Some("one")).await.is_err());
+	let res = tx.putc("test", "tre", Some("one")).await;
+	assert!(matches!(res, Err(crate::err::Error::TxConditionNotMet)));
 	tx.commit().await.unwrap();
 	// Create a readonly transaction
 	let mut tx = ds.transaction(false, false).await.unwrap();
@@ -146,6 +147,36 @@ async fn putc() {
 	tx.cancel().await.unwrap();
 }
 
+#[tokio::test]
+#[serial]
+async fn putc_when_absent() {
+	// Create a new datastore
+	let ds = new_ds().await;
+	// Create a writeable transaction
+	let mut tx = ds.transaction(true, false).await.unwrap();
+	assert!(tx.putc("test", "one", None).await.is_ok());
+	tx.commit().await.unwrap();
+	// Create a readonly transaction
+	let mut tx = ds.transaction(false, false).await.unwrap();
+	let val = tx.get("test").await.unwrap();
+	assert!(matches!(val.as_deref(), Some(b"one")));
+	tx.cancel().await.unwrap();
+	// Create a writeable transaction
+	let mut tx = ds.transaction(true, false).await.unwrap();
+	let res = tx.putc("test", "two", None).await;
+	assert!(matches!(res, Err(crate::err::Error::TxConditionNotMet)));
+	let res = tx.putc("none", "two", Some("one")).await;
+	assert!(matches!(res, Err(crate::err::Error::TxConditionNotMet)));
+	tx.cancel().await.unwrap();
+	// Create a readonly transaction
+	let mut tx = ds.transaction(false, false).await.unwrap();
+	let val = tx.get("test").await.unwrap();
+	assert!(matches!(val.as_deref(), Some(b"one")));
+	let val = tx.get("none").await.unwrap();
+	assert!(matches!(val.as_deref(), None));
+	tx.cancel().await.unwrap();
+}
+
 #[tokio::test]
 #[serial]
 async fn delc() {
@@ -222,3 +253,206 @@ async fn scan() {
 	assert_eq!(val[1].1, b"2");
 	tx.cancel().await.unwrap();
 }
+
+#[tokio::test]
+#[serial]
+async fn transact_with_retry() {
+	use crate::err::Error;
+	use std::sync::atomic::{AtomicU32, Ordering};
+	// Create a new datastore
+	let ds = new_ds().await;
+	// Fail twice with a conflict, then succeed
+	let attempts = AtomicU32::new(0);
+	let start = std::time::Instant::now();
+	let res = ds
+		.transact_with_retry(true, false, 3, |mut tx| {
+			let attempts = &attempts;
+			async move {
+				if attempts.fetch_add(1, Ordering::SeqCst) < 2 {
+					tx.cancel().await?;
+					return Err(Error::TxRetryable);
+				}
+				tx.set("test", "ok").await?;
+				tx.commit().await?;
+				Ok::<_, Error>("done")
+			}
+		})
+		.await;
+	assert!(matches!(res, Ok("done")));
+	assert_eq!(attempts.load(Ordering::SeqCst), 3);
+	// Check that the attempts were backed off
+	assert!(start.elapsed() >= crate::cnf::TRANSACTION_RETRY_BACKOFF * 3);
+	// Check that the key was committed
+	let mut tx = ds.transaction(false, false).await.unwrap();
+	let val = tx.get("test").await.unwrap();
+	assert!(matches!(val.as_deref(), Some(b"ok")));
+	tx.cancel().await.unwrap();
+}
+
+#[tokio::test]
+#[serial]
+async fn transact_with_retry_gives_up() {
+	use crate::err::Error;
+	use std::sync::atomic::{AtomicU32, Ordering};
+	// Create a new datastore
+	let ds = new_ds().await;
+	// Conflicts are only retried up to the limit
+	let attempts = AtomicU32::new(0);
+	let res = ds
+		.transact_with_retry(true, false, 2, |mut tx| {
+			let attempts = &attempts;
+			async move {
+				attempts.fetch_add(1, Ordering::SeqCst);
+				tx.cancel().await?;
+				Err::<(), _>(Error::TxRetryable)
+			}
+		})
+		.await;
+	assert!(matches!(res, Err(Error::TxRetryable)));
+	assert_eq!(attempts.load(Ordering::SeqCst), 3);
+	// Other errors are returned immediately
+	let attempts = AtomicU32::new(0);
+	let res = ds
+		.transact_with_retry(true, false, 2, |mut tx| {
+			let attempts = &attempts;
+			async move {
+				attempts.fetch_add(1, Ordering::SeqCst);
+				tx.cancel().await?;
+				Err::<(), _>(Error::TxKeyAlreadyExists)
+			}
+		})
+		.await;
+	assert!(matches!(res, Err(Error::TxKeyAlreadyExists)));
+	assert_eq!(attempts.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+#[serial]
+async fn scan_page() {
+	// Create a new datastore
+	let ds = new_ds().await;
+	// Create a writeable transaction
+	let mut tx = ds.transaction(true, false).await.unwrap();
+	for i in 0..250 {
+		assert!(tx.put(format!("test{i:03}"), i.to_string()).await.is_ok());
+	}
+	assert!(tx.put("testz", "outside").await.is_ok());
+	tx.commit().await.unwrap();
+	// Create a readonly transaction
+	let mut tx = ds.transaction(false, false).await.unwrap();
+	let (mut keys, mut cursor) = tx.scan_page("test000".."test999", 100).await.unwrap();
+	assert_eq!(keys.len(), 100);
+	let mut pages = 1;
+	while let Some(next) = cursor {
+		let (page, next) = tx.scan_page_from("test000".."test999", next, 100).await.unwrap();
+		assert!(page.len() <= 100);
+		keys.extend(page);
+		cursor = next;
+		pages += 1;
+	}
+	assert_eq!(pages, 3);
+	assert_eq!(keys.len(), 250);
+	for (i, (k, v)) in keys.iter().enumerate() {
+		assert_eq!(k, format!("test{i:03}").as_bytes());
+		assert_eq!(v, i.to_string().as_bytes());
+	}
+	tx.cancel().await.unwrap();
+	// Create a readonly transaction
+	let mut tx = ds.transaction(false, false).await.unwrap();
+	let (keys, cursor) = tx.scan_page("test000".."test999", 250).await.unwrap();
+	assert_eq!(keys.len(), 250);
+	assert!(cursor.is_none());
+	tx.cancel().await.unwrap();
+	// Create a readonly transaction
+	let mut tx = ds.transaction(false, false).await.unwrap();
+	let res = tx.scan_page("test000".."test999", 0).await;
+	assert!(matches!(res, Err(crate::err::Error::InvalidPageLimit)));
+	tx.cancel().await.unwrap();
+}
+
+#[tokio::test]
+#[serial]
+async fn set_with_ttl() {
+	use crate::dbs::node::Timestamp;
+	use std::time::Duration;
+	// Create a new datastore
+	let ds = new_ds().await;
+	// Create a writeable transaction
+	let mut tx = ds.transaction(true, false).await.unwrap();
+	tx.set_clock(Timestamp {
+		value: 1_000_000,
+	});
+	tx.set_with_ttl("test", "ok", Duration::from_secs(10)).await.unwrap();
+	tx.set("text", "ok").await.unwrap();
+	tx.commit().await.unwrap();
+	// The key is present before it expires
+	let mut tx = ds.transaction(false, false).await.unwrap();
+	tx.set_clock(Timestamp {
+		value: 1_009_999,
+	});
+	let val = tx.get("test").await.unwrap();
+	assert!(matches!(val.as_deref(), Some(b"ok")));
+	assert!(tx.exi("test").await.unwrap());
+	let val = tx.scan("t".."u", 10).await.unwrap();
+	assert_eq!(val.len(), 2);
+	tx.cancel().await.unwrap();
+	// The key is absent after it expires
+	let mut tx = ds.transaction(false, false).await.unwrap();
+	tx.set_clock(Timestamp {
+		value: 1_010_000,
+	});
+	assert!(tx.get("test").await.unwrap().is_none());
+	assert!(!tx.exi("test").await.unwrap());
+	let val = tx.get("text").await.unwrap();
+	assert!(matches!(val.as_deref(), Some(b"ok")));
+	let val = tx.scan("t".."u", 10).await.unwrap();
+	assert_eq!(val.len(), 1);
+	assert_eq!(val[0].0, b"text");
+	let val = tx.keys("t".."u", 1).await.unwrap();
+	assert_eq!(val, vec![b"text".to_vec()]);
+	tx.cancel().await.unwrap();
+	// Expired keys are removed by compaction
+	let mut tx = ds.transaction(true, false).await.unwrap();
+	tx.set_clock(Timestamp {
+		value: 1_010_000,
+	});
+	assert_eq!(tx.compact_expired(100).await.unwrap(), 1);
+	tx.commit().await.unwrap();
+	let mut tx = ds.transaction(false, false).await.unwrap();
+	assert!(tx.get(crate::key::root::tl::new(b"test")).await.unwrap().is_none());
+	assert!(tx.get("test").await.unwrap().is_none());
+	assert!(tx.get("text").await.unwrap().is_some());
+	tx.cancel().await.unwrap();
+}
+
+#[tokio::test]
+#[serial]
+async fn set_clears_ttl() {
+	use crate::dbs::node::Timestamp;
+	use std::time::Duration;
+	// Create a new datastore
+	let ds = new_ds().await;
+	// Create a writeable transaction
+	let mut tx = ds.transaction(true, false).await.unwrap();
+	tx.set_clock(Timestamp {
+		value: 1_000_000,
+	});
+	tx.set_with_ttl("test", "ok", Duration::from_secs(10)).await.unwrap();
+	tx.set_with_ttl("text", "ok", Duration::from_secs(10)).await.unwrap();
+	tx.set("test", "new").await.unwrap();
+	tx.del("text").await.unwrap();
+	tx.commit().await.unwrap();
+	// The expiry times are removed
+	let mut tx = ds.transaction(true, false).await.unwrap();
+	tx.set_clock(Timestamp {
+		value: 1_010_000,
+	});
+	assert!(tx.get(crate::key::root::tl::new(b"test")).await.unwrap().is_none());
+	assert!(tx.get(crate::key::root::tl::new(b"text")).await.unwrap().is_none());
+	// The newer value does not expire, and is kept by compaction
+	assert_eq!(tx.compact_expired(100).await.unwrap(), 0);
+	let val = tx.get("test").await.unwrap();
+	assert!(matches!(val.as_deref(), Some(b"new")));
+	assert!(tx.get("text").await.unwrap().is_none());
+	tx.cancel().await.unwrap();
+}
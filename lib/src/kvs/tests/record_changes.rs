@@ -0,0 +1,63 @@
+#[tokio::test]
+#[serial]
+async fn update_records_one_change_with_before_and_after() {
+	let test = match init().await {
+		Ok(test) => test,
+		Err(e) => panic!("{}", e),
+	};
+	let ctx = context::Context::background();
+	let opt = Options::new()
+		.with_id(Uuid::new_v4())
+		.with_ns(Some("test".into()))
+		.with_db(Some("test".into()))
+		.with_auth(Arc::new(crate::dbs::Auth::Kv));
+
+	// Create the record in a first transaction
+	let mut txn = test.db.transaction(true, false).await.unwrap();
+	txn.track_record_changes(true);
+	let tx = Arc::new(Mutex::new(txn));
+	let stm = sql::parse("CREATE person:test SET name = 'Tobie'").unwrap();
+	stm[0].compute(&ctx, &opt, &tx, None).await.unwrap();
+	let mut txn = tx.lock().await;
+	assert_eq!(txn.record_changes().len(), 1);
+	assert_eq!(txn.record_changes()[0].before, Value::None);
+	txn.commit().await.unwrap();
+	drop(txn);
+
+	// Update the record in a second transaction
+	let mut txn = test.db.transaction(true, false).await.unwrap();
+	txn.track_record_changes(true);
+	let tx = Arc::new(Mutex::new(txn));
+	let stm = sql::parse("UPDATE person:test SET name = 'Jaime'").unwrap();
+	stm[0].compute(&ctx, &opt, &tx, None).await.unwrap();
+	let mut txn = tx.lock().await;
+	let changes = txn.record_changes();
+	assert_eq!(changes.len(), 1);
+	assert_eq!(changes[0].tb, "person");
+	assert_eq!(changes[0].id, sql::thing("person:test").unwrap());
+	assert_eq!(changes[0].before, sql::value("{ id: person:test, name: 'Tobie' }").unwrap());
+	assert_eq!(changes[0].after, sql::value("{ id: person:test, name: 'Jaime' }").unwrap());
+	txn.commit().await.unwrap();
+}
+
+#[tokio::test]
+#[serial]
+async fn record_changes_are_not_tracked_by_default() {
+	let test = match init().await {
+		Ok(test) => test,
+		Err(e) => panic!("{}", e),
+	};
+	let ctx = context::Context::background();
+	let opt = Options::new()
+		.with_id(Uuid::new_v4())
+		.with_ns(Some("test".into()))
+		.with_db(Some("test".into()))
+		.with_auth(Arc::new(crate::dbs::Auth::Kv));
+
+	let tx = Arc::new(Mutex::new(test.db.transaction(true, false).await.unwrap()));
+	let stm = sql::parse("CREATE person:test SET name = 'Tobie'").unwrap();
+	stm[0].compute(&ctx, &opt, &tx, None).await.unwrap();
+	let mut txn = tx.lock().await;
+	assert!(txn.record_changes().is_empty());
+	txn.commit().await.unwrap();
+}
@@ -1,6 +1,8 @@
 use crate::key::database::tb;
 use crate::key::database::tb::Tb;
-use crate::sql::statements::DefineTableStatement;
+use crate::sql::statements::{
+	DefineDatabaseStatement, DefineNamespaceStatement, DefineTableStatement,
+};
 
 #[tokio::test]
 #[serial]
@@ -88,3 +90,214 @@ async fn table_definitions_can_be_deleted() {
 		Err(e) => panic!("Unexpected error on get {:?}", e),
 	};
 }
+
+#[tokio::test]
+#[serial]
+async fn table_definition_keys_can_be_listed() {
+	// Setup
+	let test = match init().await {
+		Ok(ctx) => ctx,
+		Err(e) => panic!("{:?}", e),
+	};
+	let mut tx = match test.db.transaction(true, false).await {
+		Ok(tx) => tx,
+		Err(e) => panic!("{:?}", e),
+	};
+
+	// Create several table definitions
+	let namespace = "test_namespace";
+	let database = "test_database";
+	let tables = ["test_table_a", "test_table_b", "test_table_c"];
+	for table in tables {
+		let key = Tb::new(namespace, database, table);
+		let value = DefineTableStatement {
+			name: table.into(),
+			drop: false,
+			full: false,
+			view: None,
+			permissions: Default::default(),
+			changefeed: None,
+		};
+		match tx.set(&key, &value).await {
+			Ok(_) => {}
+			Err(e) => panic!("{:?}", e),
+		};
+	}
+
+	// Validate with keys
+	match tx.keys(tb::prefix(namespace, database)..tb::suffix(namespace, database), 1000).await {
+		Ok(keys) => {
+			let expected: Vec<Vec<u8>> = tables
+				.iter()
+				.map(|table| Tb::new(namespace, database, table).encode().unwrap())
+				.collect();
+			assert_eq!(keys, expected);
+			for key in keys.iter() {
+				assert!(key > &tb::prefix(namespace, database));
+				assert!(key < &tb::suffix(namespace, database));
+			}
+		}
+		Err(e) => panic!("{:?}", e),
+	}
+}
+
+#[tokio::test]
+#[serial]
+async fn table_definitions_can_be_deleted_by_range() {
+	// Setup
+	let test = match init().await {
+		Ok(ctx) => ctx,
+		Err(e) => panic!("{:?}", e),
+	};
+	let mut tx = match test.db.transaction(true, false).await {
+		Ok(tx) => tx,
+		Err(e) => panic!("{:?}", e),
+	};
+
+	// Create several table definitions
+	let namespace = "test_namespace";
+	let database = "test_database";
+	let tables = ["test_table_a", "test_table_b", "test_table_c"];
+	for table in tables {
+		let key = Tb::new(namespace, database, table);
+		let value = DefineTableStatement {
+			name: table.into(),
+			drop: false,
+			full: false,
+			view: None,
+			permissions: Default::default(),
+			changefeed: None,
+		};
+		match tx.set(&key, &value).await {
+			Ok(_) => {}
+			Err(e) => panic!("{:?}", e),
+		};
+	}
+
+	// Validate range delete
+	match tx.delr(tb::prefix(namespace, database)..tb::suffix(namespace, database), 1000).await {
+		Ok(num) => assert_eq!(num, 3),
+		Err(e) => panic!("{:?}", e),
+	};
+
+	// Should not exist
+	match tx.scan(tb::prefix(namespace, database)..tb::suffix(namespace, database), 1000).await {
+		Ok(scan) => assert!(scan.is_empty()),
+		Err(e) => panic!("{:?}", e),
+	}
+}
+
+#[tokio::test]
+#[serial]
+async fn table_definitions_can_be_listed() {
+	// Setup
+	let test = match init().await {
+		Ok(ctx) => ctx,
+		Err(e) => panic!("{:?}", e),
+	};
+	let mut tx = match test.db.transaction(true, false).await {
+		Ok(tx) => tx,
+		Err(e) => panic!("{:?}", e),
+	};
+
+	// Create the namespace, database, and table definitions
+	let namespace = "test_namespace";
+	let database = "test_database";
+	let ns = DefineNamespaceStatement {
+		name: namespace.into(),
+	};
+	match tx.set(crate::key::root::ns::new(namespace), &ns).await {
+		Ok(_) => {}
+		Err(e) => panic!("{:?}", e),
+	};
+	let db = DefineDatabaseStatement {
+		name: database.into(),
+		changefeed: None,
+	};
+	match tx.set(crate::key::namespace::db::new(namespace, database), &db).await {
+		Ok(_) => {}
+		Err(e) => panic!("{:?}", e),
+	};
+	let tables: Vec<DefineTableStatement> = ["test_table_a", "test_table_b"]
+		.into_iter()
+		.map(|table| DefineTableStatement {
+			name: table.into(),
+			drop: false,
+			full: true,
+			view: None,
+			permissions: Default::default(),
+			changefeed: None,
+		})
+		.collect();
+	for value in tables.iter() {
+		let key = Tb::new(namespace, database, &value.name);
+		match tx.set(&key, value).await {
+			Ok(_) => {}
+			Err(e) => panic!("{:?}", e),
+		};
+	}
+
+	// Validate the decoded definitions
+	match tx.all_ns().await {
+		Ok(all) => assert_eq!(&*all, &[ns]),
+		Err(e) => panic!("{:?}", e),
+	}
+	match tx.all_db(namespace).await {
+		Ok(all) => assert_eq!(&*all, &[db]),
+		Err(e) => panic!("{:?}", e),
+	}
+	match tx.all_tb(namespace, database).await {
+		Ok(all) => assert_eq!(&*all, tables.as_slice()),
+		Err(e) => panic!("{:?}", e),
+	}
+}
+
+#[tokio::test]
+#[serial]
+async fn table_definitions_can_be_fetched_together() {
+	// Setup
+	let test = match init().await {
+		Ok(ctx) => ctx,
+		Err(e) => panic!("{:?}", e),
+	};
+	let mut tx = match test.db.transaction(true, false).await {
+		Ok(tx) => tx,
+		Err(e) => panic!("{:?}", e),
+	};
+
+	// Create three table definitions
+	let namespace = "test_namespace";
+	let database = "test_database";
+	let mut keys: Vec<crate::kvs::Key> = Vec::new();
+	let mut values = Vec::new();
+	for table in ["one", "two", "three"] {
+		let key = Tb::new(namespace, database, table);
+		let value = DefineTableStatement {
+			name: table.into(),
+			..Default::default()
+		};
+		match tx.set(&key, &value).await {
+			Ok(_) => {}
+			Err(e) => panic!("{:?}", e),
+		};
+		keys.push((&key).into());
+		values.push(value);
+	}
+
+	// Delete the second table definition
+	match tx.del(&Tb::new(namespace, database, "two")).await {
+		Ok(_) => {}
+		Err(e) => panic!("{:?}", e),
+	};
+
+	// Validate the values are returned in order
+	match tx.get_many(&keys).await {
+		Ok(res) => {
+			assert_eq!(res.len(), 3);
+			assert_eq!(DefineTableStatement::from(res[0].as_ref().unwrap()), values[0]);
+			assert!(res[1].is_none());
+			assert_eq!(DefineTableStatement::from(res[2].as_ref().unwrap()), values[2]);
+		}
+		Err(e) => panic!("{:?}", e),
+	}
+}
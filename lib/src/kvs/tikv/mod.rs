@@ -4,6 +4,7 @@ use crate::err::Error;
 use crate::kvs::Key;
 use crate::kvs::Val;
 use crate::vs::{try_to_u64_be, u64_to_versionstamp, Versionstamp};
+use std::collections::HashMap;
 use std::ops::Range;
 use tikv::CheckLevel;
 use tikv::TimestampExt;
@@ -188,6 +189,18 @@ impl Transaction {
 		// Return result
 		Ok(res)
 	}
+	/// Fetch many keys from the database in a single request
+	pub async fn get_many(&mut self, keys: &[Key]) -> Result<Vec<Option<Val>>, Error> {
+		// Check to see if transaction is closed
+		if self.ok {
+			return Err(Error::TxFinished);
+		}
+		// Get the keys
+		let res = self.tx.batch_get(keys.to_vec()).await?;
+		let mut res: HashMap<Key, Val> = res.map(|kv| (Key::from(kv.0), kv.1)).collect();
+		// Return the values in the order of the keys
+		Ok(keys.iter().map(|k| res.remove(k)).collect())
+	}
 	/// Insert or update a key in the database
 	pub async fn set<K, V>(&mut self, key: K, val: V) -> Result<(), Error>
 	where
@@ -327,4 +340,24 @@ impl Transaction {
 		// Return result
 		Ok(res)
 	}
+	/// Retrieve a range of keys from the databases, without their values
+	pub async fn keys<K>(&mut self, rng: Range<K>, limit: u32) -> Result<Vec<Key>, Error>
+	where
+		K: Into<Key>,
+	{
+		// Check to see if transaction is closed
+		if self.ok {
+			return Err(Error::TxFinished);
+		}
+		// Convert the range to bytes
+		let rng: Range<Key> = Range {
+			start: rng.start.into(),
+			end: rng.end.into(),
+		};
+		// Scan the keys
+		let res = self.tx.scan_keys(rng, limit).await?;
+		let res = res.map(Key::from).collect();
+		// Return result
+		Ok(res)
+	}
 }
@@ -1,3 +1,4 @@
+use super::ds::InFlight;
 use super::kv::Add;
 use super::kv::Convert;
 use super::Key;
@@ -6,6 +7,7 @@ use crate::cf;
 use crate::dbs::node::ClusterMembership;
 use crate::dbs::node::Timestamp;
 use crate::err::Error;
+use crate::key::root::tl;
 use crate::kvs::cache::Cache;
 use crate::kvs::cache::Entry;
 use crate::kvs::LqValue;
@@ -19,6 +21,7 @@ use crate::sql::Value;
 use crate::vs::Versionstamp;
 use channel::Sender;
 use sql::permission::Permissions;
+use sql::statements::DefineAccessStatement;
 use sql::statements::DefineAnalyzerStatement;
 use sql::statements::DefineDatabaseStatement;
 use sql::statements::DefineEventStatement;
@@ -33,11 +36,13 @@ use sql::statements::DefineTableStatement;
 use sql::statements::DefineTokenStatement;
 use sql::statements::LiveStatement;
 use std::borrow::Cow;
+use std::collections::HashSet;
 use std::fmt;
 use std::fmt::Debug;
 use std::ops::Range;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use uuid::Uuid;
 
 /// A set of undoable updates and requests against a dataset.
@@ -46,8 +51,29 @@ pub struct Transaction {
 	pub(super) inner: Inner,
 	pub(super) cache: Cache,
 	pub(super) cf: cf::Writer,
+	pub(super) clock: Option<Timestamp>,
+	pub(super) changes: Option<Vec<RecordChange>>,
+	pub(super) inflight: Option<InFlight>,
+	pub(super) expiry: Arc<AtomicBool>,
 }
 
+/// A change to a record which was written within a transaction.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecordChange {
+	/// The table which the record belongs to
+	pub tb: String,
+	/// The id of the record which was changed
+	pub id: Thing,
+	/// The record before the change, or `NONE` if it was created
+	pub before: Value,
+	/// The record after the change, or `NONE` if it was deleted
+	pub after: Value,
+}
+
+/// An opaque position within a key range, used to resume a paginated scan.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Cursor(Key);
+
 #[allow(clippy::large_enum_variant)]
 pub(super) enum Inner {
 	#[cfg(feature = "kv-mem")]
@@ -62,6 +88,7 @@ pub(super) enum Inner {
 	TiKV(super::tikv::Transaction),
 	#[cfg(feature = "kv-fdb")]
 	FoundationDB(super::fdb::Transaction),
+	Custom(Box<dyn super::KvTransaction>),
 }
 
 impl fmt::Display for Transaction {
@@ -80,6 +107,7 @@ impl fmt::Display for Transaction {
 			Inner::TiKV(_) => write!(f, "tikv"),
 			#[cfg(feature = "kv-fdb")]
 			Inner::FoundationDB(_) => write!(f, "fdb"),
+			Inner::Custom(_) => write!(f, "custom"),
 			#[allow(unreachable_patterns)]
 			_ => unreachable!(),
 		}
@@ -131,6 +159,10 @@ impl Transaction {
 				inner: Inner::FoundationDB(v),
 				..
 			} => v.closed(),
+			Transaction {
+				inner: Inner::Custom(v),
+				..
+			} => v.closed(),
 			#[allow(unreachable_patterns)]
 			_ => unreachable!(),
 		}
@@ -142,7 +174,7 @@ impl Transaction {
 	pub async fn cancel(&mut self) -> Result<(), Error> {
 		#[cfg(debug_assertions)]
 		trace!("Cancel");
-		match self {
+		let res = match self {
 			#[cfg(feature = "kv-mem")]
 			Transaction {
 				inner: Inner::Mem(v),
@@ -173,9 +205,16 @@ impl Transaction {
 				inner: Inner::FoundationDB(v),
 				..
 			} => v.cancel().await,
+			Transaction {
+				inner: Inner::Custom(v),
+				..
+			} => v.cancel().await,
 			#[allow(unreachable_patterns)]
 			_ => unreachable!(),
-		}
+		};
+		// The transaction is no longer in-flight
+		self.inflight = None;
+		res
 	}
 
 	/// Commit a transaction.
@@ -184,7 +223,12 @@ impl Transaction {
 	pub async fn commit(&mut self) -> Result<(), Error> {
 		#[cfg(debug_assertions)]
 		trace!("Commit");
-		match self {
+		// Roll back the transaction if the datastore was shut down before it finished
+		if self.inflight.as_ref().map_or(false, InFlight::closed) {
+			self.cancel().await?;
+			return Err(Error::DsShutdown);
+		}
+		let res = match self {
 			#[cfg(feature = "kv-mem")]
 			Transaction {
 				inner: Inner::Mem(v),
@@ -215,19 +259,33 @@ impl Transaction {
 				inner: Inner::FoundationDB(v),
 				..
 			} => v.commit().await,
+			Transaction {
+				inner: Inner::Custom(v),
+				..
+			} => v.commit().await,
 			#[allow(unreachable_patterns)]
 			_ => unreachable!(),
-		}
+		};
+		// The transaction is no longer in-flight
+		self.inflight = None;
+		res
 	}
 
 	/// Delete a key from the datastore.
-	#[allow(unused_variables)]
 	pub async fn del<K>(&mut self, key: K) -> Result<(), Error>
 	where
 		K: Into<Key> + Debug,
 	{
 		#[cfg(debug_assertions)]
 		trace!("Del {:?}", key);
+		let key: Key = key.into();
+		self.clear_expiry(&key).await?;
+		self.raw_del(key).await
+	}
+
+	// Delete a key from the datastore, leaving any expiry time in place.
+	#[allow(unused_variables)]
+	async fn raw_del(&mut self, key: Key) -> Result<(), Error> {
 		match self {
 			#[cfg(feature = "kv-mem")]
 			Transaction {
@@ -259,6 +317,10 @@ impl Transaction {
 				inner: Inner::FoundationDB(v),
 				..
 			} => v.del(key).await,
+			Transaction {
+				inner: Inner::Custom(v),
+				..
+			} => v.del(key.into()).await,
 			#[allow(unreachable_patterns)]
 			_ => unreachable!(),
 		}
@@ -272,6 +334,11 @@ impl Transaction {
 	{
 		#[cfg(debug_assertions)]
 		trace!("Exi {:?}", key);
+		let key: Key = key.into();
+		// Keys which have expired are treated as absent
+		if self.expired(&key).await? {
+			return Ok(false);
+		}
 		match self {
 			#[cfg(feature = "kv-mem")]
 			Transaction {
@@ -303,19 +370,33 @@ impl Transaction {
 				inner: Inner::FoundationDB(v),
 				..
 			} => v.exi(key).await,
+			Transaction {
+				inner: Inner::Custom(v),
+				..
+			} => v.exi(key).await,
 			#[allow(unreachable_patterns)]
 			_ => unreachable!(),
 		}
 	}
 
 	/// Fetch a key from the datastore.
-	#[allow(unused_variables)]
 	pub async fn get<K>(&mut self, key: K) -> Result<Option<Val>, Error>
 	where
 		K: Into<Key> + Debug,
 	{
 		#[cfg(debug_assertions)]
 		trace!("Get {:?}", key);
+		let key: Key = key.into();
+		// Keys which have expired are treated as absent
+		if self.expired(&key).await? {
+			return Ok(None);
+		}
+		self.raw_get(key).await
+	}
+
+	// Fetch a key from the datastore, without checking whether it has expired.
+	#[allow(unused_variables)]
+	async fn raw_get(&mut self, key: Key) -> Result<Option<Val>, Error> {
 		match self {
 			#[cfg(feature = "kv-mem")]
 			Transaction {
@@ -347,11 +428,38 @@ impl Transaction {
 				inner: Inner::FoundationDB(v),
 				..
 			} => v.get(key).await,
+			Transaction {
+				inner: Inner::Custom(v),
+				..
+			} => v.get(key.into()).await,
 			#[allow(unreachable_patterns)]
 			_ => unreachable!(),
 		}
 	}
 
+	/// Fetch many keys from the datastore, in a single request where the storage engine supports it.
+	///
+	/// The values are returned in the same order as the keys, with `None` for any key which does not exist.
+	pub async fn get_many(&mut self, keys: &[Key]) -> Result<Vec<Option<Val>>, Error> {
+		#[cfg(debug_assertions)]
+		trace!("GetMany {:?}", keys);
+		#[cfg(feature = "kv-tikv")]
+		if !self.expiry.load(Ordering::Relaxed) {
+			if let Transaction {
+				inner: Inner::TiKV(v),
+				..
+			} = self
+			{
+				return v.get_many(keys).await;
+			}
+		}
+		let mut vals = Vec::with_capacity(keys.len());
+		for key in keys {
+			vals.push(self.get(key.clone()).await?);
+		}
+		Ok(vals)
+	}
+
 	/// Insert or update a key in the datastore.
 	#[allow(unused_variables)]
 	pub async fn set<K, V>(&mut self, key: K, val: V) -> Result<(), Error>
@@ -361,6 +469,8 @@ impl Transaction {
 	{
 		#[cfg(debug_assertions)]
 		trace!("Set {:?} => {:?}", key, val);
+		let key: Key = key.into();
+		self.clear_expiry(&key).await?;
 		match self {
 			#[cfg(feature = "kv-mem")]
 			Transaction {
@@ -392,6 +502,10 @@ impl Transaction {
 				inner: Inner::FoundationDB(v),
 				..
 			} => v.set(key, val).await,
+			Transaction {
+				inner: Inner::Custom(v),
+				..
+			} => v.set(key, val.into()).await,
 			#[allow(unreachable_patterns)]
 			_ => unreachable!(),
 		}
@@ -440,6 +554,10 @@ impl Transaction {
 				inner: Inner::SpeeDB(v),
 				..
 			} => v.get_timestamp(key).await,
+			Transaction {
+				inner: Inner::Custom(v),
+				..
+			} => v.get_timestamp(key.into()).await,
 			#[allow(unreachable_patterns)]
 			_ => unreachable!(),
 		}
@@ -506,6 +624,14 @@ impl Transaction {
 				let k = v.get_versionstamped_key(ts_key, prefix, suffix).await?;
 				v.set(k, val).await
 			}
+			Transaction {
+				inner: Inner::Custom(v),
+				..
+			} => {
+				let k =
+					v.get_versionstamped_key(ts_key.into(), prefix.into(), suffix.into()).await?;
+				v.set(k, val.into()).await
+			}
 			#[allow(unreachable_patterns)]
 			_ => unreachable!(),
 		}
@@ -551,6 +677,10 @@ impl Transaction {
 				inner: Inner::FoundationDB(v),
 				..
 			} => v.put(key, val).await,
+			Transaction {
+				inner: Inner::Custom(v),
+				..
+			} => v.put(key, val.into()).await,
 			#[allow(unreachable_patterns)]
 			_ => unreachable!(),
 		}
@@ -558,14 +688,46 @@ impl Transaction {
 
 	/// Retrieve a specific range of keys from the datastore.
 	///
-	/// This function fetches the full range of key-value pairs, in a single request to the underlying datastore.
-	#[allow(unused_variables)]
+	/// This function fetches the full range of key-value pairs, in a single request to the underlying
+	/// datastore. Keys which have expired are skipped, which may require further requests.
 	pub async fn scan<K>(&mut self, rng: Range<K>, limit: u32) -> Result<Vec<(Key, Val)>, Error>
 	where
 		K: Into<Key> + Debug,
 	{
 		#[cfg(debug_assertions)]
 		trace!("Scan {:?} - {:?}", rng.start, rng.end);
+		let mut beg: Key = rng.start.into();
+		let end: Key = rng.end.into();
+		// Only check expiry times if keys have been set with a time-to-live
+		if !self.expiry.load(Ordering::Relaxed) {
+			return self.raw_scan(beg..end, limit).await;
+		}
+		let now = self.clock().value;
+		let mut out = Vec::new();
+		while out.len() < limit as usize {
+			let num = limit - out.len() as u32;
+			let res = self.raw_scan(beg.clone()..end.clone(), num).await?;
+			let (Some((first, _)), Some((last, _))) = (res.first(), res.last()) else {
+				break;
+			};
+			// Fetch the expiry times of the keys in this batch
+			let rng = tl::new(first)..tl::new(last).add(0x00);
+			let exp = self.raw_scan(rng, u32::MAX).await?;
+			let expired: HashSet<&[u8]> =
+				exp.iter().filter(|(_, v)| tl::expiry(v) <= now).map(|(k, _)| tl::key(k)).collect();
+			let done = res.len() < num as usize;
+			beg = last.clone().add(0x00);
+			out.extend(res.iter().filter(|(k, _)| !expired.contains(k.as_slice())).cloned());
+			if done {
+				break;
+			}
+		}
+		Ok(out)
+	}
+
+	// Retrieve a specific range of keys from the datastore, including keys which have expired.
+	#[allow(unused_variables)]
+	async fn raw_scan(&mut self, rng: Range<Key>, limit: u32) -> Result<Vec<(Key, Val)>, Error> {
 		match self {
 			#[cfg(feature = "kv-mem")]
 			Transaction {
@@ -597,12 +759,129 @@ impl Transaction {
 				inner: Inner::FoundationDB(v),
 				..
 			} => v.scan(rng, limit).await,
+			Transaction {
+				inner: Inner::Custom(v),
+				..
+			} => v.scan(rng.start.into()..rng.end.into(), limit).await,
+			#[allow(unreachable_patterns)]
+			_ => unreachable!(),
+		}
+	}
+
+	/// Retrieve a page of keys from the start of a specific range in the datastore.
+	///
+	/// If there are further keys in the range, a [`Cursor`] is returned which
+	/// can be passed to [`Transaction::scan_page_from`] to retrieve the next page.
+	/// An error is returned if the limit is zero.
+	pub async fn scan_page<K>(
+		&mut self,
+		rng: Range<K>,
+		limit: u32,
+	) -> Result<(Vec<(Key, Val)>, Option<Cursor>), Error>
+	where
+		K: Into<Key> + Debug,
+	{
+		let beg: Key = rng.start.into();
+		let end: Key = rng.end.into();
+		self.page(beg..end, limit).await
+	}
+
+	/// Retrieve the next page of keys in a specific range in the datastore, resuming from a [`Cursor`].
+	///
+	/// Every key in the range is returned in exactly one page, with no duplicates or gaps between pages.
+	pub async fn scan_page_from<K>(
+		&mut self,
+		rng: Range<K>,
+		cursor: Cursor,
+		limit: u32,
+	) -> Result<(Vec<(Key, Val)>, Option<Cursor>), Error>
+	where
+		K: Into<Key> + Debug,
+	{
+		let beg: Key = rng.start.into();
+		let end: Key = rng.end.into();
+		// Never resume from before the start of the range
+		let beg = std::cmp::max(beg, cursor.0);
+		self.page(beg..end, limit).await
+	}
+
+	async fn page(
+		&mut self,
+		rng: Range<Key>,
+		limit: u32,
+	) -> Result<(Vec<(Key, Val)>, Option<Cursor>), Error> {
+		// An empty page would never move past the cursor
+		if limit == 0 {
+			return Err(Error::InvalidPageLimit);
+		}
+		// Fetch one extra key to check for a further page
+		let mut res = self.scan(rng, limit.saturating_add(1)).await?;
+		// The next page resumes from the extra key
+		let cursor = match res.len() > limit as usize {
+			true => res.pop().map(|(k, _)| Cursor(k)),
+			false => None,
+		};
+		Ok((res, cursor))
+	}
+
+	/// Retrieve a specific range of keys from the datastore, without their values.
+	///
+	/// This function fetches the full range of keys, in a single request to the underlying datastore.
+	#[allow(unused_variables)]
+	pub async fn keys<K>(&mut self, rng: Range<K>, limit: u32) -> Result<Vec<Key>, Error>
+	where
+		K: Into<Key> + Debug,
+	{
+		#[cfg(debug_assertions)]
+		trace!("Keys {:?} - {:?}", rng.start, rng.end);
+		// Keys which have expired are skipped using their values
+		if self.expiry.load(Ordering::Relaxed) {
+			return Ok(self.scan(rng, limit).await?.into_iter().map(|(k, _)| k).collect());
+		}
+		match self {
+			#[cfg(feature = "kv-mem")]
+			Transaction {
+				inner: Inner::Mem(v),
+				..
+			} => v.keys(rng, limit),
+			#[cfg(feature = "kv-rocksdb")]
+			Transaction {
+				inner: Inner::RocksDB(v),
+				..
+			} => v.keys(rng, limit).await,
+			#[cfg(feature = "kv-speedb")]
+			Transaction {
+				inner: Inner::SpeeDB(v),
+				..
+			} => v.keys(rng, limit).await,
+			#[cfg(feature = "kv-indxdb")]
+			Transaction {
+				inner: Inner::IndxDB(v),
+				..
+			} => v.keys(rng, limit).await,
+			#[cfg(feature = "kv-tikv")]
+			Transaction {
+				inner: Inner::TiKV(v),
+				..
+			} => v.keys(rng, limit).await,
+			#[cfg(feature = "kv-fdb")]
+			Transaction {
+				inner: Inner::FoundationDB(v),
+				..
+			} => v.keys(rng, limit).await,
+			Transaction {
+				inner: Inner::Custom(v),
+				..
+			} => v.keys(rng.start.into()..rng.end.into(), limit).await,
 			#[allow(unreachable_patterns)]
 			_ => unreachable!(),
 		}
 	}
 
 	/// Update a key in the datastore if the current value matches a condition.
+	///
+	/// When `chk` is [`None`] the key is only set if it does not already exist. If the
+	/// current value does not match, this function returns an [`Error::TxConditionNotMet`] error.
 	#[allow(unused_variables)]
 	pub async fn putc<K, V>(&mut self, key: K, val: V, chk: Option<V>) -> Result<(), Error>
 	where
@@ -642,6 +921,10 @@ impl Transaction {
 				inner: Inner::FoundationDB(v),
 				..
 			} => v.putc(key, val, chk).await,
+			Transaction {
+				inner: Inner::Custom(v),
+				..
+			} => v.putc(key, val.into(), chk.map(Into::into)).await,
 			#[allow(unreachable_patterns)]
 			_ => unreachable!(),
 		}
@@ -687,11 +970,79 @@ impl Transaction {
 				inner: Inner::FoundationDB(v),
 				..
 			} => v.delc(key, chk).await,
+			Transaction {
+				inner: Inner::Custom(v),
+				..
+			} => v.delc(key, chk.map(Into::into)).await,
 			#[allow(unreachable_patterns)]
 			_ => unreachable!(),
 		}
 	}
 
+	// --------------------------------------------------
+	// Expiry methods
+	// --------------------------------------------------
+
+	/// Insert or update a key in the datastore, which expires after the specified duration.
+	///
+	/// None of the supported datastores expire individual keys within a transaction, so
+	/// the expiry time is stored under a separate key. Once the key has expired it is
+	/// treated as absent, and it remains in the datastore until it is removed with
+	/// [`Transaction::compact_expired`]. Setting or deleting the key removes its expiry time.
+	pub async fn set_with_ttl<K, V>(&mut self, key: K, val: V, ttl: Duration) -> Result<(), Error>
+	where
+		K: Into<Key> + Debug,
+		V: Into<Val> + Debug,
+	{
+		let key: Key = key.into();
+		let exp = self.clock().value.saturating_add(ttl.as_millis() as u64);
+		self.expiry.store(true, Ordering::Relaxed);
+		self.set(key.clone(), val).await?;
+		self.set(tl::new(&key), exp.to_be_bytes().to_vec()).await
+	}
+
+	// Check whether a key was set with a time-to-live, and has expired.
+	async fn expired(&mut self, key: &[u8]) -> Result<bool, Error> {
+		if !self.expiry.load(Ordering::Relaxed) {
+			return Ok(false);
+		}
+		let now = self.clock().value;
+		Ok(matches!(self.raw_get(tl::new(key)).await?, Some(v) if tl::expiry(&v) <= now))
+	}
+
+	// Remove the expiry time of a key, if keys have been set with a time-to-live.
+	async fn clear_expiry(&mut self, key: &[u8]) -> Result<(), Error> {
+		if self.expiry.load(Ordering::Relaxed) && !tl::is(key) {
+			self.raw_del(tl::new(key)).await?;
+		}
+		Ok(())
+	}
+
+	/// Delete keys which were set with a time-to-live, and which have expired.
+	///
+	/// At most `limit` expired keys are deleted, and the number of deleted keys is returned.
+	pub async fn compact_expired(&mut self, limit: u32) -> Result<u32, Error> {
+		let now = self.clock().value;
+		let mut beg = tl::prefix();
+		let end = tl::suffix();
+		let mut num = 0;
+		while num < limit {
+			let res = self.raw_scan(beg.clone()..end.clone(), 1000).await?;
+			let Some((last, _)) = res.last() else {
+				break;
+			};
+			beg = last.clone().add(0x00);
+			for (k, v) in res {
+				if num < limit && tl::expiry(&v) <= now {
+					self.raw_del(tl::key(&k).to_vec()).await?;
+					self.raw_del(k).await?;
+					num += 1;
+				}
+			}
+		}
+		Ok(num)
+	}
+
 	// --------------------------------------------------
 	// Superjacent methods
 	// --------------------------------------------------
@@ -748,8 +1099,9 @@ impl Transaction {
 	}
 	/// Delete a range of keys from the datastore.
 	///
-	/// This function fetches key-value pairs from the underlying datastore in batches of 1000.
-	pub async fn delr<K>(&mut self, rng: Range<K>, limit: u32) -> Result<(), Error>
+	/// This function fetches keys, without their values, from the underlying datastore in
+	/// batches of 1000, and returns the total number of keys which were deleted.
+	pub async fn delr<K>(&mut self, rng: Range<K>, limit: u32) -> Result<u32, Error>
 	where
 		K: Into<Key>,
 	{
@@ -759,20 +1111,20 @@ impl Transaction {
 		let mut num = limit;
 		// Start processing
 		while num > 0 {
-			// Get records batch
+			// Get keys batch
 			let res = match nxt {
 				None => {
 					let min = beg.clone();
 					let max = end.clone();
 					let num = std::cmp::min(1000, num);
-					self.scan(min..max, num).await?
+					self.keys(min..max, num).await?
 				}
 				Some(ref mut beg) => {
 					beg.push(0x00);
 					let min = beg.clone();
 					let max = end.clone();
 					let num = std::cmp::min(1000, num);
-					self.scan(min..max, num).await?
+					self.keys(min..max, num).await?
 				}
 			};
 			// Get total results
@@ -782,7 +1134,7 @@ impl Transaction {
 				break;
 			}
 			// Loop over results
-			for (i, (k, _)) in res.into_iter().enumerate() {
+			for (i, k) in res.into_iter().enumerate() {
 				// Ready the next
 				if n == i + 1 {
 					nxt = Some(k.clone());
@@ -793,7 +1145,7 @@ impl Transaction {
 				num -= 1;
 			}
 		}
-		Ok(())
+		Ok(limit - num)
 	}
 	/// Retrieve a specific prefix of keys from the datastore.
 	///
@@ -940,6 +1292,10 @@ impl Transaction {
 	}
 
 	pub(crate) fn clock(&self) -> Timestamp {
+		// Use a fixed clock if one has been set
+		if let Some(ts) = &self.clock {
+			return ts.clone();
+		}
 		// Use a timestamp oracle if available
 		let now: u128 = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis();
 		Timestamp {
@@ -947,6 +1303,12 @@ impl Transaction {
 		}
 	}
 
+	/// Fix the clock used by this transaction, for testing expiry
+	#[cfg(test)]
+	pub(crate) fn set_clock(&mut self, ts: Timestamp) {
+		self.clock = Some(ts);
+	}
+
 	// Set heartbeat
 	pub async fn set_hb(&mut self, timestamp: Timestamp, id: Uuid) -> Result<(), Error> {
 		let key = crate::key::root::hb::Hb::new(timestamp.clone(), id);
@@ -1193,6 +1555,25 @@ impl Transaction {
 		})
 	}
 
+	/// Retrieve all namespace access method definitions for a specific namespace.
+	pub async fn all_na(&mut self, ns: &str) -> Result<Arc<[DefineAccessStatement]>, Error> {
+		let key = crate::key::namespace::ac::prefix(ns);
+		Ok(if let Some(e) = self.cache.get(&key) {
+			if let Entry::Nas(v) = e {
+				v
+			} else {
+				unreachable!();
+			}
+		} else {
+			let beg = crate::key::namespace::ac::prefix(ns);
+			let end = crate::key::namespace::ac::suffix(ns);
+			let val = self.getr(beg..end, u32::MAX).await?;
+			let val = val.convert().into();
+			self.cache.set(key, Entry::Nas(Arc::clone(&val)));
+			val
+		})
+	}
+
 	/// Retrieve all database definitions for a specific namespace.
 	pub async fn all_db(&mut self, ns: &str) -> Result<Arc<[DefineDatabaseStatement]>, Error> {
 		let key = crate::key::namespace::db::prefix(ns);
@@ -1258,6 +1639,29 @@ impl Transaction {
 		})
 	}
 
+	/// Retrieve all database access method definitions for a specific database.
+	pub async fn all_da(
+		&mut self,
+		ns: &str,
+		db: &str,
+	) -> Result<Arc<[DefineAccessStatement]>, Error> {
+		let key = crate::key::database::ac::prefix(ns, db);
+		Ok(if let Some(e) = self.cache.get(&key) {
+			if let Entry::Das(v) = e {
+				v
+			} else {
+				unreachable!();
+			}
+		} else {
+			let beg = crate::key::database::ac::prefix(ns, db);
+			let end = crate::key::database::ac::suffix(ns, db);
+			let val = self.getr(beg..end, u32::MAX).await?;
+			let val = val.convert().into();
+			self.cache.set(key, Entry::Das(Arc::clone(&val)));
+			val
+		})
+	}
+
 	/// Retrieve all function definitions for a specific database.
 	pub async fn all_fc(
 		&mut self,
@@ -1328,6 +1732,30 @@ impl Transaction {
 		})
 	}
 
+	/// Retrieve all scope access method definitions for a scope.
+	pub async fn all_sa(
+		&mut self,
+		ns: &str,
+		db: &str,
+		sc: &str,
+	) -> Result<Arc<[DefineAccessStatement]>, Error> {
+		let key = crate::key::scope::ac::prefix(ns, db, sc);
+		Ok(if let Some(e) = self.cache.get(&key) {
+			if let Entry::Sas(v) = e {
+				v
+			} else {
+				unreachable!();
+			}
+		} else {
+			let beg = crate::key::scope::ac::prefix(ns, db, sc);
+			let end = crate::key::scope::ac::suffix(ns, db, sc);
+			let val = self.getr(beg..end, u32::MAX).await?;
+			let val = val.convert().into();
+			self.cache.set(key, Entry::Sas(Arc::clone(&val)));
+			val
+		})
+	}
+
 	/// Retrieve all scope definitions for a specific database.
 	pub async fn all_pa(
 		&mut self,
@@ -1567,6 +1995,15 @@ impl Transaction {
 		Ok(val.into())
 	}
 
+	/// Retrieve a specific namespace access method definition.
+	pub async fn get_na(&mut self, ns: &str, na: &str) -> Result<DefineAccessStatement, Error> {
+		let key = crate::key::namespace::ac::new(ns, na);
+		let val = self.get(key).await?.ok_or(Error::NaNotFound {
+			value: na.to_owned(),
+		})?;
+		Ok(val.into())
+	}
+
 	/// Retrieve a specific database definition.
 	pub async fn get_db(&mut self, ns: &str, db: &str) -> Result<DefineDatabaseStatement, Error> {
 		let key = crate::key::namespace::db::new(ns, db);
@@ -1604,6 +2041,20 @@ impl Transaction {
 		Ok(val.into())
 	}
 
+	/// Retrieve a specific database access method definition.
+	pub async fn get_da(
+		&mut self,
+		ns: &str,
+		db: &str,
+		da: &str,
+	) -> Result<DefineAccessStatement, Error> {
+		let key = crate::key::database::ac::new(ns, db, da);
+		let val = self.get(key).await?.ok_or(Error::DaNotFound {
+			value: da.to_owned(),
+		})?;
+		Ok(val.into())
+	}
+
 	/// Retrieve a specific scope definition.
 	pub async fn get_sc(
 		&mut self,
@@ -1633,6 +2084,21 @@ impl Transaction {
 		Ok(val.into())
 	}
 
+	/// Retrieve a specific scope access method definition.
+	pub async fn get_sa(
+		&mut self,
+		ns: &str,
+		db: &str,
+		sc: &str,
+		sa: &str,
+	) -> Result<DefineAccessStatement, Error> {
+		let key = crate::key::scope::ac::new(ns, db, sc, sa);
+		let val = self.get(key).await?.ok_or(Error::SaNotFound {
+			value: sa.to_owned(),
+		})?;
+		Ok(val.into())
+	}
+
 	/// Retrieve a specific function definition.
 	pub async fn get_fc(
 		&mut self,
@@ -2085,6 +2551,20 @@ impl Transaction {
 				chn.send(bytes!("")).await?;
 			}
 		}
+		// Output ACCESSES
+		{
+			let das = self.all_da(ns, db).await?;
+			if !das.is_empty() {
+				chn.send(bytes!("-- ------------------------------")).await?;
+				chn.send(bytes!("-- ACCESSES")).await?;
+				chn.send(bytes!("-- ------------------------------")).await?;
+				chn.send(bytes!("")).await?;
+				for da in das.iter() {
+					chn.send(bytes!(format!("{da};"))).await?;
+				}
+				chn.send(bytes!("")).await?;
+			}
+		}
 		// Output PARAMS
 		{
 			let pas = self.all_pa(ns, db).await?;
@@ -2120,6 +2600,16 @@ impl Transaction {
 							chn.send(bytes!("")).await?;
 						}
 					}
+					// Output ACCESSES
+					{
+						let sas = self.all_sa(ns, db, &sc.name).await?;
+						if !sas.is_empty() {
+							for sa in sas.iter() {
+								chn.send(bytes!(format!("{sa};"))).await?;
+							}
+							chn.send(bytes!("")).await?;
+						}
+					}
 				}
 				chn.send(bytes!("")).await?;
 			}
@@ -2275,6 +2765,39 @@ impl Transaction {
 		}
 	}
 
+	/// Enable or disable tracking of the record changes written within this transaction.
+	///
+	/// Record changes are not tracked by default, as every change holds a copy of
+	/// the record both before and after it was written.
+	pub fn track_record_changes(&mut self, enabled: bool) {
+		self.changes = match enabled {
+			true => Some(self.changes.take().unwrap_or_default()),
+			false => None,
+		};
+	}
+
+	// record_write tracks a record which was stored or deleted in this transaction,
+	// so that the changes can be inspected before the transaction is committed.
+	pub(crate) fn record_write(&mut self, id: &Thing, before: &Value, after: &Value) {
+		if let Some(changes) = &mut self.changes {
+			changes.push(RecordChange {
+				tb: id.tb.to_owned(),
+				id: id.clone(),
+				before: before.clone(),
+				after: after.clone(),
+			})
+		}
+	}
+
+	/// Retrieve the record changes which were written within this transaction.
+	///
+	/// The changes are listed in the order in which they were written, and
+	/// will be persisted when this transaction is committed. No changes are
+	/// listed unless they are tracked with [`Transaction::track_record_changes`].
+	pub fn record_changes(&self) -> &[RecordChange] {
+		self.changes.as_deref().unwrap_or_default()
+	}
+
 	// complete_changes will complete the changefeed recording for the given namespace and database.
 	//
 	// Under the hood, this function calls the transaction's `set_versionstamped_key` for each change.
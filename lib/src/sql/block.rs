@@ -13,8 +13,8 @@ use crate::sql::statements::ifelse::{ifelse, IfelseStatement};
 use crate::sql::statements::insert::{insert, InsertStatement};
 use crate::sql::statements::output::{output, OutputStatement};
 use crate::sql::statements::relate::{relate, RelateStatement};
-use crate::sql::statements::select::{select, SelectStatement};
-use crate::sql::statements::set::{set, SetStatement};
+use crate::sql::statements::select::{select, SelectStatement, SelectStatementV1};
+use crate::sql::statements::set::{set, SetStatement, SetStatementV1};
 use crate::sql::statements::update::{update, UpdateStatement};
 use crate::sql::value::{value, Value};
 use nom::branch::alt;
@@ -65,19 +65,25 @@ impl Block {
 		for v in self.iter() {
 			match v {
 				Entry::Set(v) => {
-					// Check if the variable is a protected variable
-					let val = match PROTECTED_PARAM_NAMES.contains(&v.name.as_str()) {
-						// The variable isn't protected and can be stored
-						false => v.compute(&ctx, opt, txn, doc).await,
+					// Check if any variable is a protected variable
+					let val = match v
+						.names()
+						.iter()
+						.find(|v| PROTECTED_PARAM_NAMES.contains(&v.as_str()))
+					{
+						// The variables aren't protected and can be stored
+						None => v.compute(&ctx, opt, txn, doc).await,
 						// The user tried to set a protected variable
-						true => {
+						Some(name) => {
 							return Err(Error::InvalidParam {
-								name: v.name.to_owned(),
+								name: name.to_owned(),
 							})
 						}
 					}?;
-					// Set the parameter
-					ctx.add_value(v.name.to_owned(), val);
+					// Set the parameters
+					for (name, val) in v.bind(val)? {
+						ctx.add_value(name, val);
+					}
 				}
 				Entry::Ifelse(v) => {
 					v.compute(&ctx, opt, txn, doc).await?;
@@ -167,6 +173,7 @@ pub fn block(i: &str) -> IResult<&str, Block> {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash)]
+#[serde(from = "EntryRepr", into = "EntryRepr")]
 pub enum Entry {
 	Value(Value),
 	Set(SetStatement),
@@ -180,6 +187,66 @@ pub enum Entry {
 	Output(OutputStatement),
 }
 
+/// The stored layout of an [`Entry`].
+///
+/// Statements which have gained fields keep their original layout in their original
+/// variants, so that blocks stored by earlier versions can still be decoded. Statements
+/// which use the new fields are stored in the variants appended to the end.
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Entry")]
+enum EntryRepr {
+	Value(Value),
+	Set(SetStatementV1),
+	Ifelse(IfelseStatement),
+	Select(SelectStatementV1),
+	Create(CreateStatement),
+	Update(UpdateStatement),
+	Delete(DeleteStatement),
+	Relate(RelateStatement),
+	Insert(InsertStatement),
+	Output(OutputStatement),
+	Destructure(SetStatement),
+	SelectDistinct(SelectStatement),
+}
+
+impl From<Entry> for EntryRepr {
+	fn from(v: Entry) -> Self {
+		match v {
+			Entry::Value(v) => Self::Value(v),
+			Entry::Set(v) if v.destructure.is_some() => Self::Destructure(v),
+			Entry::Set(v) => Self::Set(v.into()),
+			Entry::Ifelse(v) => Self::Ifelse(v),
+			Entry::Select(v) if v.distinct => Self::SelectDistinct(v),
+			Entry::Select(v) => Self::Select(v.into()),
+			Entry::Create(v) => Self::Create(v),
+			Entry::Update(v) => Self::Update(v),
+			Entry::Delete(v) => Self::Delete(v),
+			Entry::Relate(v) => Self::Relate(v),
+			Entry::Insert(v) => Self::Insert(v),
+			Entry::Output(v) => Self::Output(v),
+		}
+	}
+}
+
+impl From<EntryRepr> for Entry {
+	fn from(v: EntryRepr) -> Self {
+		match v {
+			EntryRepr::Value(v) => Self::Value(v),
+			EntryRepr::Set(v) => Self::Set(v.into()),
+			EntryRepr::Ifelse(v) => Self::Ifelse(v),
+			EntryRepr::Select(v) => Self::Select(v.into()),
+			EntryRepr::Create(v) => Self::Create(v),
+			EntryRepr::Update(v) => Self::Update(v),
+			EntryRepr::Delete(v) => Self::Delete(v),
+			EntryRepr::Relate(v) => Self::Relate(v),
+			EntryRepr::Insert(v) => Self::Insert(v),
+			EntryRepr::Output(v) => Self::Output(v),
+			EntryRepr::Destructure(v) => Self::Set(v),
+			EntryRepr::SelectDistinct(v) => Self::Select(v),
+		}
+	}
+}
+
 impl PartialOrd for Entry {
 	#[inline]
 	fn partial_cmp(&self, _: &Self) -> Option<Ordering> {
@@ -291,4 +358,42 @@ mod tests {
 		let out = res.unwrap().1;
 		assert_eq!(sql, format!("{:#}", out))
 	}
+
+	#[test]
+	fn block_decodes_stored_let_layout() {
+		#[derive(Serialize)]
+		struct SetStatement {
+			name: String,
+			what: Value,
+		}
+		#[derive(Serialize)]
+		enum Entry {
+			#[allow(dead_code)]
+			Value(Value),
+			Set(SetStatement),
+		}
+		let old = vec![Entry::Set(SetStatement {
+			name: String::from("test"),
+			what: Value::from(1),
+		})];
+		let bytes = crate::sql::serde::serialize(&old).unwrap();
+		let out: Block = crate::sql::serde::deserialize(&bytes).unwrap();
+		assert_eq!(block("{ LET $test = 1; }").unwrap().1, out);
+	}
+
+	#[test]
+	fn block_stores_destructuring_let() {
+		let (_, out) = block("{ LET { a, b } = { a: 1, b: 2 }; RETURN $a; }").unwrap();
+		let bytes = crate::sql::serde::serialize(&out).unwrap();
+		let res: Block = crate::sql::serde::deserialize(&bytes).unwrap();
+		assert_eq!(out, res);
+	}
+
+	#[test]
+	fn block_stores_select_distinct() {
+		let (_, out) = block("{ LET $x = 1; SELECT DISTINCT name FROM test; }").unwrap();
+		let bytes = crate::sql::serde::serialize(&out).unwrap();
+		let res: Block = crate::sql::serde::deserialize(&bytes).unwrap();
+		assert_eq!(out, res);
+	}
 }
@@ -17,10 +17,13 @@ use nom::multi::separated_list1;
 use serde::{Deserialize, Serialize};
 use std::fmt::{self, Display, Formatter};
 
+/// A single `field op expr` assignment, such as `name = 'Tobie'` or `age += 1`
+pub type Assignment = (Idiom, Operator, Value);
+
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash)]
 pub enum Data {
 	EmptyExpression,
-	SetExpression(Vec<(Idiom, Operator, Value)>),
+	SetExpression(Vec<Assignment>),
 	UnsetExpression(Vec<Idiom>),
 	PatchExpression(Value),
 	MergeExpression(Value),
@@ -28,7 +31,7 @@ pub enum Data {
 	ContentExpression(Value),
 	SingleExpression(Value),
 	ValuesExpression(Vec<Vec<(Idiom, Value)>>),
-	UpdateExpression(Vec<(Idiom, Operator, Value)>),
+	UpdateExpression(Vec<Assignment>),
 }
 
 impl Default for Data {
@@ -122,14 +125,7 @@ pub fn data(i: &str) -> IResult<&str, Data> {
 fn set(i: &str) -> IResult<&str, Data> {
 	let (i, _) = tag_no_case("SET")(i)?;
 	let (i, _) = shouldbespace(i)?;
-	let (i, v) = separated_list1(commas, |i| {
-		let (i, l) = idiom(i)?;
-		let (i, _) = mightbespace(i)?;
-		let (i, o) = assigner(i)?;
-		let (i, _) = mightbespace(i)?;
-		let (i, r) = value(i)?;
-		Ok((i, (l, o, r)))
-	})(i)?;
+	let (i, v) = assignments(i)?;
 	Ok((i, Data::SetExpression(v)))
 }
 
@@ -168,6 +164,19 @@ fn content(i: &str) -> IResult<&str, Data> {
 	Ok((i, Data::ContentExpression(v)))
 }
 
+pub fn assignments(i: &str) -> IResult<&str, Vec<Assignment>> {
+	separated_list1(commas, assignment)(i)
+}
+
+fn assignment(i: &str) -> IResult<&str, Assignment> {
+	let (i, l) = idiom(i)?;
+	let (i, _) = mightbespace(i)?;
+	let (i, o) = assigner(i)?;
+	let (i, _) = mightbespace(i)?;
+	let (i, r) = value(i)?;
+	Ok((i, (l, o, r)))
+}
+
 pub fn single(i: &str) -> IResult<&str, Data> {
 	let (i, v) = value(i)?;
 	Ok((i, Data::SingleExpression(v)))
@@ -200,14 +209,7 @@ pub fn values(i: &str) -> IResult<&str, Data> {
 pub fn update(i: &str) -> IResult<&str, Data> {
 	let (i, _) = tag_no_case("ON DUPLICATE KEY UPDATE")(i)?;
 	let (i, _) = shouldbespace(i)?;
-	let (i, v) = separated_list1(commas, |i| {
-		let (i, l) = idiom(i)?;
-		let (i, _) = mightbespace(i)?;
-		let (i, o) = assigner(i)?;
-		let (i, _) = mightbespace(i)?;
-		let (i, r) = value(i)?;
-		Ok((i, (l, o, r)))
-	})(i)?;
+	let (i, v) = assignments(i)?;
 	Ok((i, Data::UpdateExpression(v)))
 }
 
@@ -435,6 +435,46 @@ mod tests {
 		assert_eq!(out.0, Duration::new(0, 500000000));
 	}
 
+	#[test]
+	fn duration_microseconds() {
+		let sql = "10us";
+		let res = duration(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!("10µs", format!("{}", out));
+		assert_eq!(out.0, Duration::new(0, 10000));
+	}
+
+	#[test]
+	fn duration_nanoseconds() {
+		let sql = "100ns";
+		let res = duration(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!("100ns", format!("{}", out));
+		assert_eq!(out.0, Duration::new(0, 100));
+	}
+
+	#[test]
+	fn duration_subsecond_multi() {
+		let sql = "1h30m500ms";
+		let res = duration(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!("1h30m500ms", format!("{}", out));
+		assert_eq!(out.0, Duration::new(5_400, 500000000));
+	}
+
+	#[test]
+	fn duration_subsecond_units() {
+		let sql = "1s2ms3us4ns";
+		let res = duration(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!("1s2ms3µs4ns", format!("{}", out));
+		assert_eq!(out.0, Duration::new(1, 2003004));
+	}
+
 	#[test]
 	fn duration_overflow() {
 		let sql = "10000000000000000d";
@@ -1,6 +1,7 @@
 use crate::sql::common::val_u8;
 use nom::character::is_digit;
 use std::borrow::Cow;
+use std::cell::{Cell, RefCell};
 
 const SINGLE: char = '\'';
 
@@ -14,6 +15,67 @@ const DOUBLE_ESC: &str = r#"\""#;
 const BACKTICK: char = '`';
 const BACKTICK_ESC: &str = r#"\`"#;
 
+/// How identifiers are quoted when serializing SurrealQL
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Quoting {
+	/// Quote every identifier, even when this is not necessary
+	Always,
+	/// Quote only the identifiers which would otherwise not parse
+	#[default]
+	Minimal,
+	/// Never quote identifiers, and fail on any which would need quoting
+	Never,
+}
+
+/// Options which control how SurrealQL is serialized
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct SerializeOptions {
+	/// How identifiers are quoted
+	pub quoting: Quoting,
+}
+
+impl SerializeOptions {
+	/// Set how identifiers are quoted
+	pub fn with_quoting(mut self, quoting: Quoting) -> Self {
+		self.quoting = quoting;
+		self
+	}
+}
+
+thread_local! {
+	/// The identifier quoting policy in effect on this thread.
+	static QUOTING: Cell<Quoting> = Cell::new(Quoting::Minimal);
+	/// The first identifier which needed quoting when quoting was disabled.
+	static UNQUOTED: RefCell<Option<String>> = RefCell::new(None);
+}
+
+/// Restores the previous quoting state when dropped, even if formatting panics
+struct Restore(Quoting, Option<String>);
+
+impl Drop for Restore {
+	fn drop(&mut self) {
+		QUOTING.with(|v| v.set(self.0));
+		UNQUOTED.with(|v| *v.borrow_mut() = self.1.take());
+	}
+}
+
+/// Serializes a value with the specified identifier quoting policy. When
+/// quoting is disabled, the first identifier which needed quoting is returned
+/// as an error.
+pub(crate) fn serialize_with<T: std::fmt::Display>(
+	val: &T,
+	quoting: Quoting,
+) -> Result<String, String> {
+	let _restore =
+		Restore(QUOTING.with(|v| v.replace(quoting)), UNQUOTED.with(|v| v.borrow_mut().take()));
+	let out = val.to_string();
+	match UNQUOTED.with(|v| v.borrow_mut().take()) {
+		Some(ident) => Err(ident),
+		None => Ok(out),
+	}
+}
+
 /// Quotes a string with single or double quotes:
 /// - cat -> 'cat'
 /// - cat's -> "cat's"
@@ -66,9 +128,22 @@ pub fn escape_rid(s: &str) -> Cow<'_, str> {
 }
 
 #[inline]
-/// Escapes an ident if necessary
+/// Escapes an ident according to the current quoting policy
 pub fn escape_ident(s: &str) -> Cow<'_, str> {
-	escape_numeric(s, BACKTICK, BACKTICK, BACKTICK_ESC)
+	match QUOTING.with(Cell::get) {
+		Quoting::Minimal => escape_numeric(s, BACKTICK, BACKTICK, BACKTICK_ESC),
+		Quoting::Always => {
+			Cow::Owned(format!("{BACKTICK}{}{BACKTICK}", s.replace(BACKTICK, BACKTICK_ESC)))
+		}
+		Quoting::Never => {
+			if let Cow::Owned(_) = escape_numeric(s, BACKTICK, BACKTICK, BACKTICK_ESC) {
+				UNQUOTED.with(|v| {
+					v.borrow_mut().get_or_insert_with(|| s.to_owned());
+				});
+			}
+			Cow::Borrowed(s)
+		}
+	}
 }
 
 #[inline]
@@ -107,3 +182,53 @@ pub fn escape_numeric<'a>(s: &'a str, l: char, r: char, e: &str) -> Cow<'a, str>
 		_ => Cow::Borrowed(s),
 	}
 }
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+	use crate::sql::parse;
+
+	#[test]
+	fn serialize_quoting_minimal() {
+		let sql = parse("SELECT test AS `some thing`, other FROM person").unwrap();
+		let out = serialize_with(&sql, Quoting::Minimal).unwrap();
+		assert_eq!(out, "SELECT test AS `some thing`, other FROM person;");
+	}
+
+	#[test]
+	fn serialize_quoting_always() {
+		let sql = parse("SELECT test AS `some thing`, other FROM person").unwrap();
+		let out = serialize_with(&sql, Quoting::Always).unwrap();
+		assert_eq!(out, "SELECT `test` AS `some thing`, `other` FROM `person`;");
+		assert_eq!(parse(&out).unwrap(), sql);
+	}
+
+	#[test]
+	fn serialize_quoting_never() {
+		let sql = parse("SELECT test, other FROM person").unwrap();
+		let out = serialize_with(&sql, Quoting::Never).unwrap();
+		assert_eq!(out, "SELECT test, other FROM person;");
+		let sql = parse("SELECT test AS `some thing`, other FROM `1234`").unwrap();
+		let out = serialize_with(&sql, Quoting::Never);
+		assert_eq!(out, Err("some thing".to_owned()));
+		// The policy is restored after serializing
+		assert_eq!(sql.to_string(), "SELECT test AS `some thing`, other FROM `1234`;");
+	}
+
+	#[test]
+	fn serialize_quoting_restored_after_panic() {
+		struct Failing;
+		impl std::fmt::Display for Failing {
+			fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+				write!(f, "{}", escape_ident("some thing"))?;
+				panic!("formatting failed")
+			}
+		}
+		let res = std::panic::catch_unwind(|| serialize_with(&Failing, Quoting::Never));
+		assert!(res.is_err());
+		// The policy is restored, and the failed identifier is discarded
+		assert_eq!(escape_ident("some thing"), "`some thing`");
+		assert_eq!(UNQUOTED.with(|v| v.borrow().clone()), None);
+	}
+}
@@ -171,13 +171,18 @@ impl Function {
 					// Get the function definition
 					run.get_fc(opt.ns(), opt.db(), s).await?
 				};
+				// Arguments after the last one without a default are optional
+				let max = val.args.len();
+				let min = (0..max).rposition(|i| !matches!(val.defaults.get(i), Some(Some(_))));
+				let min = min.map_or(0, |i| i + 1);
 				// Check the function arguments
-				if x.len() != val.args.len() {
+				if x.len() < min || x.len() > max {
 					return Err(Error::InvalidArguments {
 						name: format!("fn::{}", val.name),
-						message: match val.args.len() {
-							1 => String::from("The function expects 1 argument."),
-							l => format!("The function expects {l} arguments."),
+						message: match (min, max) {
+							(1, 1) => String::from("The function expects 1 argument."),
+							(l, h) if l == h => format!("The function expects {l} arguments."),
+							(l, h) => format!("The function expects {l} to {h} arguments."),
 						},
 					});
 				}
@@ -186,7 +191,15 @@ impl Function {
 				// Duplicate context
 				let mut ctx = Context::new(ctx);
 				// Process the function arguments
-				for (val, (name, kind)) in a.into_iter().zip(val.args) {
+				let mut a = a.into_iter();
+				let mut d = val.defaults.into_iter();
+				for (name, kind) in val.args {
+					let val = match (a.next(), d.next().flatten()) {
+						(Some(v), _) => v,
+						// Omitted arguments fall back to their default value
+						(None, Some(v)) => v.compute(&ctx, opt, txn, doc).await?,
+						(None, None) => Value::None,
+					};
 					ctx.add_value(name.to_raw(), val.coerce_to(&kind)?);
 				}
 				// Run the custom function
@@ -205,6 +218,8 @@ impl Function {
 				{
 					Err(Error::InvalidScript {
 						message: String::from("Embedded functions are not enabled."),
+						stack: None,
+						line: None,
 					})
 				}
 			}
@@ -296,6 +311,7 @@ fn function_array(i: &str) -> IResult<&str, &str> {
 			tag("boolean_not"),
 			tag("boolean_or"),
 			tag("boolean_xor"),
+			tag("chunk"),
 			tag("clump"),
 			tag("combine"),
 			tag("complement"),
@@ -307,9 +323,10 @@ fn function_array(i: &str) -> IResult<&str, &str> {
 			tag("first"),
 			tag("flatten"),
 			tag("group"),
-			tag("insert"),
 		)),
 		alt((
+			tag("insert_at"),
+			tag("insert"),
 			tag("intersect"),
 			tag("join"),
 			tag("last"),
@@ -320,19 +337,25 @@ fn function_array(i: &str) -> IResult<&str, &str> {
 			tag("matches"),
 			tag("max"),
 			tag("min"),
+			tag("move"),
 			tag("pop"),
 			tag("prepend"),
 			tag("push"),
 		)),
 		alt((
+			tag("remove_at"),
 			tag("remove"),
 			tag("reverse"),
+			tag("sample"),
+			tag("skip"),
 			tag("slice"),
 			tag("sort::asc"),
 			tag("sort::desc"),
 			tag("sort"),
+			tag("take"),
 			tag("transpose"),
 			tag("union"),
+			tag("windows"),
 		)),
 	))(i)
 }
@@ -347,6 +370,9 @@ fn function_crypto(i: &str) -> IResult<&str, &str> {
 		preceded(tag("bcrypt::"), alt((tag("compare"), tag("generate")))),
 		preceded(tag("pbkdf2::"), alt((tag("compare"), tag("generate")))),
 		preceded(tag("scrypt::"), alt((tag("compare"), tag("generate")))),
+		preceded(tag("totp::"), alt((tag("generate"), tag("verify")))),
+		tag("decrypt"),
+		tag("encrypt"),
 		tag("md5"),
 		tag("sha1"),
 		tag("sha256"),
@@ -391,6 +417,7 @@ fn function_geo(i: &str) -> IResult<&str, &str> {
 		tag("bearing"),
 		tag("centroid"),
 		tag("distance"),
+		tag("simplify"),
 		preceded(tag("hash::"), alt((tag("decode"), tag("encode")))),
 	))(i)
 }
@@ -507,24 +534,35 @@ fn function_session(i: &str) -> IResult<&str, &str> {
 
 fn function_string(i: &str) -> IResult<&str, &str> {
 	alt((
-		tag("concat"),
-		tag("contains"),
-		tag("endsWith"),
-		tag("join"),
-		tag("len"),
-		tag("lowercase"),
-		tag("repeat"),
-		tag("replace"),
-		tag("reverse"),
-		tag("slice"),
-		tag("slug"),
-		tag("split"),
-		tag("startsWith"),
-		tag("trim"),
-		tag("uppercase"),
-		tag("words"),
-		preceded(tag("distance::"), alt((tag("hamming"), tag("levenshtein")))),
-		preceded(tag("similarity::"), alt((tag("fuzzy"), tag("jaro"), tag("smithwaterman")))),
+		alt((
+			tag("concat"),
+			tag("contains"),
+			tag("count"),
+			tag("endsWith"),
+			tag("join"),
+			tag("len"),
+			tag("lowercase"),
+			tag("matches"),
+			tag("repeat"),
+			tag("replace_regex"),
+			tag("replace"),
+			tag("reverse"),
+		)),
+		alt((
+			tag("slice"),
+			tag("slug"),
+			tag("split"),
+			tag("startsWith"),
+			tag("trim"),
+			tag("uppercase"),
+			tag("words"),
+			preceded(tag("distance::"), alt((tag("hamming"), tag("levenshtein")))),
+			preceded(tag("fuzzy::"), tag("search")),
+			preceded(
+				tag("similarity::"),
+				alt((tag("fuzzy"), tag("jaro_winkler"), tag("jaro"), tag("smithwaterman"))),
+			),
+		)),
 	))(i)
 }
 
@@ -536,6 +574,7 @@ fn function_time(i: &str) -> IResult<&str, &str> {
 		tag("format"),
 		tag("group"),
 		tag("hour"),
+		tag("interval"),
 		tag("minute"),
 		tag("max"),
 		tag("min"),
@@ -548,9 +587,11 @@ fn function_time(i: &str) -> IResult<&str, &str> {
 		tag("unix"),
 		tag("wday"),
 		tag("week"),
-		tag("yday"),
-		tag("year"),
-		preceded(tag("from::"), alt((tag("micros"), tag("millis"), tag("secs"), tag("unix")))),
+		alt((
+			tag("yday"),
+			tag("year"),
+			preceded(tag("from::"), alt((tag("micros"), tag("millis"), tag("secs"), tag("unix")))),
+		)),
 	))(i)
 }
 
@@ -1,9 +1,11 @@
+use crate::err::Error;
 use crate::sql::comment::mightbespace;
 use crate::sql::common::verbar;
 use crate::sql::common::{closeparentheses, commas, openparentheses};
 use crate::sql::error::IResult;
 use crate::sql::fmt::Fmt;
 use crate::sql::table::{table, Table};
+use crate::sql::value::Value;
 use nom::branch::alt;
 use nom::bytes::complete::tag;
 use nom::character::complete::char;
@@ -47,6 +49,10 @@ impl Kind {
 	fn is_any(&self) -> bool {
 		matches!(self, Kind::Any)
 	}
+	/// Check that a value matches this type, as enforced by `DEFINE FIELD ... TYPE`
+	pub fn check(&self, value: &Value) -> Result<(), Error> {
+		value.clone().coerce_to(self).map(|_| ())
+	}
 }
 
 impl From<&Kind> for Box<Kind> {
@@ -523,4 +529,17 @@ mod tests {
 		assert_eq!("set<float, 10>", format!("{}", out));
 		assert_eq!(out, Kind::Set(Box::new(Kind::Float), Some(10)));
 	}
+
+	#[test]
+	fn kind_check_array_of_records() {
+		let kind = crate::sql::kind("array<record<user>>").unwrap();
+		assert_eq!(kind, Kind::Array(Box::new(Kind::Record(vec![Table::from("user")])), None));
+		let val = crate::sql::value("[user:one, user:two]").unwrap();
+		assert!(kind.check(&val).is_ok());
+		let val = crate::sql::value("[user:one, post:two]").unwrap();
+		assert!(matches!(kind.check(&val), Err(Error::CoerceTo { .. })));
+		let val = crate::sql::value("user:one").unwrap();
+		assert!(matches!(kind.check(&val), Err(Error::CoerceTo { .. })));
+		assert!(crate::sql::kind("array<record<user>").is_err());
+	}
 }
@@ -52,12 +52,14 @@ pub(crate) mod permission;
 pub(crate) mod query;
 pub(crate) mod range;
 pub(crate) mod regex;
+pub(crate) mod schema;
 pub(crate) mod scoring;
 pub(crate) mod script;
 pub(crate) mod special;
 pub(crate) mod split;
 pub(crate) mod start;
 pub(crate) mod statement;
+pub(crate) mod stats;
 pub(crate) mod strand;
 pub(crate) mod subquery;
 pub(crate) mod table;
@@ -85,12 +87,14 @@ pub use self::block::Block;
 pub use self::bytes::Bytes;
 pub use self::cast::Cast;
 pub use self::cond::Cond;
+pub use self::data::Assignment;
 pub use self::data::Data;
 pub use self::datetime::Datetime;
 pub use self::dir::Dir;
 pub use self::duration::Duration;
 pub use self::edges::Edges;
 pub use self::error::Error;
+pub use self::escape::{Quoting, SerializeOptions};
 pub use self::explain::Explain;
 pub use self::expression::Expression;
 pub use self::fetch::Fetch;
@@ -111,9 +115,11 @@ pub use self::kind::Kind;
 pub use self::limit::Limit;
 pub use self::model::Model;
 pub use self::number::Number;
+pub use self::number::ParseOptions;
 pub use self::object::Object;
 pub use self::operation::Op;
 pub use self::operation::Operation;
+pub use self::operation::Patch;
 pub use self::operator::Operator;
 pub use self::order::Order;
 pub use self::order::Orders;
@@ -125,12 +131,15 @@ pub use self::permission::Permissions;
 pub use self::query::Query;
 pub use self::range::Range;
 pub use self::regex::Regex;
+pub use self::schema::Schema;
 pub use self::script::Script;
 pub use self::split::Split;
 pub use self::split::Splits;
 pub use self::start::Start;
 pub use self::statement::Statement;
+pub use self::statement::StatementKind;
 pub use self::statement::Statements;
+pub use self::stats::QueryStats;
 pub use self::strand::Strand;
 pub use self::subquery::Subquery;
 pub use self::table::Table;
@@ -138,6 +147,7 @@ pub use self::table::Tables;
 pub use self::thing::Thing;
 pub use self::timeout::Timeout;
 pub use self::uuid::Uuid;
+pub use self::value::NumberMode;
 pub use self::value::Value;
 pub use self::value::Values;
 pub use self::version::Version;
@@ -5,12 +5,16 @@ use crate::sql::error::IResult;
 use crate::sql::strand::Strand;
 use nom::branch::alt;
 use nom::bytes::complete::tag;
-use nom::character::complete::i64;
-use nom::combinator::{map, opt};
+use nom::character::complete::{char, digit1, i64, one_of};
+use nom::combinator::{cond, cut, map, opt, recognize};
+use nom::multi::separated_list1;
 use nom::number::complete::recognize_float;
+use nom::sequence::{pair, tuple};
 use nom::Err::Failure;
 use rust_decimal::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::cell::Cell;
 use std::cmp::Ordering;
 use std::fmt::{self, Display, Formatter};
 use std::hash;
@@ -370,6 +374,59 @@ impl Number {
 		}
 	}
 
+	/// Rounds this number up to the specified number of decimal places.
+	/// Negative places round to the left of the decimal point.
+	pub fn ceil_dp(self, places: i64) -> Self {
+		self.to_dp(places, RoundingStrategy::ToPositiveInfinity, f64::ceil)
+	}
+
+	/// Rounds this number down to the specified number of decimal places.
+	/// Negative places round to the left of the decimal point.
+	pub fn floor_dp(self, places: i64) -> Self {
+		self.to_dp(places, RoundingStrategy::ToNegativeInfinity, f64::floor)
+	}
+
+	/// Rounds this number to the specified number of decimal places, with
+	/// midpoints rounded away from zero, so that `1.345` becomes `1.35` and
+	/// `-1.345` becomes `-1.35`. Negative places round to the left of the
+	/// decimal point, so that `-2` rounds to the nearest hundred.
+	pub fn round_dp(self, places: i64) -> Self {
+		self.to_dp(places, RoundingStrategy::MidpointAwayFromZero, f64::round)
+	}
+
+	fn to_dp(self, places: i64, strategy: RoundingStrategy, round: fn(f64) -> f64) -> Self {
+		// A decimal can not hold more than 28 decimal places
+		let places = places.clamp(-28, 28);
+		// Rounds a decimal, scaling it when rounding to the left of the decimal point
+		let dp = |v: Decimal| -> Option<Decimal> {
+			match places {
+				p if p >= 0 => Some(v.round_dp_with_strategy(p as u32, strategy)),
+				p => {
+					let scale =
+						Decimal::from_i128_with_scale(10i128.pow(p.unsigned_abs() as u32), 0);
+					v.checked_div(scale)?.round_dp_with_strategy(0, strategy).checked_mul(scale)
+				}
+			}
+		};
+		match self {
+			Number::Int(v) if places >= 0 => Number::Int(v),
+			Number::Int(v) => match dp(Decimal::from(v)) {
+				Some(d) => d.to_i64().map(Number::Int).unwrap_or(Number::Decimal(d)),
+				None => Number::Int(v),
+			},
+			// Floats are rounded using their shortest decimal representation, so
+			// that a value such as `1.345` is not rounded as `1.34499999999999997`
+			Number::Float(v) => match Decimal::from_str(&v.to_string()).ok().and_then(dp) {
+				Some(d) => d.to_f64().unwrap_or(v).into(),
+				None => {
+					let scale = 10f64.powi(places as i32);
+					(round(v * scale) / scale).into()
+				}
+			},
+			Number::Decimal(v) => dp(v).unwrap_or(v).into(),
+		}
+	}
+
 	pub fn fixed(self, precision: usize) -> Number {
 		match self {
 			Number::Int(v) => format!("{v:.precision$}").try_into().unwrap_or_default(),
@@ -649,14 +706,98 @@ impl Sort for Vec<Number> {
 	}
 }
 
+/// Options which control how numbers are parsed
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct ParseOptions {
+	/// Whether underscores are accepted as digit separators, such as in `1_000_000`
+	pub digit_separators: bool,
+	/// Whether numbers in exponent notation, such as `1e6`, are accepted
+	pub exponents: bool,
+}
+
+impl Default for ParseOptions {
+	fn default() -> Self {
+		Self {
+			digit_separators: false,
+			exponents: true,
+		}
+	}
+}
+
+impl ParseOptions {
+	/// Set whether underscores are accepted as digit separators
+	pub fn with_digit_separators(mut self, enabled: bool) -> Self {
+		self.digit_separators = enabled;
+		self
+	}
+	/// Set whether numbers in exponent notation are accepted
+	pub fn with_exponents(mut self, enabled: bool) -> Self {
+		self.exponents = enabled;
+		self
+	}
+}
+
+thread_local! {
+	/// The number parsing options in effect on this thread, if they differ from the defaults.
+	static OPTIONS: Cell<Option<ParseOptions>> = Cell::new(None);
+}
+
+/// Restores the previous number parsing options when dropped, even if the parser panics
+struct Restore(Option<ParseOptions>);
+
+impl Drop for Restore {
+	fn drop(&mut self) {
+		OPTIONS.with(|v| v.set(self.0));
+	}
+}
+
+/// Runs a parser with the specified number parsing options in effect
+pub(crate) fn parse_with<T>(opts: ParseOptions, f: impl FnOnce() -> T) -> T {
+	let opts = (opts != ParseOptions::default()).then_some(opts);
+	let _restore = Restore(OPTIONS.with(|v| v.replace(opts)));
+	f()
+}
+
+fn digits(opts: ParseOptions, i: &str) -> IResult<&str, &str> {
+	match opts.digit_separators {
+		true => recognize(separated_list1(char('_'), digit1))(i),
+		false => digit1(i),
+	}
+}
+
+fn exponent(opts: ParseOptions, i: &str) -> IResult<&str, &str> {
+	recognize(tuple((one_of("eE"), opt(one_of("+-")), cut(|i| digits(opts, i)))))(i)
+}
+
+fn recognize_number(opts: ParseOptions, i: &str) -> IResult<&str, Cow<str>> {
+	let (i, v) = recognize(tuple((
+		opt(one_of("+-")),
+		alt((
+			recognize(pair(|i| digits(opts, i), opt(pair(char('.'), opt(|i| digits(opts, i)))))),
+			recognize(pair(char('.'), |i| digits(opts, i))),
+		)),
+		cond(opts.exponents, opt(|i| exponent(opts, i))),
+	)))(i)?;
+	// Remove any digit separators
+	match opts.digit_separators && v.contains('_') {
+		true => Ok((i, Cow::Owned(v.replace('_', "")))),
+		false => Ok((i, Cow::Borrowed(v))),
+	}
+}
+
 fn not_nan(i: &str) -> IResult<&str, Number> {
-	let (i, v) = recognize_float(i)?;
+	let (i, v) = match OPTIONS.with(Cell::get) {
+		// Parse with the default options, unless others are in effect
+		None => map(recognize_float, Cow::Borrowed)(i)?,
+		Some(opts) => recognize_number(opts, i)?,
+	};
 	let (i, suffix) = suffix(i)?;
 	let (i, _) = ending(i)?;
 	let number = match suffix {
-		Suffix::None => Number::try_from(v).map_err(|_| Failure(Parser(i)))?,
-		Suffix::Float => Number::from(f64::from_str(v).map_err(|_| Failure(Parser(i)))?),
-		Suffix::Decimal => Number::from(Decimal::from_str(v).map_err(|_| Failure(Parser(i)))?),
+		Suffix::None => Number::try_from(&*v).map_err(|_| Failure(Parser(i)))?,
+		Suffix::Float => Number::from(f64::from_str(&v).map_err(|_| Failure(Parser(i)))?),
+		Suffix::Decimal => Number::from(Decimal::from_str(&v).map_err(|_| Failure(Parser(i)))?),
 	};
 	Ok((i, number))
 }
@@ -866,6 +1007,30 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn number_round_dp() {
+		assert_eq!(Number::Float(1.345).round_dp(2), Number::Float(1.35));
+		assert_eq!(Number::Float(-1.345).round_dp(2), Number::Float(-1.35));
+		assert_eq!(Number::Float(1234.5).round_dp(-2), Number::Float(1200.0));
+		assert_eq!(Number::Int(1250).round_dp(-2), Number::Int(1300));
+		assert_eq!(Number::Int(-1250).round_dp(-2), Number::Int(-1300));
+		assert_eq!(Number::Int(1234).round_dp(2), Number::Int(1234));
+		assert_eq!(
+			Number::Decimal(Decimal::from_str("1.345").unwrap()).round_dp(2),
+			Number::Decimal(Decimal::from_str("1.35").unwrap())
+		);
+	}
+
+	#[test]
+	fn number_floor_and_ceil_dp() {
+		assert_eq!(Number::Float(1.345).floor_dp(2), Number::Float(1.34));
+		assert_eq!(Number::Float(1.345).ceil_dp(2), Number::Float(1.35));
+		assert_eq!(Number::Float(-1.345).floor_dp(2), Number::Float(-1.35));
+		assert_eq!(Number::Float(-1.345).ceil_dp(2), Number::Float(-1.34));
+		assert_eq!(Number::Int(1234).floor_dp(-1), Number::Int(1230));
+		assert_eq!(Number::Int(1234).ceil_dp(-2), Number::Int(1300));
+	}
+
 	#[test]
 	fn ord() {
 		fn assert_cmp(a: &Number, b: &Number, ord: Ordering) {
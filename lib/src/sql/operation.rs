@@ -1,6 +1,7 @@
 use crate::sql::idiom::Idiom;
 use crate::sql::value::Value;
 use serde::{Deserialize, Serialize};
+use std::ops::Deref;
 
 #[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
 pub struct Operation {
@@ -9,6 +10,31 @@ pub struct Operation {
 	pub value: Value,
 }
 
+/// A list of JSON Patch operations, which transforms one value into another
+#[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
+pub struct Patch(pub Vec<Operation>);
+
+impl From<Vec<Operation>> for Patch {
+	fn from(v: Vec<Operation>) -> Self {
+		Self(v)
+	}
+}
+
+impl Deref for Patch {
+	type Target = Vec<Operation>;
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+impl IntoIterator for Patch {
+	type Item = Operation;
+	type IntoIter = std::vec::IntoIter<Self::Item>;
+	fn into_iter(self) -> Self::IntoIter {
+		self.0.into_iter()
+	}
+}
+
 #[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
 pub enum Op {
 	None,
@@ -1,10 +1,17 @@
 use crate::err::Error;
+use crate::sql::data::Assignment;
 use crate::sql::error::Error::{Field, Group, Order, Parser, Split};
 use crate::sql::error::IResult;
+use crate::sql::idiom::Idiom;
+use crate::sql::kind::Kind;
+use crate::sql::number::{parse_with, ParseOptions};
 use crate::sql::query::{query, Query};
+use crate::sql::range::Range;
+use crate::sql::statement::{located, StatementKind, Statements};
 use crate::sql::subquery::{subquery, Subquery};
 use crate::sql::thing::Thing;
 use crate::sql::value::Value;
+use nom::combinator::all_consuming;
 use nom::Err;
 use std::str;
 use tracing::instrument;
@@ -15,6 +22,42 @@ pub fn parse(input: &str) -> Result<Query, Error> {
 	parse_impl(input, query)
 }
 
+/// Parses a SurrealQL [`Query`], checking that each statement is of an allowed kind
+///
+/// An error is returned for the first statement which is not allowed, along with
+/// its position in the input. When none of the allowed kinds write data, an allowed
+/// statement which writes data through a subquery, function, or script is rejected too.
+#[instrument(name = "parser", skip_all, fields(length = input.len()))]
+pub fn parse_allowed(input: &str, allowed: &[StatementKind]) -> Result<Query, Error> {
+	// Parse the statements, along with their positions
+	let parsed = parse_impl(input, |i| all_consuming(located)(i))?;
+	// Check whether any of the allowed kinds write data
+	let writeable = allowed.iter().any(StatementKind::writeable);
+	// Check that each statement is allowed
+	let mut statements = Vec::with_capacity(parsed.len());
+	for (rest, stm) in parsed {
+		let statement = stm.kind();
+		if !allowed.contains(&statement) {
+			let (_, line, char) = locate(input, &input[input.len() - rest..]);
+			return Err(Error::StatementNotAllowed {
+				statement,
+				line,
+				char,
+			});
+		}
+		if !writeable && !statement.transaction() && stm.writeable() {
+			let (_, line, char) = locate(input, &input[input.len() - rest..]);
+			return Err(Error::WriteNotAllowed {
+				statement,
+				line,
+				char,
+			});
+		}
+		statements.push(stm);
+	}
+	Ok(Query(Statements(statements)))
+}
+
 /// Parses a SurrealQL Subquery [`Subquery`]
 #[instrument(name = "parser", skip_all, fields(length = input.len()))]
 pub fn sub_query(input: &str) -> Result<Subquery, Error> {
@@ -27,18 +70,59 @@ pub fn thing(input: &str) -> Result<Thing, Error> {
 	parse_impl(input, super::thing::thing)
 }
 
+/// Parses a SurrealQL record id [`Range`], such as `user:1..10`, `user:1..=10`, or `user:..10`
+#[instrument(name = "parser", skip_all, fields(length = input.len()))]
+pub fn range(input: &str) -> Result<Range, Error> {
+	parse_impl(input, super::range::range)
+}
+
 /// Parses a SurrealQL [`Value`].
 #[instrument(name = "parser", skip_all, fields(length = input.len()))]
 pub fn value(input: &str) -> Result<Value, Error> {
 	parse_impl(input, super::value::value)
 }
 
+/// Parses a SurrealQL [`Value`], using the specified options for parsing numbers
+#[instrument(name = "parser", skip_all, fields(length = input.len()))]
+pub fn value_with_options(input: &str, opts: ParseOptions) -> Result<Value, Error> {
+	parse_with(opts, || parse_impl(input, super::value::value))
+}
+
+/// Parses a SurrealQL [`Idiom`] made up of fields and array indexes
+#[instrument(name = "parser", skip_all, fields(length = input.len()))]
+pub fn idiom(input: &str) -> Result<Idiom, Error> {
+	parse_impl(input, super::idiom::basic)
+}
+
+/// Parses a comma-separated list of SurrealQL [`Assignment`]s, such as `name = 'Tobie', age += 1`
+#[instrument(name = "parser", skip_all, fields(length = input.len()))]
+pub fn assignments(input: &str) -> Result<Vec<Assignment>, Error> {
+	parse_impl(input.trim(), super::data::assignments)
+}
+
+/// Parses a SurrealQL [`Kind`], such as `int`, `option<string>`, or `array<record<user>>`
+#[instrument(name = "parser", skip_all, fields(length = input.len()))]
+pub fn kind(input: &str) -> Result<Kind, Error> {
+	parse_impl(input, super::kind::kind)
+}
+
 /// Parses JSON into an inert SurrealQL [`Value`]
 #[instrument(name = "parser", skip_all, fields(length = input.len()))]
 pub fn json(input: &str) -> Result<Value, Error> {
 	parse_impl(input.trim(), super::value::json)
 }
 
+/// Parses YAML into an inert SurrealQL [`Value`]
+#[instrument(name = "parser", skip_all, fields(length = input.len()))]
+pub fn yaml(input: &str) -> Result<Value, Error> {
+	match input.trim().len() {
+		// The input document was empty
+		0 => Err(Error::QueryEmpty),
+		// Continue parsing the document
+		_ => super::value::yaml(input),
+	}
+}
+
 fn parse_impl<O>(input: &str, parser: impl Fn(&str) -> IResult<&str, O>) -> Result<O, Error> {
 	// Check the length of the input
 	match input.trim().len() {
@@ -118,7 +202,12 @@ fn locate<'a>(input: &str, tried: &'a str) -> (&'a str, usize, usize) {
 mod tests {
 
 	use super::*;
+	use crate::sql::id::Id;
+	use crate::sql::number::Number;
+	use crate::sql::operator::Operator;
+	use crate::sql::statement::Statement;
 	use serde::Serialize;
+	use std::ops::Bound;
 	use std::{collections::HashMap, time::Instant};
 
 	#[test]
@@ -149,6 +238,156 @@ mod tests {
 		assert!(res.is_ok());
 	}
 
+	#[test]
+	fn parse_range_exclusive() {
+		let res = range("user:1..10").unwrap();
+		assert_eq!(res.tb, "user");
+		assert_eq!(res.beg, Bound::Included(Id::from(1)));
+		assert_eq!(res.end, Bound::Excluded(Id::from(10)));
+	}
+
+	#[test]
+	fn parse_range_inclusive() {
+		let res = range("user:1>..=10").unwrap();
+		assert_eq!(res.tb, "user");
+		assert_eq!(res.beg, Bound::Excluded(Id::from(1)));
+		assert_eq!(res.end, Bound::Included(Id::from(10)));
+		let res = range("user:1..=10").unwrap();
+		assert_eq!(res.beg, Bound::Included(Id::from(1)));
+		assert_eq!(res.end, Bound::Included(Id::from(10)));
+	}
+
+	#[test]
+	fn parse_range_unbounded() {
+		let res = range("user:..10").unwrap();
+		assert_eq!(res.beg, Bound::Unbounded);
+		assert_eq!(res.end, Bound::Excluded(Id::from(10)));
+		let res = range("user:..").unwrap();
+		assert_eq!(res.beg, Bound::Unbounded);
+		assert_eq!(res.end, Bound::Unbounded);
+	}
+
+	#[test]
+	fn parse_range_failure() {
+		assert!(range("user:1").is_err());
+		assert!(range("user:1..10 AND").is_err());
+	}
+
+	#[test]
+	fn parse_allowed_statements() {
+		let sql = "
+			SELECT * FROM person;
+			SELECT * FROM person WHERE age > 18;
+			CREATE person SET name = 'Tobie';
+		";
+		let res = parse_allowed(sql, &[StatementKind::Select]);
+		assert!(
+			matches!(
+				res,
+				Err(Error::StatementNotAllowed {
+					statement: StatementKind::Create,
+					line: 4,
+					char: 3,
+				})
+			),
+			"{res:?}"
+		);
+		assert_eq!(
+			res.unwrap_err().to_string(),
+			"The CREATE statement on line 4 at character 3 is not allowed"
+		);
+		let res = parse_allowed(sql, &[StatementKind::Select, StatementKind::Create]).unwrap();
+		assert_eq!(res, parse(sql).unwrap());
+		assert!(matches!(
+			parse_allowed("SELECT * FROM;", &[StatementKind::Select]),
+			Err(Error::InvalidQuery { .. })
+		));
+	}
+
+	#[test]
+	fn parse_allowed_subquery_writes() {
+		let sql = "
+			SELECT * FROM person;
+			SELECT * FROM (CREATE person SET name = 'Tobie');
+		";
+		let res = parse_allowed(sql, &[StatementKind::Select]);
+		assert!(
+			matches!(
+				res,
+				Err(Error::WriteNotAllowed {
+					statement: StatementKind::Select,
+					line: 3,
+					char: 3,
+				})
+			),
+			"{res:?}"
+		);
+		let sql = "LET $id = (DELETE person:tobie);";
+		let res = parse_allowed(sql, &[StatementKind::Select, StatementKind::Set]);
+		assert!(matches!(res, Err(Error::WriteNotAllowed { .. })), "{res:?}");
+		let res = parse_allowed(sql, &[StatementKind::Set, StatementKind::Delete]).unwrap();
+		assert_eq!(res, parse(sql).unwrap());
+	}
+
+	#[test]
+	fn parse_assignments() {
+		let res =
+			assignments("name = 'Tobie', age += 18, tags -= 'old', address.city = $city").unwrap();
+		assert_eq!(res.len(), 4);
+		assert_eq!(res[0].0, idiom("name").unwrap());
+		assert_eq!(res[0].1, Operator::Equal);
+		assert_eq!(res[0].2, Value::from("Tobie"));
+		assert_eq!(res[1].0, idiom("age").unwrap());
+		assert_eq!(res[1].1, Operator::Inc);
+		assert_eq!(res[1].2, Value::from(18));
+		assert_eq!(res[2].0, idiom("tags").unwrap());
+		assert_eq!(res[2].1, Operator::Dec);
+		assert_eq!(res[3].0.to_string(), "address.city");
+		assert_eq!(res[3].1, Operator::Equal);
+		assert_eq!(res[3].2.to_string(), "$city");
+	}
+
+	#[test]
+	fn parse_assignments_failure() {
+		assert!(matches!(assignments(""), Err(Error::QueryEmpty)));
+		assert!(assignments("name").is_err());
+		assert!(assignments("name == 'Tobie'").is_err());
+		assert!(assignments("name = 'Tobie'; DELETE person").is_err());
+	}
+
+	#[test]
+	fn parse_value_digit_separators() {
+		let opts = ParseOptions::default().with_digit_separators(true);
+		let res = value_with_options("1_000", opts);
+		assert!(matches!(res, Ok(Value::Number(Number::Int(1000)))), "{res:?}");
+		let res = value_with_options("-1_000_000.000_5", opts);
+		assert!(matches!(res, Ok(Value::Number(Number::Float(v))) if v == -1000000.0005));
+		assert!(value_with_options("1__000", opts).map_or(true, |v| !v.is_number()));
+		// Digit separators are not accepted by default
+		let opts = ParseOptions::default();
+		assert!(value_with_options("1_000", opts).map_or(true, |v| !v.is_number()));
+		assert!(value("1_000").map_or(true, |v| !v.is_number()));
+	}
+
+	#[test]
+	fn parse_value_exponents() {
+		let res = value("1e6");
+		assert!(matches!(res, Ok(Value::Number(Number::Float(v))) if v == 1e6), "{res:?}");
+		let opts = ParseOptions::default().with_exponents(false);
+		assert!(value_with_options("1e6", opts).map_or(true, |v| !v.is_number()));
+		let res = value_with_options("1.5", opts);
+		assert!(matches!(res, Ok(Value::Number(Number::Float(v))) if v == 1.5), "{res:?}");
+	}
+
+	#[test]
+	fn parse_value_options_restored_after_panic() {
+		let opts = ParseOptions::default().with_digit_separators(true);
+		let res = std::panic::catch_unwind(|| parse_with(opts, || panic!("parser failed")));
+		assert!(res.is_err());
+		// The default options are in effect again
+		assert!(value("1_000").map_or(true, |v| !v.is_number()));
+	}
+
 	#[test]
 	fn parse_complex_failure() {
 		let sql = "    SELECT    *    FROM    { }} ";
@@ -156,40 +395,65 @@ mod tests {
 		assert!(res.is_err());
 	}
 
+	const BIG_QUERY: &str = "
+		SELECT
+			*,
+			tags[$].value,
+			3s as duration,
+			1.345 AS number,
+			test AS `some thing`,
+			'2012-04-23T18:25:43.511Z' AS utctime,
+			'2012-04-23T18:25:43.511-08:00' AS pacifictime,
+			{ key: (3 + 1 + 2), other: 9 * 7, 'some thing': { otherkey: 'text', } } AS object
+		FROM $param, test, temp, test:thingy, |test:10|, |test:1..10|
+		WHERE IF true THEN 'YAY' ELSE 'OOPS' END
+			AND (0.1341, 0.5719) INSIDE { type: 'Polygon', coordinates: [[[0.1341, 0.5719], [0.1341, 0.5719]]] }
+			AND (3 + 3 * 4)=6
+			AND 3 + 3 * 4 = 6
+			AND ages CONTAINS 18
+			AND if IS true
+		SPLIT test.things
+		VERSION '2019-01-01T08:00:00Z'
+		TIMEOUT 2w;
+
+		CREATE person SET name = 'Tobie', age += 18;
+	";
+
 	#[test]
 	fn parser_try() {
-		let sql = "
-			SELECT
-				*,
-				tags[$].value,
-				3s as duration,
-				1.345 AS number,
-				test AS `some thing`,
-				'2012-04-23T18:25:43.511Z' AS utctime,
-				'2012-04-23T18:25:43.511-08:00' AS pacifictime,
-				{ key: (3 + 1 + 2), other: 9 * 7, 'some thing': { otherkey: 'text', } } AS object
-			FROM $param, test, temp, test:thingy, |test:10|, |test:1..10|
-			WHERE IF true THEN 'YAY' ELSE 'OOPS' END
-				AND (0.1341, 0.5719) INSIDE { type: 'Polygon', coordinates: [[[0.1341, 0.5719], [0.1341, 0.5719]]] }
-				AND (3 + 3 * 4)=6
-				AND 3 + 3 * 4 = 6
-				AND ages CONTAINS 18
-				AND if IS true
-			SPLIT test.things
-			VERSION '2019-01-01T08:00:00Z'
-			TIMEOUT 2w;
-
-			CREATE person SET name = 'Tobie', age += 18;
-		";
+		let sql = BIG_QUERY;
 		let res = parse(sql);
 		assert!(res.is_ok());
 		let tmp = res.unwrap();
 
+		assert_eq!(tmp.len(), 2);
+		assert!(!tmp.is_empty());
+		let mut iter = tmp.iter();
+		assert!(matches!(iter.next(), Some(Statement::Select(_))));
+		assert!(matches!(iter.next(), Some(Statement::Create(_))));
+		assert!(iter.next().is_none());
+
+		let stats = tmp.complexity();
+		assert_eq!(stats.statements, 2);
+		assert!((5..=20).contains(&stats.depth), "{stats:?}");
+		assert!((10..=50).contains(&stats.idioms), "{stats:?}");
+		assert_eq!(stats.functions, 0);
+
 		let enc: Vec<u8> = Vec::from(&tmp);
 		let dec: Query = Query::from(enc);
 		assert_eq!(tmp, dec);
 	}
 
+	#[test]
+	fn parser_display_round_trip() {
+		let tmp = parse(BIG_QUERY).unwrap();
+		let sql = tmp.to_string();
+		let dec = parse(&sql).unwrap();
+		assert_eq!(tmp, dec);
+		// Rendering the re-parsed query produces the same text
+		assert_eq!(sql, dec.to_string());
+	}
+
 	#[test]
 	#[cfg_attr(debug_assertions, ignore)]
 	fn json_benchmark() {
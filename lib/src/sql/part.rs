@@ -1,5 +1,5 @@
 use crate::sql::comment::shouldbespace;
-use crate::sql::common::{closebracket, openbracket};
+use crate::sql::common::{closebracket, closeparentheses, commas, openbracket, openparentheses};
 use crate::sql::ending::ident as ending;
 use crate::sql::error::IResult;
 use crate::sql::fmt::Fmt;
@@ -15,6 +15,7 @@ use nom::bytes::complete::tag;
 use nom::bytes::complete::tag_no_case;
 use nom::character::complete::char;
 use nom::combinator::{map, not, peek};
+use nom::multi::separated_list0;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::str;
@@ -139,7 +140,7 @@ impl<'a> Next<'a> for &'a [Part] {
 // ------------------------------
 
 pub fn part(i: &str) -> IResult<&str, Part> {
-	alt((all, last, index, field, value, graph, filter))(i)
+	alt((all, last, index, method, field, value, graph, filter))(i)
 }
 
 pub fn first(i: &str) -> IResult<&str, Part> {
@@ -187,6 +188,16 @@ pub fn field(i: &str) -> IResult<&str, Part> {
 	Ok((i, Part::Field(v)))
 }
 
+pub fn method(i: &str) -> IResult<&str, Part> {
+	let (i, _) = char('.')(i)?;
+	// Only the supported idiom methods are parsed
+	let (i, v) = map(tag("flatten"), String::from)(i)?;
+	let (i, _) = openparentheses(i)?;
+	let (i, a) = separated_list0(commas, value::value)(i)?;
+	let (i, _) = closeparentheses(i)?;
+	Ok((i, Part::Method(v, a)))
+}
+
 pub fn filter(i: &str) -> IResult<&str, Part> {
 	let (i, _) = openbracket(i)?;
 	let (i, _) = alt((tag_no_case("WHERE"), tag("?")))(i)?;
@@ -273,4 +284,31 @@ mod tests {
 		assert_eq!("[WHERE test = true]", format!("{}", out));
 		assert_eq!(out, Part::Where(Value::from(Expression::parse("test = true"))));
 	}
+
+	#[test]
+	fn part_method() {
+		let sql = ".flatten()";
+		let res = part(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!(".flatten()", format!("{}", out));
+		assert_eq!(out, Part::Method(String::from("flatten"), vec![]));
+	}
+
+	#[test]
+	fn part_method_arguments() {
+		let sql = ".flatten( 2 )";
+		let res = part(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!(".flatten(2)", format!("{}", out));
+		assert_eq!(out, Part::Method(String::from("flatten"), vec![Value::from(2)]));
+	}
+
+	#[test]
+	fn part_method_unsupported() {
+		let sql = ".reverse()";
+		let res = method(sql);
+		assert!(res.is_err());
+	}
 }
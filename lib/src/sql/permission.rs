@@ -15,6 +15,13 @@ use std::fmt::Write;
 use std::fmt::{self, Display, Formatter};
 use std::str;
 
+/// The permissions for each type of operation on a table or field.
+///
+/// Each operation is checked independently of the others, using only its
+/// own permission. A `NONE` permission always denies the operation, and a
+/// `FULL` permission always allows it, without evaluating any expression.
+/// A `WHERE` permission allows the operation only when its expression is
+/// truthy for the record. Any operation which is not specified is `FULL`.
 #[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Hash)]
 pub struct Permissions {
 	pub select: Permission,
@@ -137,49 +144,35 @@ fn full(i: &str) -> IResult<&str, Permissions> {
 
 fn specific(i: &str) -> IResult<&str, Permissions> {
 	let (i, perms) = separated_list0(commasorspace, permission)(i)?;
+	let perms: Vec<(char, Permission)> = perms.into_iter().flatten().collect();
 	Ok((
 		i,
 		Permissions {
-			select: perms
-				.iter()
-				.find_map(|x| {
-					x.iter().find_map(|y| match y {
-						('s', ref v) => Some(v.to_owned()),
-						_ => None,
-					})
-				})
-				.unwrap_or_default(),
-			create: perms
-				.iter()
-				.find_map(|x| {
-					x.iter().find_map(|y| match y {
-						('c', ref v) => Some(v.to_owned()),
-						_ => None,
-					})
-				})
-				.unwrap_or_default(),
-			update: perms
-				.iter()
-				.find_map(|x| {
-					x.iter().find_map(|y| match y {
-						('u', ref v) => Some(v.to_owned()),
-						_ => None,
-					})
-				})
-				.unwrap_or_default(),
-			delete: perms
-				.iter()
-				.find_map(|x| {
-					x.iter().find_map(|y| match y {
-						('d', ref v) => Some(v.to_owned()),
-						_ => None,
-					})
-				})
-				.unwrap_or_default(),
+			select: resolve(&perms, 's'),
+			create: resolve(&perms, 'c'),
+			update: resolve(&perms, 'u'),
+			delete: resolve(&perms, 'd'),
 		},
 	))
 }
 
+/// Resolves the permission for a single operation. If the operation is
+/// specified in more than one clause, then a NONE clause takes precedence
+/// over a FULL clause, which takes precedence over any WHERE clause. If
+/// more than one WHERE clause is specified, then the first is used.
+fn resolve(perms: &[(char, Permission)], kind: char) -> Permission {
+	let mut out = None;
+	for (_, perm) in perms.iter().filter(|(k, _)| *k == kind) {
+		match (perm, &out) {
+			(Permission::None, _) => return Permission::None,
+			(Permission::Full, _) => out = Some(Permission::Full),
+			(Permission::Specific(_), None) => out = Some(perm.clone()),
+			(Permission::Specific(_), Some(_)) => (),
+		}
+	}
+	out.unwrap_or_default()
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash)]
 pub enum Permission {
 	None,
@@ -274,4 +267,38 @@ mod tests {
 			}
 		);
 	}
+
+	#[test]
+	fn permissions_unspecified_are_full() {
+		let sql = "PERMISSIONS FOR select WHERE public = true";
+		let res = permissions(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!(
+			out,
+			Permissions {
+				select: Permission::Specific(Value::from(Expression::parse("public = true"))),
+				create: Permission::Full,
+				update: Permission::Full,
+				delete: Permission::Full,
+			}
+		);
+	}
+
+	#[test]
+	fn permissions_repeated_precedence() {
+		let sql = "PERMISSIONS FOR select WHERE public = true, FOR select, update FULL, FOR create WHERE public = true, FOR create WHERE public = false, FOR update, delete NONE, FOR delete FULL";
+		let res = permissions(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!(
+			out,
+			Permissions {
+				select: Permission::Full,
+				create: Permission::Specific(Value::from(Expression::parse("public = true"))),
+				update: Permission::None,
+				delete: Permission::None,
+			}
+		);
+	}
 }
@@ -1,6 +1,10 @@
+use crate::err::Error;
 use crate::sql::error::IResult;
+use crate::sql::escape::{serialize_with, SerializeOptions};
 use crate::sql::fmt::Pretty;
+use crate::sql::schema::Schema;
 use crate::sql::statement::{statements, Statement, Statements};
+use crate::sql::stats::QueryStats;
 use derive::Store;
 use nom::combinator::all_consuming;
 use serde::{Deserialize, Serialize};
@@ -27,6 +31,43 @@ impl IntoIterator for Query {
 	}
 }
 
+impl Query {
+	/// Returns an iterator over the statements in this query
+	pub fn iter(&self) -> impl Iterator<Item = &Statement> {
+		self.0 .0.iter()
+	}
+	/// Returns the number of statements in this query
+	pub fn len(&self) -> usize {
+		self.0 .0.len()
+	}
+	/// Checks whether this query contains no statements
+	pub fn is_empty(&self) -> bool {
+		self.0 .0.is_empty()
+	}
+	/// Returns size and complexity metrics for this query, which can be
+	/// used to reject overly large queries before they are executed
+	pub fn complexity(&self) -> QueryStats {
+		let mut stats = QueryStats {
+			statements: self.len(),
+			..QueryStats::default()
+		};
+		stats.count(&self.0);
+		stats
+	}
+	/// Checks that the query only references the tables and fields which are defined
+	/// in the schema, returning the first undefined reference and the number of the
+	/// statement in which it was found
+	pub fn check_against(&self, schema: &Schema) -> Result<(), Error> {
+		self.iter().enumerate().try_for_each(|(i, stm)| schema.check(stm, i + 1))
+	}
+	/// Serializes the query as SurrealQL, using the specified options
+	pub fn to_sql(&self, opts: &SerializeOptions) -> Result<String, Error> {
+		serialize_with(self, opts.quoting).map_err(|value| Error::UnquotedIdent {
+			value,
+		})
+	}
+}
+
 impl Display for Query {
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
 		write!(Pretty::from(f), "{}", &self.0)
@@ -42,6 +83,7 @@ pub fn query(i: &str) -> IResult<&str, Query> {
 mod tests {
 
 	use super::*;
+	use crate::sql::escape::Quoting;
 
 	#[test]
 	fn single_query() {
@@ -87,4 +129,28 @@ mod tests {
 		let out = res.unwrap().1;
 		assert_eq!("CREATE test;\nCREATE temp;", format!("{}", out))
 	}
+
+	#[test]
+	fn query_complexity() {
+		let sql = "SELECT name, math::max([1, [2, 3]]) FROM person WHERE age > 18; CREATE person;";
+		let out = query(sql).unwrap().1;
+		let stats = out.complexity();
+		assert_eq!(stats.statements, 2);
+		assert_eq!(stats.depth, 4);
+		assert_eq!(stats.idioms, 2);
+		assert_eq!(stats.functions, 1);
+	}
+
+	#[test]
+	fn query_to_sql_quoting() {
+		let sql = "SELECT * FROM `some thing`";
+		let out = query(sql).unwrap().1;
+		let opts = SerializeOptions::default();
+		assert_eq!(out.to_sql(&opts).unwrap(), "SELECT * FROM `some thing`;");
+		let opts = SerializeOptions::default().with_quoting(Quoting::Never);
+		assert!(matches!(
+			out.to_sql(&opts),
+			Err(Error::UnquotedIdent { value }) if value == "some thing"
+		));
+	}
 }
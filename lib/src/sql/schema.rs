@@ -0,0 +1,187 @@
+use crate::err::Error;
+use crate::sql::data::Data;
+use crate::sql::field::Field;
+use crate::sql::idiom::Idiom;
+use crate::sql::part::Part;
+use crate::sql::statement::Statement;
+use crate::sql::value::Value;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// A known set of table and field definitions, which a parsed query can be checked
+/// against before it is executed
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Schema {
+	tables: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl Schema {
+	/// Create a new schema with no tables
+	pub fn new() -> Self {
+		Self::default()
+	}
+	/// Define a table, along with the fields which it contains. Nested fields such as
+	/// `address.city` are checked by their top-level field. A table which is defined
+	/// without any fields is schemaless, and accepts any field.
+	pub fn with_table(mut self, table: &str, fields: &[&str]) -> Self {
+		let fields = fields.iter().map(|&f| f.split(['.', '[']).next().unwrap_or(f).to_owned());
+		self.tables.entry(table.to_owned()).or_default().extend(fields);
+		self
+	}
+	/// Check that a statement only references defined tables and fields
+	pub(crate) fn check(&self, stm: &Statement, statement: usize) -> Result<(), Error> {
+		match stm {
+			Statement::Select(v) => {
+				let tables = self.tables(v.what.iter(), statement)?;
+				for field in v.expr.0.iter() {
+					if let Field::Single {
+						expr: Value::Idiom(i),
+						..
+					} = field
+					{
+						self.fields(&tables, i, statement)?;
+					}
+				}
+				Ok(())
+			}
+			Statement::Create(v) => {
+				let tables = self.tables(v.what.iter(), statement)?;
+				self.data(&tables, v.data.as_ref(), statement)
+			}
+			Statement::Update(v) => {
+				let tables = self.tables(v.what.iter(), statement)?;
+				self.data(&tables, v.data.as_ref(), statement)
+			}
+			Statement::Delete(v) => self.tables(v.what.iter(), statement).map(|_| ()),
+			Statement::Relate(v) => {
+				let tables = self.tables([&v.kind], statement)?;
+				self.data(&tables, v.data.as_ref(), statement)
+			}
+			Statement::Insert(v) => {
+				let tables = self.tables([&v.into], statement)?;
+				self.data(&tables, Some(&v.data), statement)
+			}
+			_ => Ok(()),
+		}
+	}
+	/// Check that the tables which a statement operates on are defined
+	fn tables<'a>(
+		&self,
+		what: impl IntoIterator<Item = &'a Value>,
+		statement: usize,
+	) -> Result<Vec<&'a str>, Error> {
+		let mut tables = Vec::new();
+		for v in what {
+			let name = match v {
+				Value::Table(v) => v.0.as_str(),
+				Value::Thing(v) => v.tb.as_str(),
+				// Params and subqueries can only be checked at execution
+				_ => continue,
+			};
+			if !self.tables.contains_key(name) {
+				return Err(Error::SchemaTable {
+					name: name.to_owned(),
+					statement,
+				});
+			}
+			tables.push(name);
+		}
+		Ok(tables)
+	}
+	/// Check that the fields set by a statement are defined
+	fn data(&self, tables: &[&str], data: Option<&Data>, statement: usize) -> Result<(), Error> {
+		match data {
+			Some(Data::SetExpression(v) | Data::UpdateExpression(v)) => {
+				v.iter().try_for_each(|(i, _, _)| self.fields(tables, i, statement))
+			}
+			Some(Data::UnsetExpression(v)) => {
+				v.iter().try_for_each(|i| self.fields(tables, i, statement))
+			}
+			Some(Data::ValuesExpression(v)) => {
+				v.iter().flatten().try_for_each(|(i, _)| self.fields(tables, i, statement))
+			}
+			_ => Ok(()),
+		}
+	}
+	/// Check that the field at the start of an idiom is defined on each of the tables
+	fn fields(&self, tables: &[&str], idiom: &Idiom, statement: usize) -> Result<(), Error> {
+		let field = match idiom.first() {
+			Some(Part::Field(f)) if !f.is_id() => f.as_str(),
+			_ => return Ok(()),
+		};
+		for table in tables {
+			match self.tables.get(*table) {
+				Some(fields) if !fields.is_empty() && !fields.contains(field) => {
+					return Err(Error::SchemaField {
+						name: field.to_owned(),
+						table: table.to_string(),
+						statement,
+					});
+				}
+				_ => (),
+			}
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+	use crate::sql::parse;
+
+	#[test]
+	fn check_undefined_table() {
+		let schema = Schema::new().with_table("person", &["name"]);
+		let query = parse("SELECT unknown_field FROM undefined_table").unwrap();
+		let res = query.check_against(&schema);
+		assert!(matches!(
+			res,
+			Err(Error::SchemaTable { name, statement: 1 }) if name == "undefined_table"
+		));
+	}
+
+	#[test]
+	fn check_undefined_field() {
+		let schema = Schema::new().with_table("person", &["name", "address.city"]);
+		let query =
+			parse("SELECT name, address.city FROM person; SELECT age FROM person:tobie").unwrap();
+		let res = query.check_against(&schema);
+		assert!(matches!(
+			res,
+			Err(Error::SchemaField { name, table, statement: 2 }) if name == "age" && table == "person"
+		));
+	}
+
+	#[test]
+	fn check_defined_references() {
+		let schema = Schema::new().with_table("person", &["name", "age"]).with_table("log", &[]);
+		let query = parse(
+			"
+			SELECT id, name, age FROM person WHERE age > 18;
+			CREATE person SET name = 'Tobie', age = 30;
+			UPDATE person:tobie UNSET age;
+			INSERT INTO person (name, age) VALUES ('Jaime', 25);
+			CREATE log SET anything = true;
+			SELECT * FROM $table;
+			",
+		)
+		.unwrap();
+		assert!(query.check_against(&schema).is_ok());
+	}
+
+	#[test]
+	fn check_undefined_set_field() {
+		let schema = Schema::new().with_table("person", &["name"]);
+		let query = parse("CREATE person SET name = 'Tobie', age = 30").unwrap();
+		let res = query.check_against(&schema);
+		assert!(matches!(
+			&res,
+			Err(Error::SchemaField { name, table, statement: 1 }) if name == "age" && table == "person"
+		));
+		assert_eq!(
+			res.unwrap_err().to_string(),
+			"The field 'age' on table 'person' in statement 1 is not defined"
+		);
+	}
+}
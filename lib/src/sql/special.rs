@@ -36,6 +36,11 @@ fn contains_idiom(fields: &Fields, idiom: &Idiom) -> bool {
 	})
 }
 
+/// Check to see whether the expression, or one of its parent paths, is in the SELECT clause
+fn contains_idiom_path(fields: &Fields, idiom: &Idiom) -> bool {
+	(1..=idiom.len()).any(|n| contains_idiom(fields, &Idiom::from(&idiom[..n])))
+}
+
 pub fn check_split_on_fields<'a>(
 	i: &'a str,
 	fields: &Fields,
@@ -45,7 +50,7 @@ pub fn check_split_on_fields<'a>(
 	if let Some(splits) = splits {
 		// Loop over each of the expressions in the SPLIT ON clause
 		for split in splits.iter() {
-			if !contains_idiom(fields, &split.0) {
+			if !contains_idiom_path(fields, &split.0) {
 				// If the expression isn't specified in the SELECT clause, then error
 				return Err(Failure(Error::Split(i, split.to_string())));
 			}
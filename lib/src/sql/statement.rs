@@ -21,6 +21,7 @@ use crate::sql::statements::kill::{kill, KillStatement};
 use crate::sql::statements::live::{live, LiveStatement};
 use crate::sql::statements::option::{option, OptionStatement};
 use crate::sql::statements::output::{output, OutputStatement};
+use crate::sql::statements::rebuild::{rebuild, RebuildStatement};
 use crate::sql::statements::relate::{relate, RelateStatement};
 use crate::sql::statements::remove::{remove, RemoveStatement};
 use crate::sql::statements::select::{select, SelectStatement};
@@ -69,9 +70,20 @@ impl Display for Statements {
 }
 
 pub fn statements(i: &str) -> IResult<&str, Statements> {
-	let (i, v) = separated_list1(colons, statement)(i)?;
+	let (i, v) = located(i)?;
+	Ok((i, Statements(v.into_iter().map(|(_, v)| v).collect())))
+}
+
+/// Parses a set of statements, along with the length of the
+/// input which remained at the start of each statement
+pub(crate) fn located(i: &str) -> IResult<&str, Vec<(usize, Statement)>> {
+	let (i, v) = separated_list1(colons, |i| {
+		let (i, _) = mightbespace(i)?;
+		let (rest, v) = statement(i)?;
+		Ok((rest, (i.len(), v)))
+	})(i)?;
 	let (i, _) = many0(alt((colons, comment)))(i)?;
-	Ok((i, Statements(v)))
+	Ok((i, v))
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Store, Hash)]
@@ -90,6 +102,7 @@ pub enum Statement {
 	Live(LiveStatement),
 	Option(OptionStatement),
 	Output(OutputStatement),
+	Rebuild(RebuildStatement),
 	Relate(RelateStatement),
 	Remove(RemoveStatement),
 	Select(SelectStatement),
@@ -101,6 +114,34 @@ pub enum Statement {
 }
 
 impl Statement {
+	/// Get the kind of this statement
+	pub fn kind(&self) -> StatementKind {
+		match self {
+			Self::Analyze(_) => StatementKind::Analyze,
+			Self::Begin(_) => StatementKind::Begin,
+			Self::Cancel(_) => StatementKind::Cancel,
+			Self::Commit(_) => StatementKind::Commit,
+			Self::Create(_) => StatementKind::Create,
+			Self::Define(_) => StatementKind::Define,
+			Self::Delete(_) => StatementKind::Delete,
+			Self::Ifelse(_) => StatementKind::Ifelse,
+			Self::Info(_) => StatementKind::Info,
+			Self::Insert(_) => StatementKind::Insert,
+			Self::Kill(_) => StatementKind::Kill,
+			Self::Live(_) => StatementKind::Live,
+			Self::Option(_) => StatementKind::Option,
+			Self::Output(_) => StatementKind::Output,
+			Self::Rebuild(_) => StatementKind::Rebuild,
+			Self::Relate(_) => StatementKind::Relate,
+			Self::Remove(_) => StatementKind::Remove,
+			Self::Select(_) => StatementKind::Select,
+			Self::Set(_) => StatementKind::Set,
+			Self::Show(_) => StatementKind::Show,
+			Self::Sleep(_) => StatementKind::Sleep,
+			Self::Update(_) => StatementKind::Update,
+			Self::Use(_) => StatementKind::Use,
+		}
+	}
 	/// Get the statement timeout duration, if any
 	pub fn timeout(&self) -> Option<Duration> {
 		match self {
@@ -127,6 +168,7 @@ impl Statement {
 			Self::Live(_) => true,
 			Self::Output(v) => v.writeable(),
 			Self::Option(_) => false,
+			Self::Rebuild(_) => true,
 			Self::Relate(v) => v.writeable(),
 			Self::Remove(_) => true,
 			Self::Select(v) => v.writeable(),
@@ -157,6 +199,7 @@ impl Statement {
 			Self::Kill(v) => v.compute(ctx, opt, txn, doc).await,
 			Self::Live(v) => v.compute(ctx, opt, txn, doc).await,
 			Self::Output(v) => v.compute(ctx, opt, txn, doc).await,
+			Self::Rebuild(v) => v.compute(ctx, opt, txn, doc).await,
 			Self::Relate(v) => v.compute(ctx, opt, txn, doc).await,
 			Self::Remove(v) => v.compute(ctx, opt, txn, doc).await,
 			Self::Select(v) => v.compute(ctx, opt, txn, doc).await,
@@ -186,6 +229,7 @@ impl Display for Statement {
 			Self::Live(v) => write!(Pretty::from(f), "{v}"),
 			Self::Option(v) => write!(Pretty::from(f), "{v}"),
 			Self::Output(v) => write!(Pretty::from(f), "{v}"),
+			Self::Rebuild(v) => write!(Pretty::from(f), "{v}"),
 			Self::Relate(v) => write!(Pretty::from(f), "{v}"),
 			Self::Remove(v) => write!(Pretty::from(f), "{v}"),
 			Self::Select(v) => write!(Pretty::from(f), "{v}"),
@@ -198,6 +242,99 @@ impl Display for Statement {
 	}
 }
 
+/// The kind of a [`Statement`], without any of its contents
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum StatementKind {
+	Analyze,
+	Begin,
+	Cancel,
+	Commit,
+	Create,
+	Define,
+	Delete,
+	Ifelse,
+	Info,
+	Insert,
+	Kill,
+	Live,
+	Option,
+	Output,
+	Rebuild,
+	Relate,
+	Remove,
+	Select,
+	Set,
+	Show,
+	Sleep,
+	Update,
+	Use,
+}
+
+impl StatementKind {
+	/// Check if statements of this kind write data
+	pub fn writeable(&self) -> bool {
+		match self {
+			Self::Analyze => false,
+			Self::Begin => false,
+			Self::Cancel => false,
+			Self::Commit => false,
+			Self::Create => true,
+			Self::Define => true,
+			Self::Delete => true,
+			Self::Ifelse => false,
+			Self::Info => false,
+			Self::Insert => true,
+			Self::Kill => true,
+			Self::Live => true,
+			Self::Option => false,
+			Self::Output => false,
+			Self::Rebuild => true,
+			Self::Relate => true,
+			Self::Remove => true,
+			Self::Select => false,
+			Self::Set => false,
+			Self::Show => false,
+			Self::Sleep => false,
+			Self::Update => true,
+			Self::Use => false,
+		}
+	}
+	/// Check if this kind is a transaction statement
+	pub(crate) fn transaction(&self) -> bool {
+		matches!(self, Self::Begin | Self::Cancel | Self::Commit)
+	}
+}
+
+impl Display for StatementKind {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.write_str(match self {
+			Self::Analyze => "ANALYZE",
+			Self::Begin => "BEGIN",
+			Self::Cancel => "CANCEL",
+			Self::Commit => "COMMIT",
+			Self::Create => "CREATE",
+			Self::Define => "DEFINE",
+			Self::Delete => "DELETE",
+			Self::Ifelse => "IF",
+			Self::Info => "INFO",
+			Self::Insert => "INSERT",
+			Self::Kill => "KILL",
+			Self::Live => "LIVE",
+			Self::Option => "OPTION",
+			Self::Output => "RETURN",
+			Self::Rebuild => "REBUILD",
+			Self::Relate => "RELATE",
+			Self::Remove => "REMOVE",
+			Self::Select => "SELECT",
+			Self::Set => "LET",
+			Self::Show => "SHOW",
+			Self::Sleep => "SLEEP",
+			Self::Update => "UPDATE",
+			Self::Use => "USE",
+		})
+	}
+}
+
 pub fn statement(i: &str) -> IResult<&str, Statement> {
 	delimited(
 		mightbespace,
@@ -216,7 +353,7 @@ pub fn statement(i: &str) -> IResult<&str, Statement> {
 			map(live, Statement::Live),
 			map(option, Statement::Option),
 			map(output, Statement::Output),
-			map(relate, Statement::Relate),
+			alt((map(rebuild, Statement::Rebuild), map(relate, Statement::Relate))),
 			map(remove, Statement::Remove),
 			map(select, Statement::Select),
 			map(set, Statement::Set),
@@ -58,6 +58,7 @@ pub enum DefineStatement {
 	Event(DefineEventStatement),
 	Field(DefineFieldStatement),
 	Index(DefineIndexStatement),
+	Access(DefineAccessStatement),
 }
 
 impl DefineStatement {
@@ -82,6 +83,7 @@ impl DefineStatement {
 			Self::Field(ref v) => v.compute(ctx, opt, txn, doc).await,
 			Self::Index(ref v) => v.compute(ctx, opt, txn, doc).await,
 			Self::Analyzer(ref v) => v.compute(ctx, opt, txn, doc).await,
+			Self::Access(ref v) => v.compute(ctx, opt, txn, doc).await,
 		}
 	}
 }
@@ -101,6 +103,7 @@ impl Display for DefineStatement {
 			Self::Field(v) => Display::fmt(v, f),
 			Self::Index(v) => Display::fmt(v, f),
 			Self::Analyzer(v) => Display::fmt(v, f),
+			Self::Access(v) => Display::fmt(v, f),
 		}
 	}
 }
@@ -112,6 +115,7 @@ pub fn define(i: &str) -> IResult<&str, DefineStatement> {
 		map(function, DefineStatement::Function),
 		map(login, DefineStatement::Login),
 		map(token, DefineStatement::Token),
+		map(access, DefineStatement::Access),
 		map(scope, DefineStatement::Scope),
 		map(param, DefineStatement::Param),
 		map(table, DefineStatement::Table),
@@ -258,11 +262,62 @@ fn database_opts(i: &str) -> IResult<&str, DefineDatabaseOption> {
 // --------------------------------------------------
 // --------------------------------------------------
 
-#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Store, Hash)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Hash)]
 pub struct DefineFunctionStatement {
 	pub name: Ident,
 	pub args: Vec<(Ident, Kind)>,
 	pub block: Block,
+	/// The default values of the arguments, in the same order as the arguments
+	pub defaults: Vec<Option<Value>>,
+}
+
+// Function definitions stored before argument defaults were
+// supported have no defaults, so are decoded using this layout.
+#[derive(Deserialize)]
+struct DefineFunctionStatementV1 {
+	name: Ident,
+	args: Vec<(Ident, Kind)>,
+	block: Block,
+}
+
+impl From<DefineFunctionStatementV1> for DefineFunctionStatement {
+	fn from(v: DefineFunctionStatementV1) -> Self {
+		DefineFunctionStatement {
+			name: v.name,
+			args: v.args,
+			block: v.block,
+			defaults: Vec::new(),
+		}
+	}
+}
+
+impl From<DefineFunctionStatement> for Vec<u8> {
+	fn from(v: DefineFunctionStatement) -> Vec<u8> {
+		Self::from(&v)
+	}
+}
+
+impl From<&DefineFunctionStatement> for Vec<u8> {
+	fn from(v: &DefineFunctionStatement) -> Vec<u8> {
+		crate::sql::serde::serialize(v).unwrap_or_default()
+	}
+}
+
+impl From<Vec<u8>> for DefineFunctionStatement {
+	fn from(v: Vec<u8>) -> Self {
+		Self::from(&v)
+	}
+}
+
+impl From<&Vec<u8>> for DefineFunctionStatement {
+	fn from(v: &Vec<u8>) -> Self {
+		match crate::sql::serde::deserialize::<Self>(v) {
+			Ok(v) => v,
+			Err(_) => {
+				crate::sql::serde::deserialize::<DefineFunctionStatementV1>(v).unwrap().into()
+			}
+		}
+	}
 }
 
 impl DefineFunctionStatement {
@@ -298,6 +353,9 @@ impl fmt::Display for DefineFunctionStatement {
 				f.write_str(", ")?;
 			}
 			write!(f, "${name}: {kind}")?;
+			if let Some(Some(v)) = self.defaults.get(i) {
+				write!(f, " = {v}")?;
+			}
 		}
 		f.write_str(") ")?;
 		Display::fmt(&self.block, f)
@@ -321,8 +379,15 @@ fn function(i: &str) -> IResult<&str, DefineFunctionStatement> {
 		let (i, _) = char(':')(i)?;
 		let (i, _) = mightbespace(i)?;
 		let (i, kind) = kind(i)?;
-		Ok((i, (name, kind)))
+		let (i, default) = opt(|i| {
+			let (i, _) = mightbespace(i)?;
+			let (i, _) = char('=')(i)?;
+			let (i, _) = mightbespace(i)?;
+			value(i)
+		})(i)?;
+		Ok((i, ((name, kind), default)))
 	})(i)?;
+	let (args, defaults) = args.into_iter().unzip();
 	let (i, _) = mightbespace(i)?;
 	let (i, _) = char(')')(i)?;
 	let (i, _) = mightbespace(i)?;
@@ -333,6 +398,7 @@ fn function(i: &str) -> IResult<&str, DefineFunctionStatement> {
 			name,
 			args,
 			block,
+			defaults,
 		},
 	))
 }
@@ -643,6 +709,120 @@ fn token(i: &str) -> IResult<&str, DefineTokenStatement> {
 	))
 }
 
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Store, Hash)]
+pub struct DefineAccessStatement {
+	pub name: Ident,
+	pub base: Base,
+	pub kind: Algorithm,
+	pub code: String,
+}
+
+impl DefineAccessStatement {
+	/// Process this type returning a computed simple Value
+	pub(crate) async fn compute(
+		&self,
+		_ctx: &Context<'_>,
+		opt: &Options,
+		txn: &Transaction,
+		_doc: Option<&CursorDoc<'_>>,
+	) -> Result<Value, Error> {
+		match &self.base {
+			Base::Ns => {
+				// Selected DB?
+				opt.needs(Level::Ns)?;
+				// Allowed to run?
+				opt.check(Level::Kv)?;
+				// Claim transaction
+				let mut run = txn.lock().await;
+				// Process the statement
+				let key = crate::key::namespace::ac::new(opt.ns(), &self.name);
+				run.add_ns(opt.ns(), opt.strict).await?;
+				run.set(key, self).await?;
+				// Ok all good
+				Ok(Value::None)
+			}
+			Base::Db => {
+				// Selected DB?
+				opt.needs(Level::Db)?;
+				// Allowed to run?
+				opt.check(Level::Ns)?;
+				// Claim transaction
+				let mut run = txn.lock().await;
+				// Process the statement
+				let key = crate::key::database::ac::new(opt.ns(), opt.db(), &self.name);
+				run.add_ns(opt.ns(), opt.strict).await?;
+				run.add_db(opt.ns(), opt.db(), opt.strict).await?;
+				run.set(key, self).await?;
+				// Ok all good
+				Ok(Value::None)
+			}
+			Base::Sc(sc) => {
+				// Selected DB?
+				opt.needs(Level::Db)?;
+				// Allowed to run?
+				opt.check(Level::Db)?;
+				// Claim transaction
+				let mut run = txn.lock().await;
+				// Process the statement
+				let key = crate::key::scope::ac::new(opt.ns(), opt.db(), sc, &self.name);
+				run.add_ns(opt.ns(), opt.strict).await?;
+				run.add_db(opt.ns(), opt.db(), opt.strict).await?;
+				run.add_sc(opt.ns(), opt.db(), sc, opt.strict).await?;
+				run.set(key, self).await?;
+				// Ok all good
+				Ok(Value::None)
+			}
+			_ => unreachable!(),
+		}
+	}
+}
+
+impl Display for DefineAccessStatement {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"DEFINE ACCESS {} ON {} TYPE JWT ALGORITHM {} KEY {}",
+			self.name,
+			self.base,
+			self.kind,
+			quote_str(&self.code)
+		)
+	}
+}
+
+fn access(i: &str) -> IResult<&str, DefineAccessStatement> {
+	let (i, _) = tag_no_case("DEFINE")(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, _) = tag_no_case("ACCESS")(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, name) = ident(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, _) = tag_no_case("ON")(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, base) = base_or_scope(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, _) = tag_no_case("TYPE")(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, _) = tag_no_case("JWT")(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, _) = tag_no_case("ALGORITHM")(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, kind) = algorithm(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, _) = tag_no_case("KEY")(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, code) = strand_raw(i)?;
+	Ok((
+		i,
+		DefineAccessStatement {
+			name,
+			base,
+			kind,
+			code,
+		},
+	))
+}
+
 // --------------------------------------------------
 // --------------------------------------------------
 // --------------------------------------------------
@@ -1385,6 +1565,48 @@ mod tests {
 		assert_eq!(6, stm.to_vec().len());
 	}
 
+	#[test]
+	fn check_define_access_jwt() {
+		let sql = "DEFINE ACCESS provider ON DATABASE TYPE JWT ALGORITHM RS256 KEY 'public-key'";
+		let (_, stm) = access(sql).unwrap();
+		assert_eq!(
+			stm,
+			DefineAccessStatement {
+				name: Ident("provider".to_string()),
+				base: Base::Db,
+				kind: Algorithm::Rs256,
+				code: "public-key".to_string(),
+			}
+		);
+		assert_eq!(sql, format!("{}", stm));
+	}
+
+	#[test]
+	fn check_define_function_default_params() {
+		let sql = "DEFINE FUNCTION fn::greet($name: string, $greeting: string='Hello') { RETURN $greeting + ' ' + $name; }";
+		let (_, stm) = function(sql).unwrap();
+		assert_eq!(stm.args.len(), 2);
+		assert_eq!(stm.defaults, vec![None, Some(Value::from("Hello"))]);
+		assert_eq!(
+			"DEFINE FUNCTION fn::greet($name: string, $greeting: string = 'Hello') { RETURN $greeting + ' ' + $name; }",
+			format!("{}", stm)
+		);
+	}
+	#[test]
+	fn check_define_function_without_defaults_decodes() {
+		let sql = "DEFINE FUNCTION fn::greet($name: string) { RETURN 'Hello ' + $name; }";
+		let (_, stm) = function(sql).unwrap();
+		// Encode the definition as it was stored before argument defaults were supported
+		let enc = crate::sql::serde::serialize(&(&stm.name, &stm.args, &stm.block)).unwrap();
+		let dec = DefineFunctionStatement::from(enc);
+		assert_eq!(dec.name, stm.name);
+		assert_eq!(dec.args, stm.args);
+		assert!(dec.defaults.is_empty());
+		// Definitions with argument defaults are decoded with their defaults
+		let dec = DefineFunctionStatement::from(Vec::<u8>::from(&stm));
+		assert_eq!(dec, stm);
+	}
+
 	#[test]
 	fn check_create_non_unique_index() {
 		let sql = "DEFINE INDEX my_index ON TABLE my_table COLUMNS my_col";
@@ -173,4 +173,16 @@ mod tests {
 		let out = res.unwrap().1;
 		assert_eq!("DELETE test", format!("{}", out))
 	}
+
+	#[test]
+	fn delete_statement_return() {
+		let sql = "DELETE test WHERE age > 18 RETURN BEFORE";
+		let res = delete(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!(out.output, Some(Output::Before));
+		assert_eq!("DELETE test WHERE age > 18 RETURN BEFORE", format!("{}", out));
+		let out = delete("DELETE test RETURN DIFF").unwrap().1;
+		assert_eq!(out.output, Some(Output::Diff));
+	}
 }
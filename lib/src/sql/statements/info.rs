@@ -21,6 +21,7 @@ pub enum InfoStatement {
 	Db,
 	Sc(Ident),
 	Tb(Ident),
+	Ix(Ident, Ident),
 }
 
 impl InfoStatement {
@@ -79,6 +80,12 @@ impl InfoStatement {
 					tmp.insert(v.name.to_string(), v.to_string().into());
 				}
 				res.insert("tokens".to_owned(), tmp.into());
+				// Process the accesses
+				let mut tmp = Object::default();
+				for v in run.all_na(opt.ns()).await?.iter() {
+					tmp.insert(v.name.to_string(), v.to_string().into());
+				}
+				res.insert("accesses".to_owned(), tmp.into());
 				// Ok all good
 				Value::from(res).ok()
 			}
@@ -103,6 +110,12 @@ impl InfoStatement {
 					tmp.insert(v.name.to_string(), v.to_string().into());
 				}
 				res.insert("tokens".to_owned(), tmp.into());
+				// Process the accesses
+				let mut tmp = Object::default();
+				for v in run.all_da(opt.ns(), opt.db()).await?.iter() {
+					tmp.insert(v.name.to_string(), v.to_string().into());
+				}
+				res.insert("accesses".to_owned(), tmp.into());
 				// Process the functions
 				let mut tmp = Object::default();
 				for v in run.all_fc(opt.ns(), opt.db()).await?.iter() {
@@ -151,6 +164,12 @@ impl InfoStatement {
 					tmp.insert(v.name.to_string(), v.to_string().into());
 				}
 				res.insert("tokens".to_owned(), tmp.into());
+				// Process the accesses
+				let mut tmp = Object::default();
+				for v in run.all_sa(opt.ns(), opt.db(), sc).await?.iter() {
+					tmp.insert(v.name.to_string(), v.to_string().into());
+				}
+				res.insert("accesses".to_owned(), tmp.into());
 				// Ok all good
 				Value::from(res).ok()
 			}
@@ -190,6 +209,37 @@ impl InfoStatement {
 				// Ok all good
 				Value::from(res).ok()
 			}
+			InfoStatement::Ix(tb, ix) => {
+				// Selected DB?
+				opt.needs(Level::Db)?;
+				// Allowed to run?
+				opt.check(Level::Db)?;
+				// Claim transaction
+				let mut run = txn.lock().await;
+				// Read the index
+				let ix = run.get_ix(opt.ns(), opt.db(), tb, ix).await?;
+				// Create the result set
+				let mut res = Object::default();
+				res.insert("definition".to_owned(), ix.to_string().into());
+				// Count the unique and non-unique index entries
+				let rng = crate::key::index::Index::range(opt.ns(), opt.db(), tb, &ix.name);
+				let (mut beg, end) = (rng.start, rng.end);
+				let mut count: u64 = 0;
+				loop {
+					let keys = run.keys(beg.clone()..end.clone(), 1000).await?;
+					count += keys.len() as u64;
+					match keys.last() {
+						Some(k) if keys.len() == 1000 => {
+							beg = k.clone();
+							beg.push(0);
+						}
+						_ => break,
+					}
+				}
+				res.insert("entries".to_owned(), count.into());
+				// Ok all good
+				Value::from(res).ok()
+			}
 		}
 	}
 }
@@ -202,6 +252,7 @@ impl fmt::Display for InfoStatement {
 			Self::Db => f.write_str("INFO FOR DATABASE"),
 			Self::Sc(ref s) => write!(f, "INFO FOR SCOPE {s}"),
 			Self::Tb(ref t) => write!(f, "INFO FOR TABLE {t}"),
+			Self::Ix(ref t, ref i) => write!(f, "INFO FOR INDEX {i} ON {t}"),
 		}
 	}
 }
@@ -211,7 +262,7 @@ pub fn info(i: &str) -> IResult<&str, InfoStatement> {
 	let (i, _) = shouldbespace(i)?;
 	let (i, _) = tag_no_case("FOR")(i)?;
 	let (i, _) = shouldbespace(i)?;
-	alt((kv, ns, db, sc, tb))(i)
+	alt((kv, ns, db, sc, tb, ix))(i)
 }
 
 fn kv(i: &str) -> IResult<&str, InfoStatement> {
@@ -243,6 +294,17 @@ fn tb(i: &str) -> IResult<&str, InfoStatement> {
 	Ok((i, InfoStatement::Tb(table)))
 }
 
+fn ix(i: &str) -> IResult<&str, InfoStatement> {
+	let (i, _) = alt((tag_no_case("INDEX"), tag_no_case("IX")))(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, index) = ident(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, _) = tag_no_case("ON")(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, table) = ident(i)?;
+	Ok((i, InfoStatement::Ix(table, index)))
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -287,4 +349,14 @@ mod tests {
 		assert_eq!(out, InfoStatement::Tb(Ident::from("test")));
 		assert_eq!("INFO FOR TABLE test", format!("{}", out));
 	}
+
+	#[test]
+	fn info_query_ix() {
+		let sql = "INFO FOR INDEX test_idx ON test";
+		let res = info(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!(out, InfoStatement::Ix(Ident::from("test"), Ident::from("test_idx")));
+		assert_eq!("INFO FOR INDEX test_idx ON test", format!("{}", out));
+	}
 }
@@ -128,6 +128,9 @@ impl fmt::Display for InsertStatement {
 			f.write_str(" IGNORE")?
 		}
 		write!(f, " INTO {} {}", self.into, self.data)?;
+		if let Some(ref v) = self.update {
+			write!(f, " {v}")?
+		}
 		if let Some(ref v) = self.output {
 			write!(f, " {v}")?
 		}
@@ -190,4 +193,16 @@ mod tests {
 		let out = res.unwrap().1;
 		assert_eq!("INSERT IGNORE INTO test (field) VALUES ($value)", format!("{}", out))
 	}
+
+	#[test]
+	fn insert_statement_on_duplicate_key() {
+		let sql = "INSERT INTO test (id, name) VALUES (1, 'a') ON DUPLICATE KEY UPDATE name = 'b', visits += 1";
+		let res = insert(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!(
+			"INSERT INTO test (id, name) VALUES (1, 'a') ON DUPLICATE KEY UPDATE name = 'b', visits += 1",
+			format!("{}", out)
+		)
+	}
 }
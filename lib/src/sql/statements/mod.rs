@@ -12,6 +12,7 @@ pub(crate) mod kill;
 pub(crate) mod live;
 pub(crate) mod option;
 pub(crate) mod output;
+pub(crate) mod rebuild;
 pub(crate) mod relate;
 pub(crate) mod remove;
 pub(crate) mod select;
@@ -33,12 +34,15 @@ pub use self::kill::KillStatement;
 pub use self::live::LiveStatement;
 pub use self::option::OptionStatement;
 pub use self::output::OutputStatement;
+pub use self::rebuild::RebuildStatement;
 pub use self::relate::RelateStatement;
 pub use self::select::SelectStatement;
+pub use self::set::Destructure;
 pub use self::set::SetStatement;
 pub use self::update::UpdateStatement;
 pub use self::yuse::UseStatement;
 
+pub use self::define::DefineAccessStatement;
 pub use self::define::DefineAnalyzerStatement;
 pub use self::define::DefineDatabaseStatement;
 pub use self::define::DefineEventStatement;
@@ -53,6 +57,7 @@ pub use self::define::DefineStatement;
 pub use self::define::DefineTableStatement;
 pub use self::define::DefineTokenStatement;
 
+pub use self::remove::RemoveAccessStatement;
 pub use self::remove::RemoveDatabaseStatement;
 pub use self::remove::RemoveEventStatement;
 pub use self::remove::RemoveFieldStatement;
@@ -0,0 +1,117 @@
+use crate::ctx::Context;
+use crate::dbs::Options;
+use crate::dbs::{Level, Transaction};
+use crate::doc::CursorDoc;
+use crate::err::Error;
+use crate::sql::comment::shouldbespace;
+use crate::sql::error::IResult;
+use crate::sql::ident::{ident, Ident};
+use crate::sql::statements::UpdateStatement;
+use crate::sql::value::{Value, Values};
+use derive::Store;
+use nom::bytes::complete::tag_no_case;
+use nom::combinator::opt;
+use nom::sequence::tuple;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Store, Hash)]
+pub enum RebuildStatement {
+	Index(Ident, Ident),
+}
+
+impl RebuildStatement {
+	/// Process this type returning a computed simple Value
+	pub(crate) async fn compute(
+		&self,
+		ctx: &Context<'_>,
+		opt: &Options,
+		txn: &Transaction,
+		doc: Option<&CursorDoc<'_>>,
+	) -> Result<Value, Error> {
+		match self {
+			RebuildStatement::Index(tb, ix) => {
+				// Selected DB?
+				opt.needs(Level::Db)?;
+				// Allowed to run?
+				opt.check(Level::Db)?;
+				// Claim transaction
+				let mut run = txn.lock().await;
+				// Check that the index exists
+				run.get_ix(opt.ns(), opt.db(), tb, ix).await?;
+				// Remove the index data
+				let key = crate::key::index::all::new(opt.ns(), opt.db(), tb, ix);
+				run.delp(key, u32::MAX).await?;
+				// Clear the cache
+				let key = crate::key::table::ix::prefix(opt.ns(), opt.db(), tb);
+				run.clr(key).await?;
+				// Release the transaction
+				drop(run);
+				// Force queries to run
+				let opt = &opt.new_with_force(true);
+				// Don't process field queries
+				let opt = &opt.new_with_fields(false);
+				// Don't process event queries
+				let opt = &opt.new_with_events(false);
+				// Don't process table queries
+				let opt = &opt.new_with_tables(false);
+				// Regenerate the index data
+				let stm = UpdateStatement {
+					what: Values(vec![Value::Table(tb.clone().into())]),
+					..UpdateStatement::default()
+				};
+				stm.compute(ctx, opt, txn, doc).await?;
+				// Ok all good
+				Ok(Value::None)
+			}
+		}
+	}
+}
+
+pub fn rebuild(i: &str) -> IResult<&str, RebuildStatement> {
+	let (i, _) = tag_no_case("REBUILD")(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, _) = tag_no_case("INDEX")(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, ix) = ident(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, _) = tag_no_case("ON")(i)?;
+	let (i, _) = opt(tuple((shouldbespace, tag_no_case("TABLE"))))(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, tb) = ident(i)?;
+	Ok((i, RebuildStatement::Index(tb, ix)))
+}
+
+impl Display for RebuildStatement {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match self {
+			Self::Index(tb, ix) => write!(f, "REBUILD INDEX {ix} ON {tb}"),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+
+	#[test]
+	fn rebuild_index() {
+		let sql = "REBUILD INDEX email_idx ON user";
+		let res = rebuild(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!(out, RebuildStatement::Index(Ident::from("user"), Ident::from("email_idx")));
+		assert_eq!("REBUILD INDEX email_idx ON user", format!("{}", out));
+	}
+
+	#[test]
+	fn rebuild_index_on_table() {
+		let sql = "REBUILD INDEX email_idx ON TABLE user";
+		let res = rebuild(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!("REBUILD INDEX email_idx ON user", format!("{}", out));
+	}
+}
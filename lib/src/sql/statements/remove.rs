@@ -35,6 +35,7 @@ pub enum RemoveStatement {
 	Event(RemoveEventStatement),
 	Field(RemoveFieldStatement),
 	Index(RemoveIndexStatement),
+	Access(RemoveAccessStatement),
 }
 
 impl RemoveStatement {
@@ -59,6 +60,7 @@ impl RemoveStatement {
 			Self::Field(ref v) => v.compute(ctx, opt, txn).await,
 			Self::Index(ref v) => v.compute(ctx, opt, txn).await,
 			Self::Analyzer(ref v) => v.compute(ctx, opt, txn).await,
+			Self::Access(ref v) => v.compute(ctx, opt, txn).await,
 		}
 	}
 }
@@ -78,6 +80,7 @@ impl Display for RemoveStatement {
 			Self::Field(v) => Display::fmt(v, f),
 			Self::Index(v) => Display::fmt(v, f),
 			Self::Analyzer(v) => Display::fmt(v, f),
+			Self::Access(v) => Display::fmt(v, f),
 		}
 	}
 }
@@ -89,6 +92,7 @@ pub fn remove(i: &str) -> IResult<&str, RemoveStatement> {
 		map(function, RemoveStatement::Function),
 		map(login, RemoveStatement::Login),
 		map(token, RemoveStatement::Token),
+		map(access, RemoveStatement::Access),
 		map(scope, RemoveStatement::Scope),
 		map(param, RemoveStatement::Param),
 		map(table, RemoveStatement::Table),
@@ -484,6 +488,94 @@ fn token(i: &str) -> IResult<&str, RemoveTokenStatement> {
 // --------------------------------------------------
 // --------------------------------------------------
 
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Store, Hash)]
+pub struct RemoveAccessStatement {
+	pub name: Ident,
+	pub base: Base,
+}
+
+impl RemoveAccessStatement {
+	/// Process this type returning a computed simple Value
+	pub(crate) async fn compute(
+		&self,
+		_ctx: &Context<'_>,
+		opt: &Options,
+		txn: &Transaction,
+	) -> Result<Value, Error> {
+		match &self.base {
+			Base::Ns => {
+				// Selected NS?
+				opt.needs(Level::Ns)?;
+				// Allowed to run?
+				opt.check(Level::Kv)?;
+				// Claim transaction
+				let mut run = txn.lock().await;
+				// Delete the definition
+				let key = crate::key::namespace::ac::new(opt.ns(), &self.name);
+				run.del(key).await?;
+				// Ok all good
+				Ok(Value::None)
+			}
+			Base::Db => {
+				// Selected DB?
+				opt.needs(Level::Db)?;
+				// Allowed to run?
+				opt.check(Level::Ns)?;
+				// Claim transaction
+				let mut run = txn.lock().await;
+				// Delete the definition
+				let key = crate::key::database::ac::new(opt.ns(), opt.db(), &self.name);
+				run.del(key).await?;
+				// Ok all good
+				Ok(Value::None)
+			}
+			Base::Sc(sc) => {
+				// Selected DB?
+				opt.needs(Level::Db)?;
+				// Allowed to run?
+				opt.check(Level::Db)?;
+				// Claim transaction
+				let mut run = txn.lock().await;
+				// Delete the definition
+				let key = crate::key::scope::ac::new(opt.ns(), opt.db(), sc, &self.name);
+				run.del(key).await?;
+				// Ok all good
+				Ok(Value::None)
+			}
+			_ => unreachable!(),
+		}
+	}
+}
+
+impl Display for RemoveAccessStatement {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "REMOVE ACCESS {} ON {}", self.name, self.base)
+	}
+}
+
+fn access(i: &str) -> IResult<&str, RemoveAccessStatement> {
+	let (i, _) = tag_no_case("REMOVE")(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, _) = tag_no_case("ACCESS")(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, name) = ident(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, _) = tag_no_case("ON")(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, base) = base_or_scope(i)?;
+	Ok((
+		i,
+		RemoveAccessStatement {
+			name,
+			base,
+		},
+	))
+}
+
+// --------------------------------------------------
+// --------------------------------------------------
+// --------------------------------------------------
+
 #[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Store, Hash)]
 pub struct RemoveScopeStatement {
 	pub name: Ident,
@@ -839,4 +931,12 @@ mod tests {
 		});
 		assert_eq!(6, stm.to_vec().len());
 	}
+
+	#[test]
+	fn check_remove_access() {
+		let sql = "REMOVE ACCESS provider ON DATABASE";
+		let (_, stm) = remove(sql).unwrap();
+		assert!(matches!(stm, RemoveStatement::Access(_)));
+		assert_eq!(sql, format!("{}", stm));
+	}
 }
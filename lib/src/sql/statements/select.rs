@@ -26,9 +26,12 @@ use crate::sql::value::{selects, Value, Values};
 use crate::sql::version::{version, Version};
 use crate::sql::with::{with, With};
 use derive::Store;
+use nom::branch::alt;
 use nom::bytes::complete::tag_no_case;
-use nom::combinator::opt;
+use nom::combinator::{map, opt, peek};
 use nom::sequence::preceded;
+use nom::sequence::terminated;
+use nom::sequence::tuple;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -48,6 +51,72 @@ pub struct SelectStatement {
 	pub timeout: Option<Timeout>,
 	pub parallel: bool,
 	pub explain: Option<Explain>,
+	pub distinct: bool,
+}
+
+/// The stored layout of a [`SelectStatement`] from before `SELECT DISTINCT` was supported.
+///
+/// Blocks and subqueries store a `SELECT` statement without `DISTINCT` in this layout,
+/// so that those stored by earlier versions can still be decoded.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct SelectStatementV1 {
+	expr: Fields,
+	what: Values,
+	with: Option<With>,
+	cond: Option<Cond>,
+	split: Option<Splits>,
+	group: Option<Groups>,
+	order: Option<Orders>,
+	limit: Option<Limit>,
+	start: Option<Start>,
+	fetch: Option<Fetchs>,
+	version: Option<Version>,
+	timeout: Option<Timeout>,
+	parallel: bool,
+	explain: Option<Explain>,
+}
+
+impl From<SelectStatementV1> for SelectStatement {
+	fn from(v: SelectStatementV1) -> Self {
+		Self {
+			expr: v.expr,
+			what: v.what,
+			with: v.with,
+			cond: v.cond,
+			split: v.split,
+			group: v.group,
+			order: v.order,
+			limit: v.limit,
+			start: v.start,
+			fetch: v.fetch,
+			version: v.version,
+			timeout: v.timeout,
+			parallel: v.parallel,
+			explain: v.explain,
+			distinct: false,
+		}
+	}
+}
+
+impl From<SelectStatement> for SelectStatementV1 {
+	fn from(v: SelectStatement) -> Self {
+		Self {
+			expr: v.expr,
+			what: v.what,
+			with: v.with,
+			cond: v.cond,
+			split: v.split,
+			group: v.group,
+			order: v.order,
+			limit: v.limit,
+			start: v.start,
+			fetch: v.fetch,
+			version: v.version,
+			timeout: v.timeout,
+			parallel: v.parallel,
+			explain: v.explain,
+		}
+	}
 }
 
 impl SelectStatement {
@@ -144,7 +213,11 @@ impl SelectStatement {
 
 impl fmt::Display for SelectStatement {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		write!(f, "SELECT {} FROM {}", self.expr, self.what)?;
+		f.write_str("SELECT")?;
+		if self.distinct {
+			f.write_str(" DISTINCT")?
+		}
+		write!(f, " {} FROM {}", self.expr, self.what)?;
 		if let Some(ref v) = self.with {
 			write!(f, " {v}")?
 		}
@@ -188,7 +261,17 @@ impl fmt::Display for SelectStatement {
 pub fn select(i: &str) -> IResult<&str, SelectStatement> {
 	let (i, _) = tag_no_case("SELECT")(i)?;
 	let (i, _) = shouldbespace(i)?;
-	let (i, expr) = fields(i)?;
+	let (i, (distinct, expr)) = alt((
+		// DISTINCT is only a keyword when fields follow it, so a field can be named distinct
+		map(
+			preceded(
+				terminated(tag_no_case("DISTINCT"), shouldbespace),
+				terminated(fields, peek(tuple((shouldbespace, tag_no_case("FROM"))))),
+			),
+			|v| (true, v),
+		),
+		map(fields, |v| (false, v)),
+	))(i)?;
 	let (i, _) = shouldbespace(i)?;
 	let (i, _) = tag_no_case("FROM")(i)?;
 	let (i, _) = shouldbespace(i)?;
@@ -225,6 +308,7 @@ pub fn select(i: &str) -> IResult<&str, SelectStatement> {
 			timeout,
 			parallel: parallel.is_some(),
 			explain,
+			distinct,
 		},
 	))
 }
@@ -278,4 +362,43 @@ mod tests {
 		let out = res.unwrap().1;
 		assert_eq!(sql, format!("{}", out))
 	}
+
+	#[test]
+	fn select_statement_distinct() {
+		let sql = "SELECT DISTINCT name, age FROM person";
+		let res = select(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert!(out.distinct);
+		assert_eq!(sql, format!("{}", out))
+	}
+
+	#[test]
+	fn select_statement_distinct_value() {
+		let sql = "select distinct value name from person";
+		let res = select(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert!(out.distinct);
+		assert_eq!("SELECT DISTINCT VALUE name FROM person", format!("{}", out))
+	}
+
+	#[test]
+	fn select_statement_distinct_field() {
+		let sql = "SELECT distinct FROM person";
+		let res = select(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert!(!out.distinct);
+		assert_eq!("SELECT distinct FROM person", format!("{}", out))
+	}
+
+	#[test]
+	fn select_statement_not_distinct() {
+		let sql = "SELECT name FROM person";
+		let res = select(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert!(!out.distinct);
+	}
 }
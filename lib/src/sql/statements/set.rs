@@ -5,13 +5,17 @@ use crate::doc::CursorDoc;
 use crate::err::Error;
 use crate::sql::comment::mightbespace;
 use crate::sql::comment::shouldbespace;
+use crate::sql::common::{closebraces, closebracket, commas, openbraces, openbracket};
 use crate::sql::error::IResult;
 use crate::sql::ident::ident_raw;
 use crate::sql::value::{value, Value};
 use derive::Store;
+use nom::branch::alt;
 use nom::bytes::complete::tag_no_case;
 use nom::character::complete::char;
-use nom::sequence::preceded;
+use nom::combinator::{map, opt};
+use nom::multi::separated_list1;
+use nom::sequence::{delimited, preceded};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -19,6 +23,54 @@ use std::fmt;
 pub struct SetStatement {
 	pub name: String,
 	pub what: Value,
+	pub destructure: Option<Destructure>,
+}
+
+/// The stored layout of a [`SetStatement`] from before destructuring was supported.
+///
+/// Blocks store a `LET` statement without a pattern in this layout, so that blocks
+/// stored by earlier versions can still be decoded.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct SetStatementV1 {
+	name: String,
+	what: Value,
+}
+
+impl From<SetStatementV1> for SetStatement {
+	fn from(v: SetStatementV1) -> Self {
+		Self {
+			name: v.name,
+			what: v.what,
+			destructure: None,
+		}
+	}
+}
+
+impl From<SetStatement> for SetStatementV1 {
+	fn from(v: SetStatement) -> Self {
+		Self {
+			name: v.name,
+			what: v.what,
+		}
+	}
+}
+
+/// A pattern which binds several parameters from a single value
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash)]
+pub enum Destructure {
+	/// Binds each parameter to the object field with the same name
+	Object(Vec<String>),
+	/// Binds each parameter to the array element in the same position
+	Array(Vec<String>),
+}
+
+impl fmt::Display for Destructure {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Object(v) => write!(f, "{{ {} }}", v.join(", ")),
+			Self::Array(v) => write!(f, "[{}]", v.join(", ")),
+		}
+	}
 }
 
 impl SetStatement {
@@ -36,18 +88,69 @@ impl SetStatement {
 	) -> Result<Value, Error> {
 		self.what.compute(ctx, opt, txn, doc).await
 	}
+	/// Returns the names of the parameters which this statement sets
+	pub(crate) fn names(&self) -> &[String] {
+		match &self.destructure {
+			Some(Destructure::Object(v) | Destructure::Array(v)) => v,
+			None => std::slice::from_ref(&self.name),
+		}
+	}
+	/// Splits a computed value into the parameters which this statement sets
+	pub(crate) fn bind(&self, val: Value) -> Result<Vec<(String, Value)>, Error> {
+		match &self.destructure {
+			None => Ok(vec![(self.name.to_owned(), val)]),
+			Some(Destructure::Object(names)) => match val {
+				Value::Object(mut v) => names
+					.iter()
+					.map(|name| match v.remove(name) {
+						Some(v) => Ok((name.to_owned(), v)),
+						None => Err(Error::InvalidDestructure {
+							message: format!("the object has no field '{name}'"),
+						}),
+					})
+					.collect(),
+				v => Err(Error::InvalidDestructure {
+					message: format!("the value {v} is not an object"),
+				}),
+			},
+			Some(Destructure::Array(names)) => match val {
+				Value::Array(v) => {
+					let mut v = v.into_iter();
+					names
+						.iter()
+						.enumerate()
+						.map(|(i, name)| match v.next() {
+							Some(v) => Ok((name.to_owned(), v)),
+							None => Err(Error::InvalidDestructure {
+								message: format!("the array has no element at index {i}"),
+							}),
+						})
+						.collect()
+				}
+				v => Err(Error::InvalidDestructure {
+					message: format!("the value {v} is not an array"),
+				}),
+			},
+		}
+	}
 }
 
 impl fmt::Display for SetStatement {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		write!(f, "LET ${} = {}", self.name, self.what)
+		match &self.destructure {
+			Some(v) => write!(f, "LET {v} = {}", self.what),
+			None => write!(f, "LET ${} = {}", self.name, self.what),
+		}
 	}
 }
 
 pub fn set(i: &str) -> IResult<&str, SetStatement> {
 	let (i, _) = tag_no_case("LET")(i)?;
 	let (i, _) = shouldbespace(i)?;
-	let (i, n) = preceded(char('$'), ident_raw)(i)?;
+	let (i, (n, d)) = alt((
+		map(preceded(char('$'), ident_raw), |n| (n, None)),
+		map(destructure, |d| (String::new(), Some(d))),
+	))(i)?;
 	let (i, _) = mightbespace(i)?;
 	let (i, _) = char('=')(i)?;
 	let (i, _) = mightbespace(i)?;
@@ -57,10 +160,22 @@ pub fn set(i: &str) -> IResult<&str, SetStatement> {
 		SetStatement {
 			name: n,
 			what: w,
+			destructure: d,
 		},
 	))
 }
 
+fn destructure(i: &str) -> IResult<&str, Destructure> {
+	alt((
+		map(delimited(openbraces, bindings, closebraces), Destructure::Object),
+		map(delimited(openbracket, bindings, closebracket), Destructure::Array),
+	))(i)
+}
+
+fn bindings(i: &str) -> IResult<&str, Vec<String>> {
+	separated_list1(commas, preceded(opt(char('$')), ident_raw))(i)
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -74,4 +189,24 @@ mod tests {
 		let out = res.unwrap().1;
 		assert_eq!("LET $name = NULL", format!("{}", out));
 	}
+
+	#[test]
+	fn let_statement_destructure_object() {
+		let sql = "LET {a, $b} = $obj";
+		let res = set(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!("LET { a, b } = $obj", format!("{}", out));
+		assert_eq!(out.names(), ["a", "b"]);
+	}
+
+	#[test]
+	fn let_statement_destructure_array() {
+		let sql = "LET [ x, y ] = [1, 2, 3]";
+		let res = set(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!("LET [x, y] = [1, 2, 3]", format!("{}", out));
+		assert_eq!(out.names(), ["x", "y"]);
+	}
 }
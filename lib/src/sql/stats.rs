@@ -0,0 +1,296 @@
+use crate::err::Error;
+use crate::sql::function;
+use crate::sql::idiom;
+use crate::sql::value;
+use serde::ser::{self, Serialize};
+
+/// Size and complexity metrics for a parsed query
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct QueryStats {
+	/// The number of statements in the query
+	pub statements: usize,
+	/// The deepest nesting of values, such as arrays, objects, and subqueries
+	pub depth: usize,
+	/// The number of field and path references
+	pub idioms: usize,
+	/// The number of function calls
+	pub functions: usize,
+}
+
+impl QueryStats {
+	/// Counts the values, idioms, and functions which make up the specified node
+	pub(crate) fn count<T: ?Sized + Serialize>(&mut self, node: &T) {
+		// Counting can not fail, so any error can be ignored
+		let _ = node.serialize(Counter {
+			stats: self,
+			depth: 0,
+		});
+	}
+}
+
+/// A serializer which walks the syntax tree, recording metrics as it goes
+struct Counter<'a> {
+	stats: &'a mut QueryStats,
+	depth: usize,
+}
+
+impl Counter<'_> {
+	fn child<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+		value.serialize(Counter {
+			stats: &mut *self.stats,
+			depth: self.depth,
+		})
+	}
+
+	fn enter(&mut self, name: &str) {
+		match name {
+			value::TOKEN => {
+				self.depth += 1;
+				self.stats.depth = self.stats.depth.max(self.depth);
+			}
+			function::TOKEN => self.stats.functions += 1,
+			_ => (),
+		}
+	}
+}
+
+macro_rules! leaves {
+	($($method:ident: $ty:ty),*) => {
+		$(
+			fn $method(self, _: $ty) -> Result<(), Error> {
+				Ok(())
+			}
+		)*
+	};
+}
+
+impl ser::Serializer for Counter<'_> {
+	type Ok = ();
+	type Error = Error;
+
+	type SerializeSeq = Self;
+	type SerializeTuple = Self;
+	type SerializeTupleStruct = Self;
+	type SerializeTupleVariant = Self;
+	type SerializeMap = Self;
+	type SerializeStruct = Self;
+	type SerializeStructVariant = Self;
+
+	leaves! {
+		serialize_bool: bool,
+		serialize_i8: i8,
+		serialize_i16: i16,
+		serialize_i32: i32,
+		serialize_i64: i64,
+		serialize_i128: i128,
+		serialize_u8: u8,
+		serialize_u16: u16,
+		serialize_u32: u32,
+		serialize_u64: u64,
+		serialize_u128: u128,
+		serialize_f32: f32,
+		serialize_f64: f64,
+		serialize_char: char,
+		serialize_str: &str,
+		serialize_bytes: &[u8],
+		serialize_unit_struct: &'static str
+	}
+
+	fn serialize_unit(self) -> Result<(), Error> {
+		Ok(())
+	}
+
+	fn serialize_none(self) -> Result<(), Error> {
+		Ok(())
+	}
+
+	fn serialize_some<T: ?Sized + Serialize>(mut self, value: &T) -> Result<(), Error> {
+		self.child(value)
+	}
+
+	fn serialize_unit_variant(
+		mut self,
+		name: &'static str,
+		_variant_index: u32,
+		_variant: &'static str,
+	) -> Result<(), Error> {
+		self.enter(name);
+		Ok(())
+	}
+
+	fn serialize_newtype_struct<T: ?Sized + Serialize>(
+		mut self,
+		name: &'static str,
+		value: &T,
+	) -> Result<(), Error> {
+		if name == idiom::TOKEN {
+			self.stats.idioms += 1;
+		}
+		self.child(value)
+	}
+
+	fn serialize_newtype_variant<T: ?Sized + Serialize>(
+		mut self,
+		name: &'static str,
+		_variant_index: u32,
+		_variant: &'static str,
+		value: &T,
+	) -> Result<(), Error> {
+		self.enter(name);
+		self.child(value)
+	}
+
+	fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Error> {
+		Ok(self)
+	}
+
+	fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Error> {
+		Ok(self)
+	}
+
+	fn serialize_tuple_struct(
+		self,
+		_name: &'static str,
+		_len: usize,
+	) -> Result<Self::SerializeTupleStruct, Error> {
+		Ok(self)
+	}
+
+	fn serialize_tuple_variant(
+		mut self,
+		name: &'static str,
+		_variant_index: u32,
+		_variant: &'static str,
+		_len: usize,
+	) -> Result<Self::SerializeTupleVariant, Error> {
+		self.enter(name);
+		Ok(self)
+	}
+
+	fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Error> {
+		Ok(self)
+	}
+
+	fn serialize_struct(
+		self,
+		_name: &'static str,
+		_len: usize,
+	) -> Result<Self::SerializeStruct, Error> {
+		Ok(self)
+	}
+
+	fn serialize_struct_variant(
+		mut self,
+		name: &'static str,
+		_variant_index: u32,
+		_variant: &'static str,
+		_len: usize,
+	) -> Result<Self::SerializeStructVariant, Error> {
+		self.enter(name);
+		Ok(self)
+	}
+}
+
+impl ser::SerializeSeq for Counter<'_> {
+	type Ok = ();
+	type Error = Error;
+
+	fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+		self.child(value)
+	}
+
+	fn end(self) -> Result<(), Error> {
+		Ok(())
+	}
+}
+
+impl ser::SerializeTuple for Counter<'_> {
+	type Ok = ();
+	type Error = Error;
+
+	fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+		self.child(value)
+	}
+
+	fn end(self) -> Result<(), Error> {
+		Ok(())
+	}
+}
+
+impl ser::SerializeTupleStruct for Counter<'_> {
+	type Ok = ();
+	type Error = Error;
+
+	fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+		self.child(value)
+	}
+
+	fn end(self) -> Result<(), Error> {
+		Ok(())
+	}
+}
+
+impl ser::SerializeTupleVariant for Counter<'_> {
+	type Ok = ();
+	type Error = Error;
+
+	fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+		self.child(value)
+	}
+
+	fn end(self) -> Result<(), Error> {
+		Ok(())
+	}
+}
+
+impl ser::SerializeMap for Counter<'_> {
+	type Ok = ();
+	type Error = Error;
+
+	fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Error> {
+		self.child(key)
+	}
+
+	fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+		self.child(value)
+	}
+
+	fn end(self) -> Result<(), Error> {
+		Ok(())
+	}
+}
+
+impl ser::SerializeStruct for Counter<'_> {
+	type Ok = ();
+	type Error = Error;
+
+	fn serialize_field<T: ?Sized + Serialize>(
+		&mut self,
+		_key: &'static str,
+		value: &T,
+	) -> Result<(), Error> {
+		self.child(value)
+	}
+
+	fn end(self) -> Result<(), Error> {
+		Ok(())
+	}
+}
+
+impl ser::SerializeStructVariant for Counter<'_> {
+	type Ok = ();
+	type Error = Error;
+
+	fn serialize_field<T: ?Sized + Serialize>(
+		&mut self,
+		_key: &'static str,
+		value: &T,
+	) -> Result<(), Error> {
+		self.child(value)
+	}
+
+	fn end(self) -> Result<(), Error> {
+		Ok(())
+	}
+}
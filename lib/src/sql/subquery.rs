@@ -11,7 +11,7 @@ use crate::sql::statements::ifelse::{ifelse, IfelseStatement};
 use crate::sql::statements::insert::{insert, InsertStatement};
 use crate::sql::statements::output::{output, OutputStatement};
 use crate::sql::statements::relate::{relate, RelateStatement};
-use crate::sql::statements::select::{select, SelectStatement};
+use crate::sql::statements::select::{select, SelectStatement, SelectStatementV1};
 use crate::sql::statements::update::{update, UpdateStatement};
 use crate::sql::value::{value, Value};
 use nom::branch::alt;
@@ -23,7 +23,7 @@ use std::fmt::{self, Display, Formatter};
 pub(crate) const TOKEN: &str = "$surrealdb::private::sql::Subquery";
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash)]
-#[serde(rename = "$surrealdb::private::sql::Subquery")]
+#[serde(from = "SubqueryRepr", into = "SubqueryRepr")]
 pub enum Subquery {
 	Value(Value),
 	Ifelse(IfelseStatement),
@@ -37,6 +37,61 @@ pub enum Subquery {
 	// Add new variants here
 }
 
+/// The stored layout of a [`Subquery`].
+///
+/// A `SELECT` statement without `DISTINCT` keeps its original layout, so that subqueries
+/// stored by earlier versions can still be decoded. A `SELECT DISTINCT` statement is
+/// stored in the variant appended to the end.
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "$surrealdb::private::sql::Subquery")]
+enum SubqueryRepr {
+	Value(Value),
+	Ifelse(IfelseStatement),
+	Output(OutputStatement),
+	Select(SelectStatementV1),
+	Create(CreateStatement),
+	Update(UpdateStatement),
+	Delete(DeleteStatement),
+	Relate(RelateStatement),
+	Insert(InsertStatement),
+	SelectDistinct(SelectStatement),
+	// Add new variants here
+}
+
+impl From<Subquery> for SubqueryRepr {
+	fn from(v: Subquery) -> Self {
+		match v {
+			Subquery::Value(v) => Self::Value(v),
+			Subquery::Ifelse(v) => Self::Ifelse(v),
+			Subquery::Output(v) => Self::Output(v),
+			Subquery::Select(v) if v.distinct => Self::SelectDistinct(v),
+			Subquery::Select(v) => Self::Select(v.into()),
+			Subquery::Create(v) => Self::Create(v),
+			Subquery::Update(v) => Self::Update(v),
+			Subquery::Delete(v) => Self::Delete(v),
+			Subquery::Relate(v) => Self::Relate(v),
+			Subquery::Insert(v) => Self::Insert(v),
+		}
+	}
+}
+
+impl From<SubqueryRepr> for Subquery {
+	fn from(v: SubqueryRepr) -> Self {
+		match v {
+			SubqueryRepr::Value(v) => Self::Value(v),
+			SubqueryRepr::Ifelse(v) => Self::Ifelse(v),
+			SubqueryRepr::Output(v) => Self::Output(v),
+			SubqueryRepr::Select(v) => Self::Select(v.into()),
+			SubqueryRepr::Create(v) => Self::Create(v),
+			SubqueryRepr::Update(v) => Self::Update(v),
+			SubqueryRepr::Delete(v) => Self::Delete(v),
+			SubqueryRepr::Relate(v) => Self::Relate(v),
+			SubqueryRepr::Insert(v) => Self::Insert(v),
+			SubqueryRepr::SelectDistinct(v) => Self::Select(v),
+		}
+	}
+}
+
 impl PartialOrd for Subquery {
 	#[inline]
 	fn partial_cmp(&self, _: &Self) -> Option<Ordering> {
@@ -301,4 +356,28 @@ mod tests {
 		let out = res.unwrap().1;
 		assert_eq!("(SELECT * FROM test)", format!("{}", out))
 	}
+
+	#[test]
+	fn subquery_decodes_stored_select_layout() {
+		#[derive(Serialize)]
+		#[allow(dead_code)]
+		enum Subquery {
+			Value(Value),
+			Ifelse(IfelseStatement),
+			Output(OutputStatement),
+			Select(SelectStatementV1),
+		}
+		let (_, stm) = select("SELECT * FROM test").unwrap();
+		let bytes = crate::sql::serde::serialize(&Subquery::Select(stm.clone().into())).unwrap();
+		let out: super::Subquery = crate::sql::serde::deserialize(&bytes).unwrap();
+		assert_eq!(super::Subquery::Select(stm), out);
+	}
+
+	#[test]
+	fn subquery_stores_select_distinct() {
+		let (_, out) = subquery("(SELECT DISTINCT name FROM test)").unwrap();
+		let bytes = crate::sql::serde::serialize(&out).unwrap();
+		let res: Subquery = crate::sql::serde::deserialize(&bytes).unwrap();
+		assert_eq!(out, res);
+	}
 }
@@ -6,6 +6,7 @@ use crate::sql::error::IResult;
 use crate::sql::escape::escape_rid;
 use crate::sql::id::{id, Id};
 use crate::sql::ident::ident_raw;
+use crate::sql::number::Number;
 use crate::sql::strand::Strand;
 use crate::sql::value::Value;
 use derive::Store;
@@ -15,6 +16,7 @@ use nom::character::complete::char;
 use nom::combinator::map;
 use nom::sequence::delimited;
 use serde::{Deserialize, Serialize};
+use serde_json::Value as Json;
 use std::fmt;
 use std::str::FromStr;
 
@@ -89,6 +91,31 @@ impl TryFrom<&str> for Thing {
 }
 
 impl Thing {
+	/// Create a new record id from a table name and an id
+	pub fn from_parts(tb: impl Into<String>, id: impl Into<Id>) -> Thing {
+		Thing {
+			tb: tb.into(),
+			id: id.into(),
+		}
+	}
+	/// Create a new record id from a table name and a JSON id
+	///
+	/// The id can be a JSON string, integer, array, or object. Any other JSON
+	/// value is not a valid record id, and results in an error.
+	pub fn from_json_id(tb: impl Into<String>, id: Json) -> Result<Thing, Error> {
+		let id = match Value::from(id) {
+			Value::Strand(v) => Id::from(v),
+			Value::Number(Number::Int(v)) => Id::from(v),
+			Value::Array(v) => Id::from(v),
+			Value::Object(v) => Id::from(v),
+			v => {
+				return Err(Error::IdInvalid {
+					value: v.to_string(),
+				})
+			}
+		};
+		Ok(Thing::from_parts(tb, id))
+	}
 	/// Convert the Thing to a raw String
 	pub fn to_raw(&self) -> String {
 		self.to_string()
@@ -253,4 +280,31 @@ mod tests {
 			}
 		);
 	}
+
+	#[test]
+	fn thing_from_parts() {
+		let out = Thing::from_parts("test", 1);
+		assert_eq!(out, thing("test:1").unwrap().1);
+		let out = Thing::from_parts("user", vec!["a", "b"]);
+		assert_eq!(out, thing("user:['a', 'b']").unwrap().1);
+		let out = Thing::from_parts(
+			"test",
+			Object::from(map! { "location".to_owned() => Value::from("London") }),
+		);
+		assert_eq!(out, thing("test:{ location: 'London' }").unwrap().1);
+	}
+
+	#[test]
+	fn thing_from_json_id() {
+		let out = Thing::from_json_id("test", serde_json::json!(1)).unwrap();
+		assert_eq!(out, thing("test:1").unwrap().1);
+		let out = Thing::from_json_id("user", serde_json::json!(["a", "b"])).unwrap();
+		assert_eq!(out, thing("user:['a', 'b']").unwrap().1);
+		let out = Thing::from_json_id("test", serde_json::json!({ "location": "London" })).unwrap();
+		assert_eq!(out, thing("test:{ location: 'London' }").unwrap().1);
+		let out = Thing::from_json_id("test", serde_json::json!("tobie")).unwrap();
+		assert_eq!(out, thing("test:tobie").unwrap().1);
+		let res = Thing::from_json_id("test", serde_json::json!(1.5));
+		assert!(matches!(res, Err(Error::IdInvalid { .. })));
+	}
 }
@@ -0,0 +1,186 @@
+use crate::sql::number::Number;
+use crate::sql::value::Value;
+use serde_json::Value as Json;
+
+impl Value {
+	/// Converts this `Value` into a canonical JSON string, which is identical for equal values
+	/// across runs and platforms, and so is suitable for hashing and signing.
+	///
+	/// - Object keys are sorted lexicographically, and no insignificant whitespace is written.
+	/// - Integers are written in full. Floats are written in their shortest decimal form which
+	///   round-trips, without an exponent, and with no fractional part when integral, while
+	///   `NaN` and infinite floats are written as `null`. Decimals are written normalized,
+	///   without trailing zeros.
+	/// - Strings only escape quotes, backslashes, and control characters.
+	/// - Durations, datetimes, uuids, and record ids are written as strings in their raw
+	///   SurrealQL form, datetimes being in RFC 3339 format in UTC.
+	/// - Geometries are written as GeoJSON, and bytes as an array of integers.
+	/// - `NONE` is written as `null`, and any other value as a string of its SurrealQL form.
+	pub fn to_canonical_json(&self) -> String {
+		let mut out = String::new();
+		canonical(&mut out, self);
+		out
+	}
+}
+
+fn canonical(out: &mut String, v: &Value) {
+	match v {
+		Value::None | Value::Null => out.push_str("null"),
+		Value::Bool(v) => out.push_str(if *v {
+			"true"
+		} else {
+			"false"
+		}),
+		Value::Number(Number::Int(v)) => out.push_str(&v.to_string()),
+		Value::Number(Number::Float(v)) => float(out, *v),
+		Value::Number(Number::Decimal(v)) => out.push_str(&v.normalize().to_string()),
+		Value::Strand(v) => string(out, v.as_str()),
+		Value::Duration(v) => string(out, &v.to_raw()),
+		Value::Datetime(v) => string(out, &v.to_raw()),
+		Value::Uuid(v) => string(out, &v.to_raw()),
+		Value::Thing(v) => string(out, &v.to_raw()),
+		Value::Array(v) => {
+			out.push('[');
+			for (i, v) in v.iter().enumerate() {
+				if i > 0 {
+					out.push(',');
+				}
+				canonical(out, v);
+			}
+			out.push(']');
+		}
+		Value::Object(v) => {
+			out.push('{');
+			for (i, (k, v)) in v.iter().enumerate() {
+				if i > 0 {
+					out.push(',');
+				}
+				string(out, k);
+				out.push(':');
+				canonical(out, v);
+			}
+			out.push('}');
+		}
+		Value::Geometry(v) => json(out, &Value::Geometry(v.clone()).into_json()),
+		Value::Bytes(v) => {
+			out.push('[');
+			for (i, v) in v.iter().enumerate() {
+				if i > 0 {
+					out.push(',');
+				}
+				out.push_str(&v.to_string());
+			}
+			out.push(']');
+		}
+		v => string(out, &v.to_string()),
+	}
+}
+
+fn json(out: &mut String, v: &Json) {
+	match v {
+		Json::Null => out.push_str("null"),
+		Json::Bool(v) => out.push_str(if *v {
+			"true"
+		} else {
+			"false"
+		}),
+		Json::Number(v) => match (v.as_i64(), v.as_u64(), v.as_f64()) {
+			(Some(v), _, _) => out.push_str(&v.to_string()),
+			(_, Some(v), _) => out.push_str(&v.to_string()),
+			(_, _, Some(v)) => float(out, v),
+			_ => out.push_str("null"),
+		},
+		Json::String(v) => string(out, v),
+		Json::Array(v) => {
+			out.push('[');
+			for (i, v) in v.iter().enumerate() {
+				if i > 0 {
+					out.push(',');
+				}
+				json(out, v);
+			}
+			out.push(']');
+		}
+		Json::Object(v) => {
+			let mut keys: Vec<_> = v.keys().collect();
+			keys.sort_unstable();
+			out.push('{');
+			for (i, k) in keys.into_iter().enumerate() {
+				if i > 0 {
+					out.push(',');
+				}
+				string(out, k);
+				out.push(':');
+				json(out, &v[k]);
+			}
+			out.push('}');
+		}
+	}
+}
+
+fn float(out: &mut String, v: f64) {
+	match v {
+		v if !v.is_finite() => out.push_str("null"),
+		// Ensure that negative zero is written as zero
+		v if v == 0.0 => out.push('0'),
+		v => out.push_str(&v.to_string()),
+	}
+}
+
+fn string(out: &mut String, v: &str) {
+	out.push('"');
+	for c in v.chars() {
+		match c {
+			'"' => out.push_str("\\\""),
+			'\\' => out.push_str("\\\\"),
+			'\n' => out.push_str("\\n"),
+			'\r' => out.push_str("\\r"),
+			'\t' => out.push_str("\\t"),
+			'\u{08}' => out.push_str("\\b"),
+			'\u{0c}' => out.push_str("\\f"),
+			c if c < ' ' => out.push_str(&format!("\\u{:04x}", c as u32)),
+			c => out.push(c),
+		}
+	}
+	out.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+	use crate::sql::test::Parse;
+
+	#[test]
+	fn canonical_sorted_keys() {
+		let one = Value::parse("{ b: 1, a: { d: [1, 2], c: 'test' } }");
+		let two = Value::parse("{ a: { c: 'test', d: [1, 2] }, b: 1 }");
+		assert_eq!(one.to_canonical_json(), two.to_canonical_json());
+		assert_eq!(one.to_canonical_json(), r#"{"a":{"c":"test","d":[1,2]},"b":1}"#);
+	}
+
+	#[test]
+	fn canonical_numbers() {
+		let val = Value::parse("[1, -0.0, 1.0, 0.1, 1.50dec, 10000000000000000000000.0]");
+		assert_eq!(val.to_canonical_json(), "[1,0,1,0.1,1.5,10000000000000000000000]");
+		let val = Value::from(f64::NAN);
+		assert_eq!(val.to_canonical_json(), "null");
+	}
+
+	#[test]
+	fn canonical_special_types() {
+		let val = Value::parse(
+			"{
+				duration: 1h30m,
+				datetime: '2023-07-03T07:18:52.841147Z',
+				id: person:tobie,
+				text: 'a \"quoted\"\\nline',
+				uuid: '03412258-988f-47cd-82db-549902cdaffe',
+			}",
+		);
+		assert_eq!(
+			val.to_canonical_json(),
+			r#"{"datetime":"2023-07-03T07:18:52.841147Z","duration":"1h30m","id":"person:tobie","text":"a \"quoted\"\nline","uuid":"03412258-988f-47cd-82db-549902cdaffe"}"#
+		);
+	}
+}
@@ -0,0 +1,81 @@
+use crate::sql::number::{decimal_is_integer, Number};
+use crate::sql::value::Value;
+use rust_decimal::prelude::ToPrimitive;
+
+/// The numeric type which all numbers are normalized to by [`Value::coerce_numbers`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NumberMode {
+	/// Every number is converted into a float
+	AllFloat,
+	/// Every number with no fractional part, which fits within an integer, is converted into an integer
+	IntWherePossible,
+}
+
+impl Value {
+	/// Normalizes every number within this `Value`, including any numbers nested
+	/// within objects and arrays, so that numbers have a predictable type.
+	pub fn coerce_numbers(&mut self, mode: NumberMode) {
+		match self {
+			Value::Number(v) => *v = coerce(v, mode),
+			Value::Object(v) => v.values_mut().for_each(|v| v.coerce_numbers(mode)),
+			Value::Array(v) => v.iter_mut().for_each(|v| v.coerce_numbers(mode)),
+			_ => (),
+		}
+	}
+}
+
+fn coerce(v: &Number, mode: NumberMode) -> Number {
+	match (mode, v) {
+		(NumberMode::AllFloat, v) => Number::Float(v.to_float()),
+		(NumberMode::IntWherePossible, Number::Float(f))
+			if f.fract() == 0.0 && *f >= i64::MIN as f64 && *f < i64::MAX as f64 =>
+		{
+			Number::Int(*f as i64)
+		}
+		(NumberMode::IntWherePossible, Number::Decimal(d)) if decimal_is_integer(d) => {
+			match d.to_i64() {
+				Some(i) => Number::Int(i),
+				None => v.clone(),
+			}
+		}
+		_ => v.clone(),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+	use crate::sql::test::Parse;
+
+	#[test]
+	fn coerce_numbers_all_float() {
+		let mut val = Value::parse("{ a: 3, b: [1, 2.5, { c: 4dec }], d: 'text' }");
+		val.coerce_numbers(NumberMode::AllFloat);
+		let res = Value::parse("{ a: 3.0, b: [1.0, 2.5, { c: 4.0 }], d: 'text' }");
+		assert_eq!(val, res);
+		assert!(matches!(val.pick(&["a".into()]), Value::Number(Number::Float(v)) if v == 3.0));
+		assert!(matches!(
+			val.pick(&["b".into(), 2.into(), "c".into()]),
+			Value::Number(Number::Float(v)) if v == 4.0
+		));
+	}
+
+	#[test]
+	fn coerce_numbers_int_where_possible() {
+		let mut val = Value::parse("{ a: 3.0, b: [1, 2.5, { c: 4.00dec, d: 4.5dec }], e: 1e100 }");
+		val.coerce_numbers(NumberMode::IntWherePossible);
+		assert!(matches!(val.pick(&["a".into()]), Value::Number(Number::Int(3))));
+		assert!(matches!(val.pick(&["b".into(), 0.into()]), Value::Number(Number::Int(1))));
+		assert!(matches!(val.pick(&["b".into(), 1.into()]), Value::Number(Number::Float(_))));
+		assert!(matches!(
+			val.pick(&["b".into(), 2.into(), "c".into()]),
+			Value::Number(Number::Int(4))
+		));
+		assert!(matches!(
+			val.pick(&["b".into(), 2.into(), "d".into()]),
+			Value::Number(Number::Decimal(_))
+		));
+		assert!(matches!(val.pick(&["e".into()]), Value::Number(Number::Float(_))));
+	}
+}
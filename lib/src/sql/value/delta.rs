@@ -0,0 +1,302 @@
+//! A compact binary delta between two values, for synchronising values
+//! over constrained links.
+//!
+//! Unlike [`Value::diff`], which produces JSON Patch operations, a delta
+//! is encoded with the same binary format used to store values, and
+//! applying a delta to the original value reproduces the new value
+//! exactly, including the representation of numbers.
+
+use crate::err::Error;
+use crate::sql::number::Number;
+use crate::sql::value::Value;
+use crate::sql::{Array, Object};
+use serde::{Deserialize, Serialize};
+
+/// The version of the delta encoding, written as the first byte of each delta
+const VERSION: u8 = 1;
+
+#[derive(Debug, Serialize, Deserialize)]
+enum Delta {
+	/// The value is unchanged
+	Same,
+	/// The value is replaced entirely
+	Replace(Value),
+	/// Fields are removed from an object, and other fields are added or changed
+	Object {
+		removed: Vec<String>,
+		changed: Vec<(String, Delta)>,
+	},
+	/// An array is resized, and elements are added or changed
+	Array {
+		len: usize,
+		changed: Vec<(usize, Delta)>,
+	},
+	/// The middle of a string is replaced, keeping its first and last bytes
+	Strand {
+		prefix: usize,
+		suffix: usize,
+		text: String,
+	},
+}
+
+impl Value {
+	/// Compute a compact binary delta which transforms this value into another value
+	pub fn binary_diff(&self, new: &Value) -> Vec<u8> {
+		let mut out = vec![VERSION];
+		// Serializing a delta into a vector can not fail
+		out.extend(crate::sql::serde::serialize(&delta(self, new)).unwrap_or_default());
+		out
+	}
+
+	/// Apply a delta created with [`Value::binary_diff`] to this value
+	pub fn apply_binary_diff(&self, diff: &[u8]) -> Result<Value, Error> {
+		let delta = match diff.split_first() {
+			Some((&VERSION, rest)) => {
+				crate::sql::serde::deserialize::<Delta>(rest).map_err(|e| Error::InvalidDelta {
+					message: e.to_string(),
+				})?
+			}
+			_ => {
+				return Err(Error::InvalidDelta {
+					message: "Unsupported delta version".to_owned(),
+				})
+			}
+		};
+		apply(self.clone(), delta)
+	}
+}
+
+fn delta(old: &Value, new: &Value) -> Delta {
+	match (old, new) {
+		(Value::Object(a), Value::Object(b)) => {
+			let removed: Vec<String> = a.keys().filter(|k| !b.contains_key(*k)).cloned().collect();
+			let changed: Vec<(String, Delta)> = b
+				.iter()
+				.filter_map(|(k, v)| match a.get(k) {
+					Some(o) => match delta(o, v) {
+						Delta::Same => None,
+						d => Some((k.clone(), d)),
+					},
+					None => Some((k.clone(), Delta::Replace(v.clone()))),
+				})
+				.collect();
+			match removed.is_empty() && changed.is_empty() {
+				true => Delta::Same,
+				false => Delta::Object {
+					removed,
+					changed,
+				},
+			}
+		}
+		(Value::Array(a), Value::Array(b)) => {
+			let changed: Vec<(usize, Delta)> = b
+				.iter()
+				.enumerate()
+				.filter_map(|(i, v)| match a.get(i) {
+					Some(o) => match delta(o, v) {
+						Delta::Same => None,
+						d => Some((i, d)),
+					},
+					None => Some((i, Delta::Replace(v.clone()))),
+				})
+				.collect();
+			match a.len() == b.len() && changed.is_empty() {
+				true => Delta::Same,
+				false => Delta::Array {
+					len: b.len(),
+					changed,
+				},
+			}
+		}
+		(Value::Strand(a), Value::Strand(b)) if a != b => {
+			let (a, b) = (a.as_str(), b.as_str());
+			// Find the common leading bytes
+			let mut prefix = a.bytes().zip(b.bytes()).take_while(|(x, y)| x == y).count();
+			while !b.is_char_boundary(prefix) {
+				prefix -= 1;
+			}
+			// Find the common trailing bytes
+			let max = a.len().min(b.len()) - prefix;
+			let mut suffix =
+				a.bytes().rev().zip(b.bytes().rev()).take(max).take_while(|(x, y)| x == y).count();
+			while !b.is_char_boundary(b.len() - suffix) {
+				suffix -= 1;
+			}
+			match prefix + suffix {
+				0 => Delta::Replace(new.clone()),
+				_ => Delta::Strand {
+					prefix,
+					suffix,
+					text: b[prefix..b.len() - suffix].to_owned(),
+				},
+			}
+		}
+		(a, b) if identical(a, b) => Delta::Same,
+		(_, b) => Delta::Replace(b.clone()),
+	}
+}
+
+/// Check if two values are equal, and have the same representation
+fn identical(a: &Value, b: &Value) -> bool {
+	match (a, b) {
+		(Value::Number(Number::Int(a)), Value::Number(Number::Int(b))) => a == b,
+		(Value::Number(Number::Float(a)), Value::Number(Number::Float(b))) => {
+			a.to_bits() == b.to_bits()
+		}
+		(Value::Number(Number::Decimal(a)), Value::Number(Number::Decimal(b))) => {
+			a == b && a.scale() == b.scale()
+		}
+		(Value::Number(_), Value::Number(_)) => false,
+		(a, b) => a == b,
+	}
+}
+
+fn apply(old: Value, delta: Delta) -> Result<Value, Error> {
+	match (old, delta) {
+		(old, Delta::Same) => Ok(old),
+		(_, Delta::Replace(v)) => Ok(v),
+		(
+			Value::Object(mut obj),
+			Delta::Object {
+				removed,
+				changed,
+			},
+		) => {
+			for k in removed.iter() {
+				obj.remove(k);
+			}
+			for (k, d) in changed {
+				let v = obj.remove(&k).unwrap_or_default();
+				obj.insert(k, apply(v, d)?);
+			}
+			Ok(Value::Object(obj))
+		}
+		(
+			Value::Array(mut arr),
+			Delta::Array {
+				len,
+				changed,
+			},
+		) => {
+			arr.truncate(len);
+			for (i, d) in changed {
+				match i.cmp(&arr.len()) {
+					std::cmp::Ordering::Less => {
+						let v = std::mem::take(&mut arr[i]);
+						arr[i] = apply(v, d)?;
+					}
+					std::cmp::Ordering::Equal => {
+						let v = apply(Value::None, d)?;
+						arr.push(v);
+					}
+					std::cmp::Ordering::Greater => {
+						return Err(Error::InvalidDelta {
+							message: format!("Array index {i} is out of bounds"),
+						})
+					}
+				}
+			}
+			match arr.len() == len {
+				true => Ok(Value::Array(arr)),
+				false => Err(Error::InvalidDelta {
+					message: format!("Expected an array of length {len}"),
+				}),
+			}
+		}
+		(
+			Value::Strand(s),
+			Delta::Strand {
+				prefix,
+				suffix,
+				text,
+			},
+		) => {
+			let s = s.as_str();
+			match (s.get(..prefix), s.len().checked_sub(suffix).and_then(|i| s.get(i..))) {
+				(Some(beg), Some(end)) if prefix + suffix <= s.len() => {
+					Ok(Value::from(format!("{beg}{text}{end}")))
+				}
+				_ => Err(Error::InvalidDelta {
+					message: "String bounds are out of range".to_owned(),
+				}),
+			}
+		}
+		(old, _) => Err(Error::InvalidDelta {
+			message: format!("The delta can not be applied to {old}"),
+		}),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+	use crate::sql::test::Parse;
+
+	#[test]
+	fn binary_diff_round_trip() {
+		let old = Value::parse(
+			"{
+				name: 'Tobie',
+				bio: 'Founder of SurrealDB, based in London',
+				tags: ['one', 'two', 'three'],
+				score: 1,
+				removed: true,
+				created: '2022-07-03T07:18:52Z',
+				owner: person:tobie,
+			}",
+		);
+		let new = Value::parse(
+			"{
+				name: 'Tobie',
+				bio: 'Co-founder of SurrealDB, based in London ✨',
+				tags: ['one', 'deux'],
+				score: 1.0,
+				added: 1h30m,
+				created: '2022-07-03T07:18:52Z',
+				owner: person:jaime,
+			}",
+		);
+		let diff = old.binary_diff(&new);
+		let res = old.apply_binary_diff(&diff).unwrap();
+		assert_eq!(res, new);
+		let Value::Object(res) = res else {
+			panic!("Expected an object");
+		};
+		assert!(matches!(res.get("score"), Some(Value::Number(Number::Float(_)))));
+	}
+
+	#[test]
+	fn binary_diff_is_compact() {
+		let old = Value::Array(Array::from(
+			(0..1000)
+				.map(|i| {
+					let mut obj = Object::default();
+					obj.insert("id".to_owned(), Value::from(i));
+					obj.insert("name".to_owned(), Value::from(format!("Record number {i}")));
+					Value::from(obj)
+				})
+				.collect::<Vec<Value>>(),
+		));
+		let mut new = old.clone();
+		if let Value::Array(arr) = &mut new {
+			arr[10] = Value::parse("{ id: 10, name: 'Changed record' }");
+			arr.push(Value::parse("{ id: 1000, name: 'New record' }"));
+		}
+		let diff = old.binary_diff(&new);
+		assert!(diff.len() < new.to_vec().len() / 10);
+		assert_eq!(old.apply_binary_diff(&diff).unwrap(), new);
+		assert_eq!(new.apply_binary_diff(&new.binary_diff(&old)).unwrap(), old);
+	}
+
+	#[test]
+	fn binary_diff_invalid() {
+		let old = Value::parse("{ test: true }");
+		let diff = old.binary_diff(&Value::parse("{ test: false }"));
+		assert!(matches!(
+			Value::from("test").apply_binary_diff(&diff),
+			Err(Error::InvalidDelta { .. })
+		));
+		assert!(matches!(old.apply_binary_diff(&[0xff]), Err(Error::InvalidDelta { .. })));
+	}
+}
@@ -1,11 +1,18 @@
 use crate::sql::idiom::Idiom;
-use crate::sql::operation::{Op, Operation};
+use crate::sql::operation::{Op, Operation, Patch};
 use crate::sql::value::Value;
-use std::cmp::min;
 
 impl Value {
-	pub(crate) fn diff(&self, val: &Value, path: Idiom) -> Vec<Operation> {
-		let mut ops: Vec<Operation> = vec![];
+	/// Compute the JSON Patch operations which transform this value into another value
+	///
+	/// Applying the returned patch to this value with [`Value::patch`] produces `val`.
+	pub fn diff(&self, val: &Value) -> Patch {
+		let mut ops = vec![];
+		self.diff_path(val, Idiom::default(), &mut ops);
+		Patch(ops)
+	}
+
+	fn diff_path(&self, val: &Value, path: Idiom, ops: &mut Vec<Operation>) {
 		match (self, val) {
 			(Value::Object(a), Value::Object(b)) if a != b => {
 				// Loop over old keys
@@ -28,37 +35,44 @@ impl Value {
 						}),
 						Some(old) => {
 							let path = path.clone().push(key.clone().into());
-							ops.append(&mut old.diff(val, path))
+							old.diff_path(val, path, ops)
 						}
 					}
 				}
 			}
 			(Value::Array(a), Value::Array(b)) if a != b => {
-				let mut n = 0;
-				while n < min(a.len(), b.len()) {
-					let path = path.clone().push(n.into());
-					ops.append(&mut a[n].diff(&b[n], path));
-					n += 1;
+				// Skip the elements which are unchanged at the start and end
+				let pre = a.iter().zip(b.iter()).take_while(|(a, b)| a == b).count();
+				let max = a.len().min(b.len()) - pre;
+				let suf = a
+					.iter()
+					.rev()
+					.zip(b.iter().rev())
+					.take(max)
+					.take_while(|(a, b)| a == b)
+					.count();
+				let (a, b) = (&a[pre..a.len() - suf], &b[pre..b.len() - suf]);
+				let len = a.len().min(b.len());
+				// Diff the elements which exist in both arrays
+				for (n, (a, b)) in a.iter().zip(b.iter()).enumerate() {
+					let path = path.clone().push((pre + n).into());
+					a.diff_path(b, path, ops);
 				}
-				while n < b.len() {
-					if n >= a.len() {
-						ops.push(Operation {
-							op: Op::Add,
-							path: path.clone().push(n.into()),
-							value: b[n].clone(),
-						})
-					}
-					n += 1;
+				// Insert any new elements in order
+				for (n, v) in b.iter().enumerate().skip(len) {
+					ops.push(Operation {
+						op: Op::Add,
+						path: path.clone().push((pre + n).into()),
+						value: v.clone(),
+					})
 				}
-				while n < a.len() {
-					if n >= b.len() {
-						ops.push(Operation {
-							op: Op::Remove,
-							path: path.clone().push(n.into()),
-							value: Value::Null,
-						})
-					}
-					n += 1;
+				// Remove any old elements from the end first
+				for n in (len..a.len()).rev() {
+					ops.push(Operation {
+						op: Op::Remove,
+						path: path.clone().push((pre + n).into()),
+						value: Value::Null,
+					})
 				}
 			}
 			(Value::Strand(a), Value::Strand(b)) if a != b => ops.push(Operation {
@@ -78,7 +92,6 @@ impl Value {
 			}),
 			(_, _) => (),
 		}
-		ops
 	}
 }
 
@@ -93,7 +106,7 @@ mod tests {
 		let old = Value::parse("{ test: true, text: 'text', other: { something: true } }");
 		let now = Value::parse("{ test: true, text: 'text', other: { something: true } }");
 		let res = Value::parse("[]");
-		assert_eq!(res.to_operations().unwrap(), old.diff(&now, Idiom::default()));
+		assert_eq!(Patch(res.to_operations().unwrap()), old.diff(&now));
 	}
 
 	#[test]
@@ -101,7 +114,7 @@ mod tests {
 		let old = Value::parse("{ test: true }");
 		let now = Value::parse("{ test: true, other: 'test' }");
 		let res = Value::parse("[{ op: 'add', path: '/other', value: 'test' }]");
-		assert_eq!(res.to_operations().unwrap(), old.diff(&now, Idiom::default()));
+		assert_eq!(Patch(res.to_operations().unwrap()), old.diff(&now));
 	}
 
 	#[test]
@@ -109,7 +122,7 @@ mod tests {
 		let old = Value::parse("{ test: true, other: 'test' }");
 		let now = Value::parse("{ test: true }");
 		let res = Value::parse("[{ op: 'remove', path: '/other' }]");
-		assert_eq!(res.to_operations().unwrap(), old.diff(&now, Idiom::default()));
+		assert_eq!(Patch(res.to_operations().unwrap()), old.diff(&now));
 	}
 
 	#[test]
@@ -117,7 +130,7 @@ mod tests {
 		let old = Value::parse("{ test: [1,2,3] }");
 		let now = Value::parse("{ test: [1,2,3,4] }");
 		let res = Value::parse("[{ op: 'add', path: '/test/3', value: 4 }]");
-		assert_eq!(res.to_operations().unwrap(), old.diff(&now, Idiom::default()));
+		assert_eq!(Patch(res.to_operations().unwrap()), old.diff(&now));
 	}
 
 	#[test]
@@ -125,7 +138,7 @@ mod tests {
 		let old = Value::parse("{ test: { other: 'test' } }");
 		let now = Value::parse("{ test: { other: false } }");
 		let res = Value::parse("[{ op: 'replace', path: '/test/other', value: false }]");
-		assert_eq!(res.to_operations().unwrap(), old.diff(&now, Idiom::default()));
+		assert_eq!(Patch(res.to_operations().unwrap()), old.diff(&now));
 	}
 
 	#[test]
@@ -135,6 +148,46 @@ mod tests {
 		let res = Value::parse(
 			"[{ op: 'change', path: '/test/other', value: '@@ -1,4 +1,4 @@\n te\n-s\n+x\n t\n' }]",
 		);
-		assert_eq!(res.to_operations().unwrap(), old.diff(&now, Idiom::default()));
+		assert_eq!(Patch(res.to_operations().unwrap()), old.diff(&now));
+	}
+
+	#[test]
+	fn diff_remove_array() {
+		let old = Value::parse("{ test: [1,2,3,4] }");
+		let now = Value::parse("{ test: [1,4] }");
+		let res =
+			Value::parse("[{ op: 'remove', path: '/test/2' }, { op: 'remove', path: '/test/1' }]");
+		assert_eq!(Patch(res.to_operations().unwrap()), old.diff(&now));
+	}
+
+	#[test]
+	fn diff_insert_array() {
+		let old = Value::parse("{ test: [1,2,3] }");
+		let now = Value::parse("{ test: [1,4,5,2,3] }");
+		let res = Value::parse(
+			"[{ op: 'add', path: '/test/1', value: 4 }, { op: 'add', path: '/test/2', value: 5 }]",
+		);
+		assert_eq!(Patch(res.to_operations().unwrap()), old.diff(&now));
+		let mut val = old.clone();
+		val.patch(&old.diff(&now)).unwrap();
+		assert_eq!(val, now);
+	}
+
+	#[test]
+	fn diff_patch_ratings() {
+		let old = Value::parse("[{ rating: 6.3 }, { rating: 8.7 }]");
+		let now = Value::parse("[{ rating: 87 }]");
+		let res = Value::parse(
+			"[{ op: 'replace', path: '/0/rating', value: 87 }, { op: 'remove', path: '/1' }]",
+		);
+		let pch = old.diff(&now);
+		assert_eq!(Patch(res.to_operations().unwrap()), pch);
+		let mut val = old.clone();
+		val.patch(&pch).unwrap();
+		assert_eq!(val, now);
+		// The reverse patch restores the original value
+		let mut val = now.clone();
+		val.patch(&now.diff(&old)).unwrap();
+		assert_eq!(val, old);
 	}
 }
@@ -3,6 +3,7 @@ use crate::dbs::{Options, Transaction};
 use crate::doc::CursorDoc;
 use crate::err::Error;
 use crate::exe::try_join_all_buffered;
+use crate::fnc;
 use crate::sql::edges::Edges;
 use crate::sql::field::{Field, Fields};
 use crate::sql::id::Id;
@@ -16,9 +17,65 @@ use async_recursion::async_recursion;
 
 impl Value {
 	/// Asynchronous method for getting a local or remote field from a `Value`
+	pub(crate) async fn get(
+		&self,
+		ctx: &Context<'_>,
+		opt: &Options,
+		txn: &Transaction,
+		doc: Option<&CursorDoc<'_>>,
+		path: &[Part],
+	) -> Result<Self, Error> {
+		// Methods are applied to the value collected by the preceding parts
+		let mut val: Option<Value> = None;
+		let mut path = path;
+		while let Some(i) = path.iter().position(|p| matches!(p, Part::Method(..))) {
+			let cur = val.as_ref().unwrap_or(self).fetch(ctx, opt, txn, doc, &path[..i]).await?;
+			if let Part::Method(name, args) = &path[i] {
+				val = Some(cur.method(ctx, opt, txn, doc, name, args).await?);
+			}
+			path = &path[i + 1..];
+		}
+		val.as_ref().unwrap_or(self).fetch(ctx, opt, txn, doc, path).await
+	}
+
+	/// Apply an idiom method to this `Value`
+	async fn method(
+		&self,
+		ctx: &Context<'_>,
+		opt: &Options,
+		txn: &Transaction,
+		doc: Option<&CursorDoc<'_>>,
+		name: &str,
+		args: &[Value],
+	) -> Result<Self, Error> {
+		match (self, name) {
+			(Value::Array(_), "flatten") => {
+				let mut a = vec![self.clone()];
+				for v in args.iter() {
+					a.push(v.compute(ctx, opt, txn, doc).await?);
+				}
+				fnc::synchronous(ctx, "array::flatten", a)
+			}
+			// Missing values pass through like any other missing field
+			(Value::None | Value::Null, "flatten") => Ok(Value::None),
+			(Value::Array(_), _) => Err(Error::InvalidFunction {
+				name: name.to_owned(),
+				message: String::from("This method is not supported."),
+			}),
+			_ => Err(Error::InvalidFunction {
+				name: name.to_owned(),
+				message: format!(
+					"This method can not be used on a value of type {}.",
+					self.kindof()
+				),
+			}),
+		}
+	}
+
+	/// Get a local or remote field from a `Value`, along a path without any methods
 	#[cfg_attr(not(target_arch = "wasm32"), async_recursion)]
 	#[cfg_attr(target_arch = "wasm32", async_recursion(?Send))]
-	pub(crate) async fn get(
+	async fn fetch(
 		&self,
 		ctx: &Context<'_>,
 		opt: &Options,
@@ -33,15 +90,15 @@ impl Value {
 				Value::Geometry(v) => match p {
 					// If this is the 'type' field then continue
 					Part::Field(f) if f.is_type() => {
-						Value::from(v.as_type()).get(ctx, opt, txn, doc, path.next()).await
+						Value::from(v.as_type()).fetch(ctx, opt, txn, doc, path.next()).await
 					}
 					// If this is the 'coordinates' field then continue
 					Part::Field(f) if f.is_coordinates() && v.is_geometry() => {
-						v.as_coordinates().get(ctx, opt, txn, doc, path.next()).await
+						v.as_coordinates().fetch(ctx, opt, txn, doc, path.next()).await
 					}
 					// If this is the 'geometries' field then continue
 					Part::Field(f) if f.is_geometries() && v.is_collection() => {
-						v.as_coordinates().get(ctx, opt, txn, doc, path.next()).await
+						v.as_coordinates().fetch(ctx, opt, txn, doc, path.next()).await
 					}
 					// Otherwise return none
 					_ => Ok(Value::None),
@@ -59,7 +116,7 @@ impl Value {
 							// Get the future return value
 							let val = v.compute(ctx, fut, txn, doc).await?;
 							// Fetch the embedded field
-							val.get(ctx, opt, txn, doc, path).await
+							val.fetch(ctx, opt, txn, doc, path).await
 						}
 					}
 				}
@@ -70,34 +127,34 @@ impl Value {
 						Some(Value::Thing(Thing {
 							id: Id::Object(v),
 							..
-						})) => Value::Object(v.clone()).get(ctx, opt, txn, doc, path.next()).await,
+						})) => Value::Object(v.clone()).fetch(ctx, opt, txn, doc, path.next()).await,
 						Some(Value::Thing(Thing {
 							id: Id::Array(v),
 							..
-						})) => Value::Array(v.clone()).get(ctx, opt, txn, doc, path.next()).await,
-						Some(v) => v.get(ctx, opt, txn, doc, path.next()).await,
+						})) => Value::Array(v.clone()).fetch(ctx, opt, txn, doc, path.next()).await,
+						Some(v) => v.fetch(ctx, opt, txn, doc, path.next()).await,
 						None => Ok(Value::None),
 					},
 					Part::Graph(_) => match v.rid() {
-						Some(v) => Value::Thing(v).get(ctx, opt, txn, doc, path).await,
+						Some(v) => Value::Thing(v).fetch(ctx, opt, txn, doc, path).await,
 						None => Ok(Value::None),
 					},
 					Part::Field(f) => match v.get(f.as_str()) {
-						Some(v) => v.get(ctx, opt, txn, doc, path.next()).await,
+						Some(v) => v.fetch(ctx, opt, txn, doc, path.next()).await,
 						None => Ok(Value::None),
 					},
 					Part::Index(i) => match v.get(&i.to_string()) {
-						Some(v) => v.get(ctx, opt, txn, doc, path.next()).await,
+						Some(v) => v.fetch(ctx, opt, txn, doc, path.next()).await,
 						None => Ok(Value::None),
 					},
 					Part::Value(x) => match x.compute(ctx, opt, txn, doc).await? {
 						Value::Strand(f) => match v.get(f.as_str()) {
-							Some(v) => v.get(ctx, opt, txn, doc, path.next()).await,
+							Some(v) => v.fetch(ctx, opt, txn, doc, path.next()).await,
 							None => Ok(Value::None),
 						},
 						_ => Ok(Value::None),
 					},
-					Part::All => self.get(ctx, opt, txn, doc, path.next()).await,
+					Part::All => self.fetch(ctx, opt, txn, doc, path.next()).await,
 					_ => Ok(Value::None),
 				},
 				// Current value at path is an array
@@ -105,19 +162,19 @@ impl Value {
 					// Current path is an `*` part
 					Part::All => {
 						let path = path.next();
-						let futs = v.iter().map(|v| v.get(ctx, opt, txn, doc, path));
+						let futs = v.iter().map(|v| v.fetch(ctx, opt, txn, doc, path));
 						try_join_all_buffered(futs).await.map(Into::into)
 					}
 					Part::First => match v.first() {
-						Some(v) => v.get(ctx, opt, txn, doc, path.next()).await,
+						Some(v) => v.fetch(ctx, opt, txn, doc, path.next()).await,
 						None => Ok(Value::None),
 					},
 					Part::Last => match v.last() {
-						Some(v) => v.get(ctx, opt, txn, doc, path.next()).await,
+						Some(v) => v.fetch(ctx, opt, txn, doc, path.next()).await,
 						None => Ok(Value::None),
 					},
 					Part::Index(i) => match v.get(i.to_usize()) {
-						Some(v) => v.get(ctx, opt, txn, doc, path.next()).await,
+						Some(v) => v.fetch(ctx, opt, txn, doc, path.next()).await,
 						None => Ok(Value::None),
 					},
 					Part::Where(w) => {
@@ -128,17 +185,17 @@ impl Value {
 								a.push(v.clone());
 							}
 						}
-						Value::from(a).get(ctx, opt, txn, doc, path.next()).await
+						Value::from(a).fetch(ctx, opt, txn, doc, path.next()).await
 					}
 					Part::Value(x) => match x.compute(ctx, opt, txn, doc).await? {
 						Value::Number(i) => match v.get(i.to_usize()) {
-							Some(v) => v.get(ctx, opt, txn, doc, path.next()).await,
+							Some(v) => v.fetch(ctx, opt, txn, doc, path.next()).await,
 							None => Ok(Value::None),
 						},
 						_ => Ok(Value::None),
 					},
 					_ => {
-						let futs = v.iter().map(|v| v.get(ctx, opt, txn, doc, path));
+						let futs = v.iter().map(|v| v.fetch(ctx, opt, txn, doc, path));
 						try_join_all_buffered(futs).await.map(Into::into)
 					}
 				},
@@ -160,7 +217,7 @@ impl Value {
 							stm.compute(ctx, opt, txn, None)
 								.await?
 								.first()
-								.get(ctx, opt, txn, None, path)
+								.fetch(ctx, opt, txn, None, path)
 								.await
 						}
 					}
@@ -192,7 +249,7 @@ impl Value {
 										.compute(ctx, opt, txn, None)
 										.await?
 										.all()
-										.get(ctx, opt, txn, None, ID.as_ref())
+										.fetch(ctx, opt, txn, None, ID.as_ref())
 										.await?
 										.flatten()
 										.ok(),
@@ -200,7 +257,7 @@ impl Value {
 										.compute(ctx, opt, txn, None)
 										.await?
 										.all()
-										.get(ctx, opt, txn, None, path.next())
+										.fetch(ctx, opt, txn, None, path.next())
 										.await?
 										.flatten()
 										.ok(),
@@ -216,7 +273,7 @@ impl Value {
 								stm.compute(ctx, opt, txn, None)
 									.await?
 									.first()
-									.get(ctx, opt, txn, None, path)
+									.fetch(ctx, opt, txn, None, path)
 									.await
 							}
 						},
@@ -283,6 +340,39 @@ mod tests {
 		assert_eq!(res, Value::from(456));
 	}
 
+	#[tokio::test]
+	async fn get_array_flatten() {
+		let (ctx, opt, txn) = mock().await;
+		let idi = Idiom::parse("test.*.something.flatten()");
+		let val = Value::parse("{ test: [{ something: [1, [2]] }, { something: [3] }] }");
+		let res = val.get(&ctx, &opt, &txn, None, &idi).await.unwrap();
+		assert_eq!(res, Value::parse("[1, [2], 3]"));
+	}
+
+	#[tokio::test]
+	async fn get_method_unsupported_receiver() {
+		let (ctx, opt, txn) = mock().await;
+		let idi = Idiom::parse("test.something.flatten()");
+		let val = Value::parse("{ test: { something: 'text', other: 123 } }");
+		let res = val.get(&ctx, &opt, &txn, None, &idi).await;
+		assert!(matches!(res, Err(Error::InvalidFunction { .. })));
+		let idi = Idiom::parse("test.other.flatten()");
+		let res = val.get(&ctx, &opt, &txn, None, &idi).await;
+		assert!(matches!(res, Err(Error::InvalidFunction { .. })));
+	}
+
+	#[tokio::test]
+	async fn get_method_missing_receiver() {
+		let (ctx, opt, txn) = mock().await;
+		let val = Value::parse("{ test: { something: null } }");
+		let idi = Idiom::parse("test.something.flatten()");
+		let res = val.get(&ctx, &opt, &txn, None, &idi).await.unwrap();
+		assert_eq!(res, Value::None);
+		let idi = Idiom::parse("test.other.flatten()");
+		let res = val.get(&ctx, &opt, &txn, None, &idi).await.unwrap();
+		assert_eq!(res, Value::None);
+	}
+
 	#[tokio::test]
 	async fn get_array_thing() {
 		let (ctx, opt, txn) = mock().await;
@@ -1,3 +1,4 @@
+pub use self::coerce_numbers::NumberMode;
 pub use self::value::*;
 
 pub(super) mod serde;
@@ -6,13 +7,16 @@ pub(super) mod serde;
 mod value;
 
 mod all;
+mod canonical;
 mod changed;
 mod clear;
+mod coerce_numbers;
 mod compare;
 mod cut;
 mod decrement;
 mod def;
 mod del;
+mod delta;
 mod diff;
 mod each;
 mod every;
@@ -27,9 +31,14 @@ mod increment;
 mod last;
 mod merge;
 mod patch;
+mod path;
 mod pick;
 mod put;
 mod replace;
 mod rid;
 mod set;
+mod visit;
 mod walk;
+mod yaml;
+
+pub(crate) use self::yaml::yaml;
@@ -1,22 +1,28 @@
 use crate::err::Error;
-use crate::sql::operation::Op;
+use crate::sql::operation::{Op, Patch};
+use crate::sql::part::Part;
 use crate::sql::value::Value;
 
 impl Value {
-	pub(crate) fn patch(&mut self, val: Value) -> Result<(), Error> {
-		for o in val.to_operations()?.into_iter() {
+	/// Apply a list of JSON Patch operations to this value
+	pub fn patch(&mut self, patch: &Patch) -> Result<(), Error> {
+		for o in patch.iter() {
 			match o.op {
-				Op::Add => match self.pick(&o.path) {
-					Value::Array(_) => self.inc(&o.path, o.value),
-					_ => self.put(&o.path, o.value),
-				},
+				Op::Add => {
+					if !self.insert(&o.path, &o.value) {
+						match self.pick(&o.path) {
+							Value::Array(_) => self.inc(&o.path, o.value.clone()),
+							_ => self.put(&o.path, o.value.clone()),
+						}
+					}
+				}
 				Op::Remove => self.cut(&o.path),
-				Op::Replace => self.put(&o.path, o.value),
+				Op::Replace => self.put(&o.path, o.value.clone()),
 				Op::Change => {
-					if let Value::Strand(p) = o.value {
+					if let Value::Strand(p) = &o.value {
 						if let Value::Strand(v) = self.pick(&o.path) {
 							let dmp = dmp::new();
-							let pch = dmp.patch_from_text(p.as_string()).map_err(|e| {
+							let pch = dmp.patch_from_text(p.as_str().to_owned()).map_err(|e| {
 								Error::InvalidPatch {
 									message: format!("{e:?}"),
 								}
@@ -36,6 +42,23 @@ impl Value {
 		}
 		Ok(())
 	}
+
+	/// Insert a value at a position within an array, shifting any later elements
+	///
+	/// Returns `false` if the path does not point to a position within an array.
+	fn insert(&mut self, path: &[Part], val: &Value) -> bool {
+		match path.split_last() {
+			Some((Part::Index(i), parent)) => match self.pick(parent) {
+				Value::Array(mut v) if i.to_usize() <= v.len() => {
+					v.insert(i.to_usize(), val.clone());
+					self.put(parent, Value::from(v));
+					true
+				}
+				_ => false,
+			},
+			_ => false,
+		}
+	}
 }
 
 #[cfg(test)]
@@ -49,7 +72,7 @@ mod tests {
 		let mut val = Value::parse("{ test: { other: null, something: 123 } }");
 		let ops = Value::parse("[{ op: 'add', path: '/temp', value: true }]");
 		let res = Value::parse("{ test: { other: null, something: 123 }, temp: true }");
-		val.patch(ops).unwrap();
+		val.patch(&Patch(ops.to_operations().unwrap())).unwrap();
 		assert_eq!(res, val);
 	}
 
@@ -58,7 +81,7 @@ mod tests {
 		let mut val = Value::parse("{ test: { other: null, something: 123 }, temp: true }");
 		let ops = Value::parse("[{ op: 'remove', path: '/temp' }]");
 		let res = Value::parse("{ test: { other: null, something: 123 } }");
-		val.patch(ops).unwrap();
+		val.patch(&Patch(ops.to_operations().unwrap())).unwrap();
 		assert_eq!(res, val);
 	}
 
@@ -67,7 +90,7 @@ mod tests {
 		let mut val = Value::parse("{ test: { other: null, something: 123 }, temp: true }");
 		let ops = Value::parse("[{ op: 'replace', path: '/temp', value: 'text' }]");
 		let res = Value::parse("{ test: { other: null, something: 123 }, temp: 'text' }");
-		val.patch(ops).unwrap();
+		val.patch(&Patch(ops.to_operations().unwrap())).unwrap();
 		assert_eq!(res, val);
 	}
 
@@ -78,7 +101,7 @@ mod tests {
 			"[{ op: 'change', path: '/temp', value: '@@ -1,4 +1,4 @@\n te\n-s\n+x\n t\n' }]",
 		);
 		let res = Value::parse("{ test: { other: null, something: 123 }, temp: 'text' }");
-		val.patch(ops).unwrap();
+		val.patch(&Patch(ops.to_operations().unwrap())).unwrap();
 		assert_eq!(res, val);
 	}
 
@@ -87,7 +110,7 @@ mod tests {
 		let mut val = Value::parse("{ test: { other: null, something: 123 } }");
 		let ops = Value::parse("[{ op: 'add', path: '/temp/test', value: true }]");
 		let res = Value::parse("{ test: { other: null, something: 123 }, temp: { test: true } }");
-		val.patch(ops).unwrap();
+		val.patch(&Patch(ops.to_operations().unwrap())).unwrap();
 		assert_eq!(res, val);
 	}
 
@@ -96,7 +119,7 @@ mod tests {
 		let mut val = Value::parse("{ test: { other: null, something: 123 }, temp: true }");
 		let ops = Value::parse("[{ op: 'remove', path: '/test/other' }]");
 		let res = Value::parse("{ test: { something: 123 }, temp: true }");
-		val.patch(ops).unwrap();
+		val.patch(&Patch(ops.to_operations().unwrap())).unwrap();
 		assert_eq!(res, val);
 	}
 
@@ -105,7 +128,7 @@ mod tests {
 		let mut val = Value::parse("{ test: { other: null, something: 123 }, temp: true }");
 		let ops = Value::parse("[{ op: 'replace', path: '/test/other', value: 'text' }]");
 		let res = Value::parse("{ test: { other: 'text', something: 123 }, temp: true }");
-		val.patch(ops).unwrap();
+		val.patch(&Patch(ops.to_operations().unwrap())).unwrap();
 		assert_eq!(res, val);
 	}
 
@@ -116,7 +139,7 @@ mod tests {
 			"[{ op: 'change', path: '/test/other', value: '@@ -1,4 +1,4 @@\n te\n-s\n+x\n t\n' }]",
 		);
 		let res = Value::parse("{ test: { other: 'text', something: 123 }, temp: true }");
-		val.patch(ops).unwrap();
+		val.patch(&Patch(ops.to_operations().unwrap())).unwrap();
 		assert_eq!(res, val);
 	}
 
@@ -125,6 +148,6 @@ mod tests {
 		// See https://github.com/surrealdb/surrealdb/issues/2001
 		let mut val = Value::parse("{ test: { other: 'test', something: 123 }, temp: true }");
 		let ops = Value::parse("[{ op: 'change', path: '/test/other', value: 'text' }]");
-		assert!(val.patch(ops).is_err());
+		assert!(val.patch(&Patch(ops.to_operations().unwrap())).is_err());
 	}
 }
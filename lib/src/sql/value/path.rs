@@ -0,0 +1,85 @@
+use crate::err::Error;
+use crate::sql::part::Part;
+use crate::sql::value::Value;
+
+impl Value {
+	/// Retrieve a reference to the value at the specified idiom, such as `ratings[1].rating`
+	///
+	/// Returns `None` if the idiom can not be parsed, or if there is no value at the path.
+	pub fn get_path(&self, idiom: &str) -> Option<&Value> {
+		let idiom = crate::sql::idiom(idiom).ok()?;
+		idiom.iter().try_fold(self, |v, p| match (v, p) {
+			(Value::Object(v), Part::Field(f)) => v.get(f.as_str()),
+			(Value::Object(v), Part::Index(i)) => v.get(&i.to_string()),
+			(Value::Array(v), Part::Index(i)) => v.get(i.to_usize()),
+			(Value::Array(v), Part::First) => v.first(),
+			(Value::Array(v), Part::Last) => v.last(),
+			_ => None,
+		})
+	}
+	/// Set the value at the specified idiom, such as `ratings[1].rating`
+	///
+	/// Any missing intermediate objects along the path are created. An error
+	/// is returned if the idiom can not be parsed.
+	pub fn set_path(&mut self, idiom: &str, val: Value) -> Result<(), Error> {
+		let idiom = crate::sql::idiom(idiom)?;
+		self.put(&idiom, val);
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+	use crate::sql::test::Parse;
+
+	fn value() -> Value {
+		Value::parse(
+			"{
+				id: film:test,
+				ratings: [
+					{ rating: 6.3 },
+					{ rating: 8.7 },
+				],
+			}",
+		)
+	}
+
+	#[test]
+	fn get_path_nested() {
+		let val = value();
+		assert_eq!(val.get_path("ratings[1].rating"), Some(&Value::from(8.7)));
+		assert_eq!(val.get_path("ratings[$].rating"), Some(&Value::from(8.7)));
+		assert_eq!(val.get_path("ratings[0]"), Some(&Value::parse("{ rating: 6.3 }")));
+		assert_eq!(val.get_path("id"), Some(&Value::parse("film:test")));
+	}
+
+	#[test]
+	fn get_path_missing() {
+		let val = value();
+		assert_eq!(val.get_path("ratings[2].rating"), None);
+		assert_eq!(val.get_path("ratings[0].score"), None);
+		assert_eq!(val.get_path("name.first"), None);
+		assert_eq!(val.get_path("ratings["), None);
+	}
+
+	#[test]
+	fn set_path_nested() {
+		let mut val = value();
+		val.set_path("ratings[1].rating", Value::from(9.1)).unwrap();
+		val.set_path("details.director.name", Value::from("Tobie")).unwrap();
+		let res = Value::parse(
+			"{
+				id: film:test,
+				details: { director: { name: 'Tobie' } },
+				ratings: [
+					{ rating: 6.3 },
+					{ rating: 9.1 },
+				],
+			}",
+		);
+		assert_eq!(val, res);
+		assert!(val.set_path("ratings[", Value::None).is_err());
+	}
+}
@@ -36,11 +36,13 @@ impl ser::Serializer for Serializer {
 	{
 		match variant {
 			"Value" => Ok(Entry::Value(value.serialize(ser::value::Serializer.wrap())?)),
-			"Set" => Ok(Entry::Set(value.serialize(ser::statement::set::Serializer.wrap())?)),
+			"Set" | "Destructure" => {
+				Ok(Entry::Set(value.serialize(ser::statement::set::Serializer.wrap())?))
+			}
 			"Ifelse" => {
 				Ok(Entry::Ifelse(value.serialize(ser::statement::ifelse::Serializer.wrap())?))
 			}
-			"Select" => {
+			"Select" | "SelectDistinct" => {
 				Ok(Entry::Select(value.serialize(ser::statement::select::Serializer.wrap())?))
 			}
 			"Create" => {
@@ -4,6 +4,7 @@ use crate::err::Error;
 use crate::sql::value::serde::ser;
 use crate::sql::Ident;
 use crate::sql::Part;
+use crate::sql::Value;
 use serde::ser::Error as _;
 use serde::ser::Impossible;
 use serde::ser::Serialize;
@@ -17,7 +18,7 @@ impl ser::Serializer for Serializer {
 	type SerializeSeq = Impossible<Part, Error>;
 	type SerializeTuple = Impossible<Part, Error>;
 	type SerializeTupleStruct = Impossible<Part, Error>;
-	type SerializeTupleVariant = Impossible<Part, Error>;
+	type SerializeTupleVariant = SerializeMethod;
 	type SerializeMap = Impossible<Part, Error>;
 	type SerializeStruct = Impossible<Part, Error>;
 	type SerializeStructVariant = Impossible<Part, Error>;
@@ -62,6 +63,57 @@ impl ser::Serializer for Serializer {
 			}
 		}
 	}
+
+	fn serialize_tuple_variant(
+		self,
+		name: &'static str,
+		_variant_index: u32,
+		variant: &'static str,
+		_len: usize,
+	) -> Result<Self::SerializeTupleVariant, Self::Error> {
+		match variant {
+			"Method" => Ok(SerializeMethod::default()),
+			variant => Err(Error::custom(format!("unexpected tuple variant `{name}::{variant}`"))),
+		}
+	}
+}
+
+#[derive(Default)]
+pub(super) struct SerializeMethod {
+	index: usize,
+	name: Option<String>,
+	args: Option<Vec<Value>>,
+}
+
+impl serde::ser::SerializeTupleVariant for SerializeMethod {
+	type Ok = Part;
+	type Error = Error;
+
+	fn serialize_field<T>(&mut self, value: &T) -> Result<(), Self::Error>
+	where
+		T: Serialize + ?Sized,
+	{
+		match self.index {
+			0 => {
+				self.name = Some(value.serialize(ser::string::Serializer.wrap())?);
+			}
+			1 => {
+				self.args = Some(value.serialize(ser::value::vec::Serializer.wrap())?);
+			}
+			index => {
+				return Err(Error::custom(format!("unexpected `Part::Method` index `{index}`")));
+			}
+		}
+		self.index += 1;
+		Ok(())
+	}
+
+	fn end(self) -> Result<Self::Ok, Self::Error> {
+		match (self.name, self.args) {
+			(Some(name), Some(args)) => Ok(Part::Method(name, args)),
+			_ => Err(Error::custom("`Part::Method` missing required value(s)")),
+		}
+	}
 }
 
 #[cfg(test)]
@@ -133,4 +185,11 @@ mod tests {
 		let serialized = part.serialize(Serializer.wrap()).unwrap();
 		assert_eq!(part, serialized);
 	}
+
+	#[test]
+	fn method() {
+		let part = Part::Method(String::from("flatten"), vec![Value::from(2)]);
+		let serialized = part.serialize(Serializer.wrap()).unwrap();
+		assert_eq!(part, serialized);
+	}
 }
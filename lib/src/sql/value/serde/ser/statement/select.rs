@@ -61,6 +61,7 @@ pub struct SerializeSelectStatement {
 	timeout: Option<Timeout>,
 	parallel: Option<bool>,
 	explain: Option<Explain>,
+	distinct: Option<bool>,
 }
 
 impl serde::ser::SerializeStruct for SerializeSelectStatement {
@@ -114,6 +115,9 @@ impl serde::ser::SerializeStruct for SerializeSelectStatement {
 			"explain" => {
 				self.explain = value.serialize(ser::explain::opt::Serializer.wrap())?;
 			}
+			"distinct" => {
+				self.distinct = Some(value.serialize(ser::primitive::bool::Serializer.wrap())?);
+			}
 			key => {
 				return Err(Error::custom(format!("unexpected field `SelectStatement::{key}`")));
 			}
@@ -138,6 +142,7 @@ impl serde::ser::SerializeStruct for SerializeSelectStatement {
 				fetch: self.fetch,
 				version: self.version,
 				timeout: self.timeout,
+				distinct: self.distinct.unwrap_or_default(),
 			}),
 			_ => Err(Error::custom("`SelectStatement` missing required field(s)")),
 		}
@@ -284,4 +289,14 @@ mod tests {
 		let value: SelectStatement = stmt.serialize(Serializer.wrap()).unwrap();
 		assert_eq!(value, stmt);
 	}
+
+	#[test]
+	fn with_distinct() {
+		let stmt = SelectStatement {
+			distinct: true,
+			..Default::default()
+		};
+		let value: SelectStatement = stmt.serialize(Serializer.wrap()).unwrap();
+		assert_eq!(value, stmt);
+	}
 }
@@ -1,4 +1,5 @@
 use crate::err::Error;
+use crate::sql::statements::Destructure;
 use crate::sql::statements::SetStatement;
 use crate::sql::value::serde::ser;
 use crate::sql::Value;
@@ -37,6 +38,7 @@ impl ser::Serializer for Serializer {
 pub struct SerializeSetStatement {
 	name: Option<String>,
 	what: Option<Value>,
+	destructure: Option<Destructure>,
 }
 
 impl serde::ser::SerializeStruct for SerializeSetStatement {
@@ -54,6 +56,9 @@ impl serde::ser::SerializeStruct for SerializeSetStatement {
 			"what" => {
 				self.what = Some(value.serialize(ser::value::Serializer.wrap())?);
 			}
+			"destructure" => {
+				self.destructure = value.serialize(DestructureOptSerializer.wrap())?;
+			}
 			key => {
 				return Err(Error::custom(format!("unexpected field `SetStatement::{key}`")));
 			}
@@ -66,12 +71,84 @@ impl serde::ser::SerializeStruct for SerializeSetStatement {
 			(Some(name), Some(what)) => Ok(SetStatement {
 				name,
 				what,
+				destructure: self.destructure,
 			}),
 			_ => Err(Error::custom("`SetStatement` missing required field(s)")),
 		}
 	}
 }
 
+struct DestructureOptSerializer;
+
+impl ser::Serializer for DestructureOptSerializer {
+	type Ok = Option<Destructure>;
+	type Error = Error;
+
+	type SerializeSeq = Impossible<Option<Destructure>, Error>;
+	type SerializeTuple = Impossible<Option<Destructure>, Error>;
+	type SerializeTupleStruct = Impossible<Option<Destructure>, Error>;
+	type SerializeTupleVariant = Impossible<Option<Destructure>, Error>;
+	type SerializeMap = Impossible<Option<Destructure>, Error>;
+	type SerializeStruct = Impossible<Option<Destructure>, Error>;
+	type SerializeStructVariant = Impossible<Option<Destructure>, Error>;
+
+	const EXPECTED: &'static str = "an `Option<Destructure>`";
+
+	#[inline]
+	fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+		Ok(None)
+	}
+
+	#[inline]
+	fn serialize_some<T>(self, value: &T) -> Result<Self::Ok, Self::Error>
+	where
+		T: ?Sized + Serialize,
+	{
+		Ok(Some(value.serialize(DestructureSerializer.wrap())?))
+	}
+}
+
+struct DestructureSerializer;
+
+impl ser::Serializer for DestructureSerializer {
+	type Ok = Destructure;
+	type Error = Error;
+
+	type SerializeSeq = Impossible<Destructure, Error>;
+	type SerializeTuple = Impossible<Destructure, Error>;
+	type SerializeTupleStruct = Impossible<Destructure, Error>;
+	type SerializeTupleVariant = Impossible<Destructure, Error>;
+	type SerializeMap = Impossible<Destructure, Error>;
+	type SerializeStruct = Impossible<Destructure, Error>;
+	type SerializeStructVariant = Impossible<Destructure, Error>;
+
+	const EXPECTED: &'static str = "an enum `Destructure`";
+
+	#[inline]
+	fn serialize_newtype_variant<T>(
+		self,
+		name: &'static str,
+		_variant_index: u32,
+		variant: &'static str,
+		value: &T,
+	) -> Result<Self::Ok, Error>
+	where
+		T: ?Sized + Serialize,
+	{
+		match variant {
+			"Object" => {
+				Ok(Destructure::Object(value.serialize(ser::string::vec::Serializer.wrap())?))
+			}
+			"Array" => {
+				Ok(Destructure::Array(value.serialize(ser::string::vec::Serializer.wrap())?))
+			}
+			variant => {
+				Err(Error::custom(format!("unexpected newtype variant `{name}::{variant}`")))
+			}
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -82,4 +159,14 @@ mod tests {
 		let value: SetStatement = stmt.serialize(Serializer.wrap()).unwrap();
 		assert_eq!(value, stmt);
 	}
+
+	#[test]
+	fn with_destructure() {
+		let stmt = SetStatement {
+			destructure: Some(Destructure::Array(vec!["x".to_owned(), "y".to_owned()])),
+			..Default::default()
+		};
+		let value: SetStatement = stmt.serialize(Serializer.wrap()).unwrap();
+		assert_eq!(value, stmt);
+	}
 }
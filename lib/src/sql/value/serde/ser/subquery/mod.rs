@@ -40,7 +40,7 @@ impl ser::Serializer for Serializer {
 			"Output" => {
 				Ok(Subquery::Output(value.serialize(ser::statement::output::Serializer.wrap())?))
 			}
-			"Select" => {
+			"Select" | "SelectDistinct" => {
 				Ok(Subquery::Select(value.serialize(ser::statement::select::Serializer.wrap())?))
 			}
 			"Create" => {
@@ -29,7 +29,7 @@ use crate::sql::model::{model, Model};
 use crate::sql::number::decimal_is_integer;
 use crate::sql::number::{number, Number};
 use crate::sql::object::{key, object, Object};
-use crate::sql::operation::Operation;
+use crate::sql::operation::{Operation, Patch};
 use crate::sql::param::{param, Param};
 use crate::sql::part::Part;
 use crate::sql::range::{range, Range};
@@ -488,6 +488,12 @@ impl From<Vec<Operation>> for Value {
 	}
 }
 
+impl From<Patch> for Value {
+	fn from(v: Patch) -> Self {
+		Value::Array(Array::from(v.0))
+	}
+}
+
 impl From<HashMap<String, Value>> for Value {
 	fn from(v: HashMap<String, Value>) -> Self {
 		Value::Object(Object::from(v))
@@ -529,6 +535,32 @@ impl From<Id> for Value {
 	}
 }
 
+/// Converts a `serde_json::Value` into a `surrealdb::sql::Value`
+///
+/// JSON strings are always converted into strings, and are never parsed into
+/// SurrealQL-specific types such as a `Thing`, `Duration`, or `Datetime`. JSON
+/// numbers are converted into integers where possible, into decimals when they
+/// are integers too large for an `i64`, and into floats otherwise. Converting the
+/// result back with [`Value::into_json`] gives the original JSON.
+impl From<Json> for Value {
+	fn from(v: Json) -> Self {
+		match v {
+			Json::Null => Value::Null,
+			Json::Bool(v) => Value::Bool(v),
+			Json::Number(v) => match (v.as_i64(), v.as_u64()) {
+				(Some(v), _) => Value::from(v),
+				(None, Some(v)) => Value::from(Decimal::from(v)),
+				(None, None) => Value::from(v.as_f64().unwrap_or_default()),
+			},
+			Json::String(v) => Value::from(v),
+			Json::Array(v) => v.into_iter().map(Value::from).collect::<Vec<_>>().into(),
+			Json::Object(v) => {
+				v.into_iter().map(|(k, v)| (k, Value::from(v))).collect::<BTreeMap<_, _>>().into()
+			}
+		}
+	}
+}
+
 impl TryFrom<Value> for i8 {
 	type Error = Error;
 	fn try_from(value: Value) -> Result<Self, Self::Error> {
@@ -807,7 +839,19 @@ impl Value {
 		matches!(self, Value::Bool(false))
 	}
 
-	/// Check if this Value is truthy
+	/// Check if this Value is truthy, as it would be evaluated in a `WHERE` or `IF` clause
+	///
+	/// | Value                      | Truthy when                            |
+	/// | -------------------------- | -------------------------------------- |
+	/// | `NONE`, `NULL`             | never                                  |
+	/// | bool                       | the value is `true`                    |
+	/// | number                     | the value is not zero                  |
+	/// | string                     | it is not empty and not `"false"`      |
+	/// | array, object              | it is not empty                        |
+	/// | duration                   | it is longer than zero                 |
+	/// | datetime                   | it is after the unix epoch             |
+	/// | uuid, record id, geometry  | always                                 |
+	/// | anything else              | never                                  |
 	pub fn is_truthy(&self) -> bool {
 		match self {
 			Value::Bool(v) => *v,
@@ -824,6 +868,11 @@ impl Value {
 		}
 	}
 
+	/// Check if this Value is falsy, the opposite of [`Value::is_truthy`]
+	pub fn is_falsy(&self) -> bool {
+		!self.is_truthy()
+	}
+
 	/// Check if this Value is a UUID
 	pub fn is_uuid(&self) -> bool {
 		matches!(self, Value::Uuid(_))
@@ -999,6 +1048,34 @@ impl Value {
 		}
 	}
 
+	/// Converts this Value into a Vec of its elements, if it is an Array
+	pub fn into_array(self) -> Option<Vec<Value>> {
+		match self {
+			Value::Array(v) => Some(v.0),
+			_ => None,
+		}
+	}
+
+	// -----------------------------------
+	// Simple access of value contents
+	// -----------------------------------
+
+	/// Returns the elements of this Value, if it is an Array
+	pub fn as_array(&self) -> Option<&[Value]> {
+		match self {
+			Value::Array(v) => Some(&v.0),
+			_ => None,
+		}
+	}
+
+	/// Returns the fields of this Value, if it is an Object
+	pub fn as_object(&self) -> Option<&BTreeMap<String, Value>> {
+		match self {
+			Value::Object(v) => Some(&v.0),
+			_ => None,
+		}
+	}
+
 	// -----------------------------------
 	// Expensive conversion of value
 	// -----------------------------------
@@ -1047,7 +1124,10 @@ impl Value {
 	/// Converts a `surrealdb::sq::Value` into a `serde_json::Value`
 	///
 	/// This converts certain types like `Thing` into their simpler formats
-	/// instead of the format used internally by SurrealDB.
+	/// instead of the format used internally by SurrealDB. A `Thing` becomes a
+	/// `table:id` string, a `Duration` becomes a string such as `1h30m`, and a
+	/// `Datetime` becomes an RFC 3339 string. Any JSON value which has been
+	/// converted into a `Value` is converted back into the same JSON value.
 	pub fn into_json(self) -> Json {
 		self.into()
 	}
@@ -1100,7 +1180,12 @@ impl Value {
 	// -----------------------------------
 
 	/// Try to coerce this value to the specified `Kind`
-	pub(crate) fn coerce_to(self, kind: &Kind) -> Result<Value, Error> {
+	///
+	/// This follows the rules which are enforced by `DEFINE FIELD ... TYPE`,
+	/// so values are only changed between compatible types, such as a float
+	/// with no fractional part to an `int`. A `string` is never parsed into
+	/// another type, so use [`Value::convert_to`] to parse user-supplied strings.
+	pub fn coerce_to(self, kind: &Kind) -> Result<Value, Error> {
 		// Attempt to convert to the desired type
 		let res = match kind {
 			Kind::Any => Ok(self),
@@ -1626,7 +1711,10 @@ impl Value {
 	// -----------------------------------
 
 	/// Try to convert this value to the specified `Kind`
-	pub(crate) fn convert_to(self, kind: &Kind) -> Result<Value, Error> {
+	///
+	/// This follows the rules of a SurrealQL cast, such as `<int> "42"`, so
+	/// strings are parsed into the target type where possible.
+	pub fn convert_to(self, kind: &Kind) -> Result<Value, Error> {
 		// Attempt to convert to the desired type
 		let res = match kind {
 			Kind::Any => Ok(self),
@@ -2911,6 +2999,29 @@ mod tests {
 		assert!(Value::from("falsey").is_truthy());
 		assert!(Value::from("something").is_truthy());
 		assert!(Value::from(Uuid::new()).is_truthy());
+		assert!(!Value::from("").is_truthy());
+		assert!(!Value::parse("[]").is_truthy());
+		assert!(Value::parse("[0]").is_truthy());
+		assert!(!Value::parse("{}").is_truthy());
+		assert!(Value::parse("{ test: false }").is_truthy());
+		assert!(!Value::parse("0s").is_truthy());
+		assert!(Value::parse("1s").is_truthy());
+	}
+
+	#[test]
+	fn convert_falsy() {
+		assert!(Value::None.is_falsy());
+		assert!(Value::Null.is_falsy());
+		assert!(Value::Bool(false).is_falsy());
+		assert!(!Value::Bool(true).is_falsy());
+		assert!(Value::from(0).is_falsy());
+		assert!(!Value::from(1).is_falsy());
+		assert!(Value::from("").is_falsy());
+		assert!(!Value::from("something").is_falsy());
+		assert!(Value::parse("[]").is_falsy());
+		assert!(!Value::parse("[1]").is_falsy());
+		assert!(Value::parse("{}").is_falsy());
+		assert!(!Value::parse("{ test: 1 }").is_falsy());
 	}
 
 	#[test]
@@ -2930,6 +3041,20 @@ mod tests {
 		assert_eq!(String::from("something"), Value::from("something").as_string());
 	}
 
+	#[test]
+	fn convert_array() {
+		let val = Value::parse(r#"[{ bio: "Line 1", id: person:test, scores: [66, 84, 73] }]"#);
+		let records = val.into_array().unwrap();
+		assert_eq!(records.len(), 1);
+		let record = records[0].as_object().unwrap();
+		assert_eq!(record["id"], Value::parse("person:test"));
+		let scores = record["scores"].as_array().unwrap();
+		assert_eq!(scores, [Value::from(66), Value::from(84), Value::from(73)]);
+		assert_eq!(Value::from(66).into_array(), None);
+		assert_eq!(Value::from("scores").as_array(), None);
+		assert_eq!(Value::parse("[1, 2]").as_object(), None);
+	}
+
 	#[test]
 	fn check_size() {
 		assert_eq!(64, std::mem::size_of::<Value>());
@@ -2966,6 +3091,67 @@ mod tests {
 		assert_eq!(22, Value::parse("{ compact: true, schema: 0 }").to_vec().len());
 	}
 
+	#[test]
+	fn convert_json() {
+		let json = serde_json::json!({
+			"name": "Tobie",
+			"age": 33,
+			"height": 1.85,
+			"admin": true,
+			"manager": null,
+			"tags": ["one", 2, { "three": [3.5, false] }],
+			"created": "1995-12-17T03:24:00Z",
+		});
+		let val = Value::from(json.clone());
+		assert_eq!(
+			val,
+			Value::Object(
+				map! {
+					"name".to_owned() => Value::from("Tobie"),
+					"age".to_owned() => Value::from(33),
+					"height".to_owned() => Value::from(1.85),
+					"admin".to_owned() => Value::from(true),
+					"manager".to_owned() => Value::Null,
+					"tags".to_owned() => Value::from(vec![
+						Value::from("one"),
+						Value::from(2),
+						Value::parse("{ three: [3.5, false] }"),
+					]),
+					"created".to_owned() => Value::from("1995-12-17T03:24:00Z"),
+				}
+				.into()
+			)
+		);
+		assert!(matches!(val.pick(&["age".into()]), Value::Number(Number::Int(33))));
+		assert_eq!(val.into_json(), json);
+	}
+
+	#[test]
+	fn convert_json_large_integer() {
+		let json = serde_json::json!(u64::MAX);
+		let val = Value::from(json.clone());
+		assert_eq!(val, Value::from(Decimal::from(u64::MAX)));
+		assert_eq!(val.into_json(), json);
+		let json = serde_json::json!(i64::MAX);
+		let val = Value::from(json.clone());
+		assert_eq!(val, Value::from(i64::MAX));
+		assert_eq!(val.into_json(), json);
+	}
+
+	#[test]
+	fn convert_into_json() {
+		let val = Value::parse(
+			"{ id: person:tobie, wait: 1h30m, created: '1995-12-17T03:24:00Z', nested: [{ id: person:jaime }] }",
+		);
+		let json = serde_json::json!({
+			"id": "person:tobie",
+			"wait": "1h30m",
+			"created": "1995-12-17T03:24:00Z",
+			"nested": [{ "id": "person:jaime" }],
+		});
+		assert_eq!(val.into_json(), json);
+	}
+
 	#[test]
 	fn serialize_deserialize() {
 		let val = Value::parse(
@@ -2978,4 +3164,28 @@ mod tests {
 		let dec: Value = enc.into();
 		assert_eq!(res, dec);
 	}
+
+	#[test]
+	fn coerce_to_kind() {
+		let res = Value::from(42.0).coerce_to(&Kind::Int).unwrap();
+		assert_eq!(res, Value::from(42));
+		let res = Value::from(42).coerce_to(&Kind::Float).unwrap();
+		assert_eq!(res, Value::from(42.0));
+		let res = Value::from("42").coerce_to(&Kind::Int);
+		assert!(matches!(res, Err(Error::CoerceTo { .. })), "{res:?}");
+		let res = Value::from(4.2).coerce_to(&Kind::Int);
+		assert!(matches!(res, Err(Error::CoerceTo { .. })), "{res:?}");
+	}
+
+	#[test]
+	fn convert_to_kind() {
+		let res = Value::from("42").convert_to(&Kind::Int).unwrap();
+		assert_eq!(res, Value::from(42));
+		let res = Value::from("1h").convert_to(&Kind::Duration).unwrap();
+		assert_eq!(res, Value::parse("1h"));
+		let res = Value::from("true").convert_to(&Kind::Bool).unwrap();
+		assert_eq!(res, Value::from(true));
+		let res = Value::from("abc").convert_to(&Kind::Int);
+		assert!(matches!(res, Err(Error::ConvertTo { .. })), "{res:?}");
+	}
 }
@@ -0,0 +1,146 @@
+use crate::sql::part::Part;
+use crate::sql::value::Value;
+
+impl Value {
+	/// Calls the visitor with this value, and with every value nested within it.
+	///
+	/// Each value is passed along with its path from this value, which is empty for
+	/// this value itself. A value is visited before any of the values nested within it.
+	pub fn visit(&self, visitor: &mut dyn FnMut(&[Part], &Value)) {
+		self._visit(&mut Vec::new(), visitor)
+	}
+	fn _visit(&self, path: &mut Vec<Part>, visitor: &mut dyn FnMut(&[Part], &Value)) {
+		visitor(path, self);
+		match self {
+			Value::Object(v) => {
+				for (k, v) in v.iter() {
+					path.push(Part::from(k.to_owned()));
+					v._visit(path, visitor);
+					path.pop();
+				}
+			}
+			Value::Array(v) => {
+				for (i, v) in v.iter().enumerate() {
+					path.push(Part::from(i));
+					v._visit(path, visitor);
+					path.pop();
+				}
+			}
+			_ => (),
+		}
+	}
+	/// Calls the visitor with this value, and with every value nested within it,
+	/// allowing each value to be modified in place.
+	///
+	/// A value is visited before any of the values nested within it, so any values
+	/// which the visitor nests within a value are also visited.
+	pub fn visit_mut(&mut self, visitor: &mut dyn FnMut(&[Part], &mut Value)) {
+		self._visit_mut(&mut Vec::new(), visitor)
+	}
+	fn _visit_mut(&mut self, path: &mut Vec<Part>, visitor: &mut dyn FnMut(&[Part], &mut Value)) {
+		visitor(path, self);
+		match self {
+			Value::Object(v) => {
+				for (k, v) in v.iter_mut() {
+					path.push(Part::from(k.to_owned()));
+					v._visit_mut(path, visitor);
+					path.pop();
+				}
+			}
+			Value::Array(v) => {
+				for (i, v) in v.iter_mut().enumerate() {
+					path.push(Part::from(i));
+					v._visit_mut(path, visitor);
+					path.pop();
+				}
+			}
+			_ => (),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+	use crate::sql::idiom::Idiom;
+	use crate::sql::test::Parse;
+	use crate::sql::thing::Thing;
+
+	fn value() -> Value {
+		Value::parse(
+			"[
+				{
+					id: article:test,
+					created_at: '1995-12-17T03:24:00Z',
+					next_signin: 1w2d6h,
+					manager: user:joanna,
+					identifier: '03412258-988f-47cd-82db-549902cdaffe',
+					author: { name: 'Tobie', tags: ['admin', 'editor'] },
+				}
+			]",
+		)
+	}
+
+	#[test]
+	fn visit_things() {
+		let mut res = vec![];
+		value().visit(&mut |path, v| {
+			if let Value::Thing(v) = v {
+				res.push((Idiom::from(path), v.clone()));
+			}
+		});
+		let val: Vec<(Idiom, Thing)> = vec![
+			(Idiom::from(vec![Part::from(0), Part::from("id")]), Thing::parse("article:test")),
+			(Idiom::from(vec![Part::from(0), Part::from("manager")]), Thing::parse("user:joanna")),
+		];
+		assert_eq!(res, val);
+	}
+
+	#[test]
+	fn visit_strings() {
+		let mut res = vec![];
+		value().visit(&mut |path, v| {
+			if let Value::Strand(v) = v {
+				res.push((Idiom::from(path), v.as_str().to_owned()));
+			}
+		});
+		let val = vec![
+			(
+				Idiom::from(vec![Part::from(0), Part::from("author"), Part::from("name")]),
+				String::from("Tobie"),
+			),
+			(
+				Idiom::from(vec![
+					Part::from(0),
+					Part::from("author"),
+					Part::from("tags"),
+					Part::from(0),
+				]),
+				String::from("admin"),
+			),
+			(
+				Idiom::from(vec![
+					Part::from(0),
+					Part::from("author"),
+					Part::from("tags"),
+					Part::from(1),
+				]),
+				String::from("editor"),
+			),
+		];
+		assert_eq!(res, val);
+	}
+
+	#[test]
+	fn visit_mut_redact() {
+		let mut val = value();
+		val.visit_mut(&mut |path, v| {
+			if path.last() == Some(&Part::from("author")) {
+				*v = Value::from("redacted");
+			}
+		});
+		assert_eq!(val.pick(&[0.into(), "author".into()]), Value::from("redacted"));
+		assert_eq!(val.pick(&[0.into(), "manager".into()]), Value::parse("user:joanna"));
+	}
+}
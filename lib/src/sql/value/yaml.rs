@@ -0,0 +1,319 @@
+//! Conversion between SurrealQL values and YAML documents.
+//!
+//! YAML scalars, sequences, and mappings map directly to the equivalent
+//! SurrealQL values, and any anchors and aliases are resolved when parsing.
+//! SurrealQL types which have no YAML equivalent are written as strings
+//! with a local tag, and are converted back to the original type when
+//! the document is parsed again:
+//!
+//! | SurrealQL type | YAML representation                     |
+//! |----------------|-----------------------------------------|
+//! | `datetime`     | `!datetime 2022-07-03T07:18:52Z`        |
+//! | `duration`     | `!duration 1h30m`                       |
+//! | `uuid`         | `!uuid 0189f0ed-3a79-7e94-bae4-bc1c1d2e4f43` |
+//! | `record`       | `!record person:tobie`                  |
+//! | `decimal`      | `!decimal '1.5'`                        |
+//! | `bytes`        | `!bytes aGVsbG8` (base64)               |
+//! | anything else  | `!surrealql <SurrealQL text>`           |
+
+use crate::err::Error;
+use crate::sql::number::Number;
+use crate::sql::value::Value;
+use crate::sql::{Array, Bytes, Datetime, Duration, Object, Thing, Uuid};
+use base64_lib::{engine::general_purpose::STANDARD_NO_PAD, Engine};
+use rust_decimal::Decimal;
+use serde::de::{self, Deserialize, Deserializer, EnumAccess, MapAccess, SeqAccess};
+use serde::de::{VariantAccess, Visitor};
+use serde_yaml::value::{Tag, TaggedValue};
+use serde_yaml::Value as Yaml;
+use std::fmt;
+use std::str::FromStr;
+
+const DATETIME: &str = "datetime";
+const DURATION: &str = "duration";
+const UUID: &str = "uuid";
+const RECORD: &str = "record";
+const DECIMAL: &str = "decimal";
+const BYTES: &str = "bytes";
+const SURREALQL: &str = "surrealql";
+
+impl Value {
+	/// Converts this value into a YAML document
+	///
+	/// SurrealQL types which have no YAML equivalent
+	/// are output as strings with a local YAML tag.
+	pub fn to_yaml(&self) -> String {
+		// Serializing a YAML value tree can not fail
+		serde_yaml::to_string(&self.to_yaml_value()).unwrap_or_default()
+	}
+
+	fn to_yaml_value(&self) -> Yaml {
+		match self {
+			Value::None | Value::Null => Yaml::Null,
+			Value::Bool(v) => Yaml::Bool(*v),
+			Value::Number(Number::Int(v)) => Yaml::Number((*v).into()),
+			Value::Number(Number::Float(v)) => Yaml::Number((*v).into()),
+			Value::Number(Number::Decimal(v)) => tagged(DECIMAL, v.to_string()),
+			Value::Strand(v) => Yaml::String(v.0.clone()),
+			Value::Array(v) => Yaml::Sequence(v.iter().map(Value::to_yaml_value).collect()),
+			Value::Object(v) => Yaml::Mapping(
+				v.iter().map(|(k, v)| (Yaml::String(k.clone()), v.to_yaml_value())).collect(),
+			),
+			Value::Datetime(v) => tagged(DATETIME, v.to_raw()),
+			Value::Duration(v) => tagged(DURATION, v.to_raw()),
+			Value::Uuid(v) => tagged(UUID, v.to_raw()),
+			Value::Thing(v) => tagged(RECORD, v.to_raw()),
+			Value::Bytes(v) => tagged(BYTES, STANDARD_NO_PAD.encode(&v.0)),
+			v => tagged(SURREALQL, v.to_string()),
+		}
+	}
+}
+
+fn tagged(tag: &str, value: String) -> Yaml {
+	Yaml::Tagged(Box::new(TaggedValue {
+		tag: Tag::new(tag),
+		value: Yaml::String(value),
+	}))
+}
+
+/// Parses a YAML document into an inert SurrealQL [`Value`]
+pub(crate) fn yaml(input: &str) -> Result<Value, Error> {
+	// Nodes are converted as the document is parsed, so
+	// that any conversion errors carry the node location
+	serde_yaml::from_str::<FromYaml>(input).map(|v| v.0).map_err(|e| Error::InvalidYaml {
+		line: e.location().map(|l| l.line()).unwrap_or_default(),
+		message: e.to_string(),
+	})
+}
+
+/// A SurrealQL value converted from a YAML node
+struct FromYaml(Value);
+
+impl<'de> Deserialize<'de> for FromYaml {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserializer.deserialize_any(ValueVisitor).map(FromYaml)
+	}
+}
+
+struct ValueVisitor;
+
+impl<'de> Visitor<'de> for ValueVisitor {
+	type Value = Value;
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("a YAML value")
+	}
+
+	fn visit_unit<E: de::Error>(self) -> Result<Value, E> {
+		Ok(Value::Null)
+	}
+
+	fn visit_bool<E: de::Error>(self, v: bool) -> Result<Value, E> {
+		Ok(v.into())
+	}
+
+	fn visit_i64<E: de::Error>(self, v: i64) -> Result<Value, E> {
+		Ok(v.into())
+	}
+
+	fn visit_u64<E: de::Error>(self, v: u64) -> Result<Value, E> {
+		Ok(match i64::try_from(v) {
+			Ok(v) => v.into(),
+			Err(_) => (v as f64).into(),
+		})
+	}
+
+	fn visit_i128<E: de::Error>(self, v: i128) -> Result<Value, E> {
+		Ok((v as f64).into())
+	}
+
+	fn visit_u128<E: de::Error>(self, v: u128) -> Result<Value, E> {
+		Ok((v as f64).into())
+	}
+
+	fn visit_f64<E: de::Error>(self, v: f64) -> Result<Value, E> {
+		Ok(v.into())
+	}
+
+	fn visit_str<E: de::Error>(self, v: &str) -> Result<Value, E> {
+		Ok(v.into())
+	}
+
+	fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Value, A::Error> {
+		let mut arr = Vec::new();
+		while let Some(FromYaml(v)) = seq.next_element()? {
+			arr.push(v);
+		}
+		Ok(Array::from(arr).into())
+	}
+
+	fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Value, A::Error> {
+		let mut obj = Object::default();
+		while let Some((FromYamlKey(k), FromYaml(v))) = map.next_entry()? {
+			obj.insert(k, v);
+		}
+		Ok(obj.into())
+	}
+
+	fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Value, A::Error> {
+		// Tagged nodes are parsed as an enum variant named after the tag
+		let (tag, contents): (String, _) = data.variant()?;
+		let FromYaml(value) = contents.newtype_variant()?;
+		from_tagged(tag.strip_prefix('!').unwrap_or(&tag), value).map_err(de::Error::custom)
+	}
+}
+
+/// A YAML mapping key, which must be a scalar value
+struct FromYamlKey(String);
+
+impl<'de> Deserialize<'de> for FromYamlKey {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserializer.deserialize_any(KeyVisitor).map(FromYamlKey)
+	}
+}
+
+struct KeyVisitor;
+
+impl KeyVisitor {
+	fn invalid<E: de::Error>() -> Result<String, E> {
+		Err(de::Error::custom("mapping keys must be scalar values"))
+	}
+}
+
+impl<'de> Visitor<'de> for KeyVisitor {
+	type Value = String;
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("a scalar mapping key")
+	}
+
+	fn visit_unit<E: de::Error>(self) -> Result<String, E> {
+		Ok("null".to_owned())
+	}
+
+	fn visit_bool<E: de::Error>(self, v: bool) -> Result<String, E> {
+		Ok(v.to_string())
+	}
+
+	fn visit_i64<E: de::Error>(self, v: i64) -> Result<String, E> {
+		Ok(v.to_string())
+	}
+
+	fn visit_u64<E: de::Error>(self, v: u64) -> Result<String, E> {
+		Ok(v.to_string())
+	}
+
+	fn visit_f64<E: de::Error>(self, v: f64) -> Result<String, E> {
+		Ok(serde_yaml::Number::from(v).to_string())
+	}
+
+	fn visit_str<E: de::Error>(self, v: &str) -> Result<String, E> {
+		Ok(v.to_owned())
+	}
+
+	fn visit_seq<A: SeqAccess<'de>>(self, _: A) -> Result<String, A::Error> {
+		Self::invalid()
+	}
+
+	fn visit_map<A: MapAccess<'de>>(self, _: A) -> Result<String, A::Error> {
+		Self::invalid()
+	}
+
+	fn visit_enum<A: EnumAccess<'de>>(self, _: A) -> Result<String, A::Error> {
+		Self::invalid()
+	}
+}
+
+fn from_tagged(tag: &str, value: Value) -> Result<Value, String> {
+	// Only string values can be tagged with a SurrealQL type
+	let Value::Strand(text) = value else {
+		return Ok(value);
+	};
+	let text = text.as_str();
+	let invalid = || format!("'{text}' is not a valid {tag} value");
+	match tag {
+		DATETIME => Datetime::try_from(text).map(Value::from).map_err(|_| invalid()),
+		DURATION => Duration::try_from(text).map(Value::from).map_err(|_| invalid()),
+		UUID => Uuid::try_from(text).map(Value::from).map_err(|_| invalid()),
+		RECORD => Thing::try_from(text).map(Value::from).map_err(|_| invalid()),
+		DECIMAL => Decimal::from_str(text).map(Value::from).map_err(|_| invalid()),
+		BYTES => STANDARD_NO_PAD.decode(text).map(|v| Value::from(Bytes(v))).map_err(|_| invalid()),
+		SURREALQL => crate::sql::value(text).map_err(|_| invalid()),
+		// Unknown tags are ignored
+		_ => Ok(text.into()),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+	use crate::sql::test::Parse;
+
+	#[test]
+	fn yaml_round_trip() {
+		let doc = r#"
+name: Tobie
+age: 33
+score: 12.5
+admin: true
+tags:
+  - one
+  - two
+address: &home
+  city: London
+  country: UK
+office: *home
+created: !datetime 2022-07-03T07:18:52Z
+owner: !record person:tobie
+"#;
+		let val = yaml(doc).unwrap();
+		assert_eq!(
+			val,
+			Value::parse(
+				"{
+					name: 'Tobie',
+					age: 33,
+					score: 12.5,
+					admin: true,
+					tags: ['one', 'two'],
+					address: { city: 'London', country: 'UK' },
+					office: { city: 'London', country: 'UK' },
+					created: '2022-07-03T07:18:52Z',
+					owner: person:tobie,
+				}"
+			)
+		);
+		assert_eq!(yaml(&val.to_yaml()).unwrap(), val);
+	}
+
+	#[test]
+	fn yaml_round_trip_surrealql_types() {
+		let val = Value::parse(
+			"{
+				duration: 1h30m,
+				decimal: 1.5dec,
+				uuid: '0189f0ed-3a79-7e94-bae4-bc1c1d2e4f43',
+				point: (51.509865, -0.118092),
+			}",
+		);
+		assert_eq!(yaml(&val.to_yaml()).unwrap(), val);
+	}
+
+	#[test]
+	fn yaml_malformed() {
+		let doc = "name: Tobie\ntags: [one, two\nage: 33\n";
+		let res = yaml(doc);
+		assert!(matches!(res, Err(Error::InvalidYaml { line, .. }) if line > 1));
+	}
+
+	#[test]
+	fn yaml_invalid_location() {
+		let doc = "name: Tobie\ncreated: !datetime yesterday\n";
+		let res = yaml(doc);
+		assert!(matches!(res, Err(Error::InvalidYaml { line, .. }) if line == 2), "{res:?}");
+		let doc = "name: Tobie\ntags:\n  [one, two]: true\n";
+		let res = yaml(doc);
+		assert!(matches!(res, Err(Error::InvalidYaml { line, .. }) if line == 3), "{res:?}");
+	}
+}
@@ -12,8 +12,10 @@ mod api_integration {
 	use surrealdb::opt::auth::Namespace;
 	use surrealdb::opt::auth::Root;
 	use surrealdb::opt::auth::Scope;
+	use surrealdb::opt::Config;
 	use surrealdb::opt::PatchOp;
 	use surrealdb::opt::Resource;
+	use surrealdb::opt::Use;
 	use surrealdb::sql::statements::BeginStatement;
 	use surrealdb::sql::statements::CommitStatement;
 	use surrealdb::sql::thing;
@@ -83,7 +85,21 @@ mod api_integration {
 			db
 		}
 
+		async fn new_db_with_config(config: Config) -> Surreal<Client> {
+			init_logger();
+			let db = Surreal::new::<Ws>(("127.0.0.1:8000", config)).await.unwrap();
+			db.signin(Root {
+				username: ROOT_USER,
+				password: ROOT_PASS,
+			})
+			.await
+			.unwrap();
+			db
+		}
+
 		include!("api/mod.rs");
+		include!("api/remote.rs");
+		include!("api/ws.rs");
 	}
 
 	#[cfg(feature = "protocol-http")]
@@ -104,7 +120,35 @@ mod api_integration {
 			db
 		}
 
+		async fn new_db_with_config(config: Config) -> Surreal<Client> {
+			init_logger();
+			let db = Surreal::new::<Http>(("127.0.0.1:8000", config)).await.unwrap();
+			db.signin(Root {
+				username: ROOT_USER,
+				password: ROOT_PASS,
+			})
+			.await
+			.unwrap();
+			db
+		}
+
+		#[tokio::test]
+		async fn root_credentials_on_endpoint() {
+			init_logger();
+			let root = Root {
+				username: ROOT_USER,
+				password: ROOT_PASS,
+			};
+			let db = Surreal::new::<Http>(("127.0.0.1:8000", root)).await.unwrap();
+			db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+			let Some(record): Option<RecordId> = db.create(("item", "foo")).await.unwrap() else {
+				panic!("record not found");
+			};
+			assert_eq!(record.id.to_string(), "item:foo");
+		}
+
 		include!("api/mod.rs");
+		include!("api/remote.rs");
 		include!("api/backup.rs");
 	}
 
@@ -143,6 +187,16 @@ mod api_integration {
 			assert_eq!(record.id.to_string(), "item:foo");
 		}
 
+		#[tokio::test]
+		async fn namespace_and_database_set_on_endpoint() {
+			init_logger();
+			let db = Surreal::new::<Mem>(((), Use::ns("namespace").db("database"))).await.unwrap();
+			let mut response = db.query("CREATE item:foo; SELECT * FROM item").await.unwrap();
+			let records: Vec<RecordId> = response.take(1).unwrap();
+			assert_eq!(records.len(), 1);
+			assert_eq!(records[0].id.to_string(), "item:foo");
+		}
+
 		#[tokio::test]
 		async fn cant_sign_into_default_root_account() {
 			init_logger();
@@ -0,0 +1,22 @@
+// Tests common to the remote protocols
+
+#[tokio::test]
+async fn oversized_response() {
+	let db = new_db_with_config(Config::new().max_response_size(1024)).await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	let error = db.query("RETURN string::repeat('a', 4096)").await.unwrap_err();
+	assert!(matches!(error, Error::Api(ApiError::PayloadTooLarge { .. })), "{error:?}");
+	// Responses within the limit are still returned
+	let mut response = db.query("RETURN string::repeat('a', 16)").await.unwrap();
+	let value: Option<String> = response.take(0).unwrap();
+	assert_eq!(value.as_deref(), Some("aaaaaaaaaaaaaaaa"));
+}
+
+#[tokio::test]
+async fn oversized_request() {
+	let db = new_db_with_config(Config::new().max_request_size(1024)).await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	let sql = format!("RETURN '{}'", "a".repeat(4096));
+	let error = db.query(sql).await.unwrap_err();
+	assert!(matches!(error, Error::Api(ApiError::PayloadTooLarge { .. })), "{error:?}");
+}
@@ -0,0 +1,65 @@
+// Tests specific to the WebSocket protocol
+
+#[tokio::test]
+async fn reconnect_after_dropped_connection() {
+	use std::sync::Arc;
+	use std::sync::Mutex;
+	use std::time::Duration;
+	use surrealdb::opt::Backoff;
+	use tokio::net::TcpListener;
+	use tokio::net::TcpStream;
+	init_logger();
+	// Proxy connections to the server, so that they can be dropped
+	let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+	let address = listener.local_addr().unwrap();
+	let connections = Arc::new(Mutex::new(Vec::new()));
+	tokio::spawn({
+		let connections = connections.clone();
+		async move {
+			while let Ok((mut inbound, _)) = listener.accept().await {
+				let task = tokio::spawn(async move {
+					let mut outbound = TcpStream::connect("127.0.0.1:8000").await.unwrap();
+					let _ = tokio::io::copy_bidirectional(&mut inbound, &mut outbound).await;
+				});
+				connections.lock().unwrap().push(task);
+			}
+		}
+	});
+	let backoff = Backoff::new()
+		.initial_delay(Duration::from_millis(50))
+		.max_delay(Duration::from_millis(500))
+		.max_attempts(20);
+	let db = Surreal::new::<Ws>((address, Config::new().reconnect(backoff))).await.unwrap();
+	db.signin(Root {
+		username: ROOT_USER,
+		password: ROOT_PASS,
+	})
+	.await
+	.unwrap();
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	db.query("CREATE user:john").await.unwrap().check().unwrap();
+	// Drop the connection to the server
+	for task in connections.lock().unwrap().drain(..) {
+		task.abort();
+	}
+	// Requests fail with a retriable error until the client has reconnected
+	let mut attempts = 0;
+	let mut response = loop {
+		match db.query("SELECT * FROM user").await {
+			Ok(response) => break response,
+			Err(error) => {
+				assert!(
+					matches!(error, Error::Api(ApiError::ConnectionLost | ApiError::Ws(..))),
+					"{error:?}"
+				);
+				attempts += 1;
+				assert!(attempts < 50, "the client did not reconnect");
+				tokio::time::sleep(Duration::from_millis(100)).await;
+			}
+		}
+	};
+	// The authentication and selected database are restored
+	let users: Vec<RecordId> = response.take(0).unwrap();
+	assert_eq!(users.len(), 1);
+	assert_eq!(users[0].id, thing("user:john").unwrap());
+}
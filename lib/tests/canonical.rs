@@ -0,0 +1,25 @@
+use surrealdb::dbs::Session;
+use surrealdb::err::Error;
+use surrealdb::kvs::Datastore;
+
+#[tokio::test]
+async fn canonical_json_of_equal_values() -> Result<(), Error> {
+	let sql = "
+		RETURN { name: 'Tobie', age: 33, friend: person:two, wait: 1h30m, tags: { b: 2.50, a: 1 } };
+		RETURN { tags: { a: 1, b: 2.5 }, wait: 90m, friend: person:two, age: 33, name: 'Tobie' };
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 2);
+	//
+	let one = res.remove(0).result?.to_canonical_json();
+	let two = res.remove(0).result?.to_canonical_json();
+	assert_eq!(one, two);
+	assert_eq!(
+		one,
+		r#"{"age":33,"friend":"person:two","name":"Tobie","tags":{"a":1,"b":2.5},"wait":"1h30m"}"#
+	);
+	//
+	Ok(())
+}
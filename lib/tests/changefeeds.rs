@@ -2,7 +2,7 @@ mod parse;
 use parse::Parse;
 use surrealdb::dbs::Session;
 use surrealdb::err::Error;
-use surrealdb::kvs::Datastore;
+use surrealdb::kvs::{ChangeKind, Datastore};
 use surrealdb::sql::Value;
 
 #[tokio::test]
@@ -157,3 +157,82 @@ async fn table_change_feeds() -> Result<(), Error> {
 	//
 	Ok(())
 }
+
+#[tokio::test]
+async fn table_change_feeds_api() -> Result<(), Error> {
+	let sql = "
+		DEFINE TABLE person CHANGEFEED 1h;
+		DEFINE TABLE animal;
+		CREATE person:one SET name = 'One';
+		UPDATE person:one SET name = 'Uno';
+		CREATE person:two SET name = 'Two';
+		DELETE person:one;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 6);
+	for _ in 0..6 {
+		res.remove(0).result?;
+	}
+	//
+	let tmp = dbs.changes("test", "test", "person", None, None).await?;
+	assert_eq!(tmp.len(), 4);
+	let kinds: Vec<_> = tmp.iter().map(|c| c.kind).collect();
+	assert_eq!(
+		kinds,
+		[ChangeKind::Create, ChangeKind::Update, ChangeKind::Create, ChangeKind::Delete]
+	);
+	let ids: Vec<_> = tmp.iter().map(|c| c.id.to_string()).collect();
+	assert_eq!(ids, ["person:one", "person:one", "person:two", "person:one"]);
+	assert!(tmp.windows(2).all(|w| w[0].versionstamp < w[1].versionstamp));
+	assert_eq!(tmp[0].value, Value::parse("{ id: person:one, name: 'One' }"));
+	assert_eq!(
+		tmp[0].diff,
+		Value::parse(
+			"[
+				{ op: 'add', path: '/id', value: person:one },
+				{ op: 'add', path: '/name', value: 'One' },
+			]"
+		)
+	);
+	assert_eq!(tmp[1].value, Value::parse("{ id: person:one, name: 'Uno' }"));
+	assert_eq!(tmp[1].diff.pick(&[0.into(), "path".into()]), Value::from("/name"));
+	assert_eq!(tmp[3].value, Value::None);
+	//
+	let since = tmp[1].versionstamp;
+	let tmp = dbs.changes("test", "test", "person", Some(since), Some(2)).await?;
+	let kinds: Vec<_> = tmp.iter().map(|c| c.kind).collect();
+	assert_eq!(kinds, [ChangeKind::Update, ChangeKind::Create]);
+	//
+	let tmp = dbs.changes("test", "test", "animal", None, None).await;
+	assert!(matches!(tmp, Err(Error::TbChangefeedNotFound { .. })), "{tmp:?}");
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn table_change_feeds_api_pages() -> Result<(), Error> {
+	let mut sql = String::from("DEFINE TABLE person CHANGEFEED 1h;");
+	for i in 0..1500 {
+		sql.push_str(&format!("CREATE person:{i} SET age = {i};"));
+	}
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None).await?;
+	assert_eq!(res.len(), 1501);
+	//
+	let tmp = dbs.changes("test", "test", "person", None, None).await?;
+	assert_eq!(tmp.len(), 100);
+	//
+	let tmp = dbs.changes("test", "test", "person", None, Some(1200)).await?;
+	assert_eq!(tmp.len(), 1200);
+	assert_eq!(tmp[1199].id.to_string(), "person:1199");
+	//
+	let since = tmp[1199].versionstamp;
+	let tmp = dbs.changes("test", "test", "person", Some(since), Some(1000)).await?;
+	assert_eq!(tmp.len(), 301);
+	assert_eq!(tmp[300].id.to_string(), "person:1499");
+	//
+	Ok(())
+}
@@ -2,9 +2,11 @@ mod parse;
 use parse::Parse;
 use surrealdb::dbs::Session;
 use surrealdb::err::Error;
+use surrealdb::key::index::Index;
 use surrealdb::kvs::Datastore;
+use surrealdb::sql::statements::DefineStatement;
 use surrealdb::sql::Idiom;
-use surrealdb::sql::{Part, Value};
+use surrealdb::sql::{Part, Statement, Value};
 
 #[tokio::test]
 async fn define_statement_namespace() -> Result<(), Error> {
@@ -48,6 +50,7 @@ async fn define_statement_database() -> Result<(), Error> {
 	let tmp = res.remove(0).result?;
 	let val = Value::parse(
 		"{
+			accesses: {},
 			databases: { test: 'DEFINE DATABASE test' },
 			logins: {},
 			tokens: {},
@@ -77,6 +80,7 @@ async fn define_statement_function() -> Result<(), Error> {
 	let tmp = res.remove(0).result?;
 	let val = Value::parse(
 		"{
+			accesses: {},
 			analyzers: {},
 			logins: {},
 			tokens: {},
@@ -93,6 +97,59 @@ async fn define_statement_function() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn define_statement_function_default_arguments() -> Result<(), Error> {
+	let sql = "
+		DEFINE FUNCTION fn::greet($name: string, $greeting: string = 'Hello') {
+			RETURN $greeting + ' ' + $name;
+		};
+		DEFINE FUNCTION fn::stamp($name: string, $at: datetime = time::now()) {
+			RETURN is::datetime($at);
+		};
+		RETURN fn::greet('Tobie');
+		RETURN fn::greet('Tobie', 'Hi');
+		RETURN fn::stamp('Tobie');
+		RETURN fn::greet();
+		RETURN fn::greet('Tobie', 'Hi', 'there');
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 7);
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from("Hello Tobie");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from("Hi Tobie");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from(true);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp.err(),
+		Some(e) if e.to_string() == "Incorrect arguments for function fn::greet(). The function expects 1 to 2 arguments."
+	));
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp.err(),
+		Some(e) if e.to_string() == "Incorrect arguments for function fn::greet(). The function expects 1 to 2 arguments."
+	));
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn define_statement_table_drop() -> Result<(), Error> {
 	let sql = "
@@ -110,6 +167,7 @@ async fn define_statement_table_drop() -> Result<(), Error> {
 	let tmp = res.remove(0).result?;
 	let val = Value::parse(
 		"{
+			accesses: {},
 			analyzers: {},
 			logins: {},
 			tokens: {},
@@ -141,6 +199,7 @@ async fn define_statement_table_schemaless() -> Result<(), Error> {
 	let tmp = res.remove(0).result?;
 	let val = Value::parse(
 		"{
+			accesses: {},
 			analyzers: {},
 			logins: {},
 			tokens: {},
@@ -176,6 +235,7 @@ async fn define_statement_table_schemafull() -> Result<(), Error> {
 	let tmp = res.remove(0).result?;
 	let val = Value::parse(
 		"{
+			accesses: {},
 			analyzers: {},
 			logins: {},
 			tokens: {},
@@ -207,6 +267,7 @@ async fn define_statement_table_schemaful() -> Result<(), Error> {
 	let tmp = res.remove(0).result?;
 	let val = Value::parse(
 		"{
+			accesses: {},
 			analyzers: {},
 			logins: {},
 			tokens: {},
@@ -736,7 +797,7 @@ async fn define_statement_index_single_unique() -> Result<(), Error> {
 	let tmp = res.remove(0).result;
 	assert!(matches!(
 		tmp.err(),
-		Some(e) if e.to_string() == r#"Database index `test` already contains 'test@surrealdb.com', with record `user:2`"#
+		Some(e) if e.to_string() == r#"Database index `test` already contains 'test@surrealdb.com', with record `user:1`"#
 	));
 	//
 	let tmp = res.remove(0).result;
@@ -749,6 +810,41 @@ async fn define_statement_index_single_unique() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn define_statement_index_unique_conflicting_record() -> Result<(), Error> {
+	let sql = "
+		DEFINE INDEX email ON user FIELDS email UNIQUE;
+		INSERT INTO user { id: user:tobie, email: 'tobie@surrealdb.com' };
+		INSERT INTO user { id: user:jaime, email: 'tobie@surrealdb.com' };
+		UPDATE user:jaime SET email = 'tobie@surrealdb.com';
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 4);
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: user:tobie, email: 'tobie@surrealdb.com' }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp.err(),
+		Some(e) if e.to_string() == r#"Database index `email` already contains 'tobie@surrealdb.com', with record `user:tobie`"#
+	));
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp.err(),
+		Some(e) if e.to_string() == r#"Database index `email` already contains 'tobie@surrealdb.com', with record `user:tobie`"#
+	));
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn define_statement_index_multiple_unique() -> Result<(), Error> {
 	let sql = "
@@ -798,13 +894,13 @@ async fn define_statement_index_multiple_unique() -> Result<(), Error> {
 	let tmp = res.remove(0).result;
 	assert!(matches!(
 		tmp.err(),
-		Some(e) if e.to_string() == r#"Database index `test` already contains ['apple', 'test@surrealdb.com'], with record `user:3`"#
+		Some(e) if e.to_string() == r#"Database index `test` already contains ['apple', 'test@surrealdb.com'], with record `user:1`"#
 	));
 	//
 	let tmp = res.remove(0).result;
 	assert!(matches!(
 		tmp.err(),
-		Some(e) if e.to_string() == r#"Database index `test` already contains ['tesla', 'test@surrealdb.com'], with record `user:4`"#
+		Some(e) if e.to_string() == r#"Database index `test` already contains ['tesla', 'test@surrealdb.com'], with record `user:2`"#
 	));
 	//
 	let tmp = res.remove(0).result;
@@ -817,7 +913,7 @@ async fn define_statement_index_multiple_unique() -> Result<(), Error> {
 	let tmp = res.remove(0).result;
 	assert!(matches!(
 		tmp.err(),
-		Some(e) if e.to_string() == r#"Database index `test` already contains ['tesla', 'test@surrealdb.com'], with record `user:4`"#
+		Some(e) if e.to_string() == r#"Database index `test` already contains ['tesla', 'test@surrealdb.com'], with record `user:2`"#
 	));
 	//
 	let tmp = res.remove(0).result;
@@ -853,13 +949,13 @@ async fn define_statement_index_single_unique_existing() -> Result<(), Error> {
 	let tmp = res.remove(0).result;
 	assert!(matches!(
 		tmp.err(),
-		Some(e) if e.to_string() == r#"Database index `test` already contains 'test@surrealdb.com', with record `user:3`"#
+		Some(e) if e.to_string() == r#"Database index `test` already contains 'test@surrealdb.com', with record `user:2`"#
 	));
 	//
 	let tmp = res.remove(0).result;
 	assert!(matches!(
 		tmp.err(),
-		Some(e) if e.to_string() == r#"Database index `test` already contains 'test@surrealdb.com', with record `user:3`"#
+		Some(e) if e.to_string() == r#"Database index `test` already contains 'test@surrealdb.com', with record `user:2`"#
 	));
 	//
 	let tmp = res.remove(0).result?;
@@ -900,13 +996,13 @@ async fn define_statement_index_multiple_unique_existing() -> Result<(), Error>
 	let tmp = res.remove(0).result;
 	assert!(matches!(
 		tmp.err(),
-		Some(e) if e.to_string() == r#"Database index `test` already contains ['apple', 'test@surrealdb.com'], with record `user:3`"#
+		Some(e) if e.to_string() == r#"Database index `test` already contains ['apple', 'test@surrealdb.com'], with record `user:1`"#
 	));
 	//
 	let tmp = res.remove(0).result;
 	assert!(matches!(
 		tmp.err(),
-		Some(e) if e.to_string() == r#"Database index `test` already contains ['apple', 'test@surrealdb.com'], with record `user:3`"#
+		Some(e) if e.to_string() == r#"Database index `test` already contains ['apple', 'test@surrealdb.com'], with record `user:1`"#
 	));
 
 	let tmp = res.remove(0).result?;
@@ -1025,6 +1121,74 @@ async fn define_statement_index_multiple_unique_embedded_multiple() -> Result<()
 	Ok(())
 }
 
+#[tokio::test]
+async fn define_statement_index_rebuild_after_corruption() -> Result<(), Error> {
+	let sql = "
+		DEFINE INDEX email_idx ON user FIELDS email UNIQUE;
+		CREATE user:1 SET email = 'tobie@surrealdb.com';
+		CREATE user:2 SET email = 'jaime@surrealdb.com';
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 3);
+	for _ in 0..3 {
+		assert!(res.remove(0).result.is_ok());
+	}
+	// Corrupt the index by removing one of its entries
+	let mut tx = dbs.transaction(true, false).await?;
+	let key =
+		Index::new("test", "test", "user", "email_idx", vec!["tobie@surrealdb.com"].into(), None);
+	tx.del(key).await?;
+	tx.commit().await?;
+	//
+	let sql = "
+		SELECT id FROM user WHERE email = 'tobie@surrealdb.com';
+		INFO FOR INDEX email_idx ON user;
+		REBUILD INDEX email_idx ON user;
+		SELECT id FROM user WHERE email = 'tobie@surrealdb.com';
+		INFO FOR INDEX email_idx ON user;
+		REBUILD INDEX missing_idx ON user;
+	";
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 6);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"{
+			definition: 'DEFINE INDEX email_idx ON user FIELDS email UNIQUE',
+			entries: 1,
+		}",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::None;
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: user:1 }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"{
+			definition: 'DEFINE INDEX email_idx ON user FIELDS email UNIQUE',
+			entries: 2,
+		}",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(tmp, Err(Error::IxNotFound { .. })));
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn define_statement_analyzer() -> Result<(), Error> {
 	let sql = "
@@ -1047,6 +1211,7 @@ async fn define_statement_analyzer() -> Result<(), Error> {
 	let tmp = res.remove(0).result?;
 	let val = Value::parse(
 		"{
+			accesses: {},
 			analyzers: {
 				autocomplete: 'DEFINE ANALYZER autocomplete FILTERS LOWERCASE,EDGENGRAM(2,10)',
 				english: 'DEFINE ANALYZER english TOKENIZERS BLANK,CLASS FILTERS LOWERCASE,SNOWBALL(ENGLISH)',
@@ -1133,3 +1298,40 @@ where
 		check(v);
 	}
 }
+
+#[tokio::test]
+async fn define_statement_validate_record() -> Result<(), Error> {
+	let sql = "
+		DEFINE TABLE person SCHEMAFULL;
+		DEFINE FIELD name ON person TYPE string;
+		DEFINE FIELD age ON person TYPE number ASSERT $value >= 18;
+		DEFINE FIELD email ON person TYPE string ASSERT string::is::email($value);
+		DEFINE FIELD active ON person VALUE $value OR false;
+	";
+	let mut tb = None;
+	let mut fds = vec![];
+	for stm in surrealdb::sql::parse(sql)?.into_iter() {
+		match stm {
+			Statement::Define(DefineStatement::Table(v)) => tb = Some(v),
+			Statement::Define(DefineStatement::Field(v)) => fds.push(v),
+			_ => unreachable!(),
+		}
+	}
+	let tb = tb.unwrap();
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	//
+	let val = Value::parse("{ name: 'Tobie', age: 33, email: 'tobie@surrealdb.com', other: true }");
+	let tmp = dbs.validate_record(&ses, val, &tb, &fds).await.unwrap();
+	let val =
+		Value::parse("{ name: 'Tobie', age: 33, email: 'tobie@surrealdb.com', active: false }");
+	assert_eq!(tmp, val);
+	//
+	let val = Value::parse("{ name: 'Tobie', age: 'old', email: 'tobie', active: true }");
+	let tmp = dbs.validate_record(&ses, val, &tb, &fds).await.unwrap_err();
+	assert_eq!(tmp.len(), 2, "{tmp:?}");
+	assert!(matches!(&tmp[0], Error::FieldCheck { field, .. } if field.to_string() == "age"));
+	assert!(matches!(&tmp[1], Error::FieldValue { field, .. } if field.to_string() == "email"));
+	//
+	Ok(())
+}
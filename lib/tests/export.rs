@@ -0,0 +1,71 @@
+mod parse;
+use parse::Parse;
+use surrealdb::dbs::Session;
+use surrealdb::err::Error;
+use surrealdb::kvs::Datastore;
+use surrealdb::sql::Value;
+
+#[tokio::test]
+async fn export_import_round_trip() -> Result<(), Error> {
+	let sql = "
+		DEFINE TABLE person SCHEMAFULL;
+		DEFINE FIELD name ON person TYPE string;
+		DEFINE INDEX name ON person FIELDS name UNIQUE;
+		CREATE person:tobie SET name = 'Tobie';
+		CREATE person:jaime SET name = 'Jaime';
+		RELATE person:tobie->knows->person:jaime SET since = '2020-01-01';
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 6);
+	for _ in 0..6 {
+		res.remove(0).result?;
+	}
+	//
+	let mut buf = Vec::new();
+	dbs.export_to("test", "test", &mut buf).await?;
+	let dump = String::from_utf8(buf).unwrap();
+	assert!(dump.contains("DEFINE TABLE person SCHEMAFULL"), "{dump}");
+	//
+	let new = Datastore::new("memory").await?;
+	new.import("test", "test", dump.as_bytes()).await?;
+	//
+	let sql = "
+		SELECT * FROM person;
+		SELECT in, out, since FROM knows;
+		CREATE person:other SET name = 'Tobie';
+	";
+	for dbs in [&dbs, &new] {
+		let res = &mut dbs.execute(sql, &ses, None).await?;
+		assert_eq!(res.len(), 3);
+		//
+		let tmp = res.remove(0).result?;
+		let val = Value::parse(
+			"[
+				{ id: person:jaime, name: 'Jaime' },
+				{ id: person:tobie, name: 'Tobie' },
+			]",
+		);
+		assert_eq!(tmp, val);
+		//
+		let tmp = res.remove(0).result?;
+		let val = Value::parse("[{ in: person:tobie, out: person:jaime, since: '2020-01-01' }]");
+		assert_eq!(tmp, val);
+		// The unique index is also restored
+		let tmp = res.remove(0).result;
+		assert!(matches!(tmp, Err(Error::IndexExists { .. })), "{tmp:?}");
+	}
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn import_reports_statement_errors() -> Result<(), Error> {
+	let dbs = Datastore::new("memory").await?;
+	let sql = "CREATE person:tobie; CREATE person:tobie;";
+	let res = dbs.import("test", "test", sql.as_bytes()).await;
+	assert!(matches!(res, Err(Error::RecordExists { .. })), "{res:?}");
+	//
+	Ok(())
+}
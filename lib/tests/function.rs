@@ -331,6 +331,28 @@ async fn function_array_combine() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_array_chunk() -> Result<(), Error> {
+	test_queries(
+		r#"
+		RETURN array::chunk([0, 1, 2, 3], 2);
+		RETURN array::chunk([0, 1, 2, 3, 4], 2);
+		RETURN array::chunk([0, 1, 2], 5);
+		RETURN array::chunk([], 2);
+	"#,
+		&["[[0, 1], [2, 3]]", "[[0, 1], [2, 3], [4]]", "[[0, 1, 2]]", "[]"],
+	)
+	.await?;
+	check_test_is_error(
+		r#"
+		RETURN array::chunk([0, 1, 2], 0);
+	"#,
+		&["Incorrect arguments for function array::chunk(). The second argument must be an integer greater than 0."],
+	)
+	.await?;
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_array_clump() -> Result<(), Error> {
 	let sql = r#"
@@ -557,6 +579,43 @@ async fn function_array_flatten() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_array_flatten_depth() -> Result<(), Error> {
+	let sql = r#"
+		RETURN array::flatten([[1, [2, [3]]], [4]]);
+		RETURN array::flatten([[1, [2, [3]]], [4]], 1);
+		RETURN array::flatten([[1, [2, [3]]], [4]], 2);
+		RETURN array::flatten([[1, [2, [3]]], [4]], 10);
+		RETURN array::flatten([[1, [2, [3]]], [4]], 0);
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 5);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[1, [2, [3]], 4]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[1, [2, [3]], 4]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[1, 2, [3], 4]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[1, 2, 3, 4]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[[1, [2, [3]]], [4]]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_array_group() -> Result<(), Error> {
 	let sql = r#"
@@ -621,6 +680,38 @@ async fn function_array_insert() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_array_insert_at() -> Result<(), Error> {
+	let sql = r#"
+		RETURN array::insert_at([], 0, 1);
+		RETURN array::insert_at([1,2,3], 3, 4);
+		RETURN array::insert_at([1,2,3], -1, 4);
+		RETURN array::insert_at([1,2,3], 10, 4);
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 4);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[1]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[1,2,3,4]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[1,2,4,3]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[1,2,3,4]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_array_intersect() -> Result<(), Error> {
 	let sql = r#"
@@ -856,6 +947,38 @@ async fn function_array_min() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_array_move() -> Result<(), Error> {
+	let sql = r#"
+		RETURN array::move([], 0, 1);
+		RETURN array::move([1,2,3,4], 0, 2);
+		RETURN array::move([1,2,3,4], -1, 0);
+		RETURN array::move([1,2,3,4], 1, 10);
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 4);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[2,3,1,4]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[4,1,2,3]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[1,3,4,2]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_array_pop() -> Result<(), Error> {
 	let sql = r#"
@@ -984,6 +1107,38 @@ async fn function_array_remove() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_array_remove_at() -> Result<(), Error> {
+	let sql = r#"
+		RETURN array::remove_at([], 0);
+		RETURN array::remove_at([1,2,3,4], 1);
+		RETURN array::remove_at([1,2,3,4], -1);
+		RETURN array::remove_at([1,2,3,4], -10);
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 4);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[1,3,4]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[1,2,3]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[2,3,4]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_array_reverse() -> Result<(), Error> {
 	let sql = r#"
@@ -1016,6 +1171,68 @@ async fn function_array_reverse() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_array_sample() -> Result<(), Error> {
+	let sql = r#"
+		RETURN array::sample([1,2,3,4,5], 2);
+		RETURN array::sample([1,2,3,4,5], 5);
+		RETURN array::sample([1,2,3,4,5], 10);
+		RETURN array::sample([], 3);
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 4);
+	//
+	for len in [2, 5, 5] {
+		let tmp = res.remove(0).result?;
+		let Value::Array(arr) = tmp else {
+			panic!("Expected an array");
+		};
+		assert_eq!(arr.len(), len);
+		assert!(arr.iter().enumerate().all(|(i, v)| !arr[..i].contains(v)));
+		assert!(arr.iter().all(|v| matches!(v, Value::Number(n) if (1..=5).contains(&n.to_int()))));
+	}
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_array_skip() -> Result<(), Error> {
+	let sql = r#"
+		RETURN array::skip([], 2);
+		RETURN array::skip([1,2,3,4], 1);
+		RETURN array::skip([1,2,3,4], 10);
+		RETURN array::skip([1,2,3,4], -2);
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 4);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[2,3,4]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[1,2,3,4]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_array_slice() -> Result<(), Error> {
 	let sql = r#"
@@ -1120,6 +1337,90 @@ async fn function_array_sort() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_array_sort_by_key() -> Result<(), Error> {
+	let sql = r#"
+		LET $films = [{ name: 'a', rating: 3 }, { name: 'b' }, { name: 'c', rating: 5 }, { name: 'd', rating: 1 }];
+		RETURN array::sort($films, { key: "rating" });
+		RETURN array::sort($films, { key: "rating" }, "asc");
+		RETURN array::sort($films, { key: "rating" }, "desc");
+		RETURN array::sort($films, { key: "rating" }, false);
+		RETURN array::sort([{ a: { b: 2 } }, { a: { b: 1 } }], { key: "a.b" });
+		RETURN array::sort([{ desc: 2 }, { desc: 1 }], { key: "desc" });
+		RETURN array::sort($films, { key: "rating." });
+		RETURN array::sort($films, { path: "rating" });
+		RETURN array::sort($films, { key: "rating" }, "DESC");
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 10);
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[{ name: 'd', rating: 1 }, { name: 'a', rating: 3 }, { name: 'c', rating: 5 }, { name: 'b' }]",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[{ name: 'd', rating: 1 }, { name: 'a', rating: 3 }, { name: 'c', rating: 5 }, { name: 'b' }]",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[{ name: 'c', rating: 5 }, { name: 'a', rating: 3 }, { name: 'd', rating: 1 }, { name: 'b' }]",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[{ name: 'c', rating: 5 }, { name: 'a', rating: 3 }, { name: 'd', rating: 1 }, { name: 'b' }]",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ a: { b: 1 } }, { a: { b: 2 } }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ desc: 1 }, { desc: 2 }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result;
+	assert!(
+		matches!(
+			&tmp,
+			Err(e) if e.to_string() == "Incorrect arguments for function array::sort(). The key 'rating.' is not a valid field path."
+		),
+		"{tmp:?}"
+	);
+	//
+	let tmp = res.remove(0).result;
+	assert!(
+		matches!(
+			&tmp,
+			Err(e) if e.to_string() == "Incorrect arguments for function array::sort(). The key must be given as an object with a string path, such as { key: 'rating' }."
+		),
+		"{tmp:?}"
+	);
+	//
+	let tmp = res.remove(0).result;
+	assert!(
+		matches!(
+			&tmp,
+			Err(e) if e.to_string() == "Incorrect arguments for function array::sort(). The direction must be one of 'asc', 'desc', true or false, but found 'DESC'."
+		),
+		"{tmp:?}"
+	);
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_array_sort_asc() -> Result<(), Error> {
 	let sql = r#"
@@ -1184,6 +1485,38 @@ async fn function_array_sort_desc() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_array_take() -> Result<(), Error> {
+	let sql = r#"
+		RETURN array::take([], 2);
+		RETURN array::take([1,2,3,4], 1);
+		RETURN array::take([1,2,3,4], 10);
+		RETURN array::take([1,2,3,4], -2);
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 4);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[1]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[1,2,3,4]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_array_transpose() -> Result<(), Error> {
 	let sql = r#"
@@ -1236,6 +1569,28 @@ async fn function_array_union() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_array_windows() -> Result<(), Error> {
+	test_queries(
+		r#"
+		RETURN array::windows([0, 1, 2, 3], 2);
+		RETURN array::windows([0, 1, 2, 3, 4], 3);
+		RETURN array::windows([0, 1, 2], 3);
+		RETURN array::windows([0, 1, 2], 5);
+	"#,
+		&["[[0, 1], [1, 2], [2, 3]]", "[[0, 1, 2], [1, 2, 3], [2, 3, 4]]", "[[0, 1, 2]]", "[]"],
+	)
+	.await?;
+	check_test_is_error(
+		r#"
+		RETURN array::windows([0, 1, 2], 0);
+	"#,
+		&["Incorrect arguments for function array::windows(). The second argument must be an integer greater than 0."],
+	)
+	.await?;
+	Ok(())
+}
+
 // --------------------------------------------------
 // bytes
 // --------------------------------------------------
@@ -1390,6 +1745,166 @@ async fn function_crypto_sha512() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_crypto_encrypt_decrypt() -> Result<(), Error> {
+	let sql = r#"
+		LET $key = '01234567890123456789012345678901';
+		LET $blob = crypto::encrypt('aes-256-gcm', $key, 'tobie');
+		RETURN $blob != crypto::encrypt('aes-256-gcm', $key, 'tobie');
+		RETURN crypto::decrypt('aes-256-gcm', $key, $blob);
+		RETURN crypto::decrypt('aes-256-gcm', '10987654321098765432109876543210', $blob);
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 5);
+	//
+	let tmp = res.remove(0).result?;
+	assert_eq!(tmp, Value::None);
+	//
+	let tmp = res.remove(0).result?;
+	assert_eq!(tmp, Value::None);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from(true);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from("tobie");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(tmp, Err(Error::InvalidFunction { .. })));
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_crypto_decrypt_tampered() -> Result<(), Error> {
+	let key = "01234567890123456789012345678901";
+	let sql = format!("RETURN crypto::encrypt('aes-256-gcm', '{key}', 'tobie');");
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None).await?;
+	let mut blob = res.remove(0).result?.as_raw_string().into_bytes();
+	// Modify a single character within the ciphertext
+	blob[20] = if blob[20] == b'A' {
+		b'B'
+	} else {
+		b'A'
+	};
+	let blob = String::from_utf8(blob).unwrap();
+	let sql = format!("RETURN crypto::decrypt('aes-256-gcm', '{key}', '{blob}');");
+	let res = &mut dbs.execute(&sql, &ses, None).await?;
+	assert_eq!(res.len(), 1);
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(tmp, Err(Error::InvalidFunction { .. })));
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_crypto_totp_generate() -> Result<(), Error> {
+	let sql = r#"
+		LET $secret = "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ";
+		RETURN crypto::totp::generate($secret, { digits: 8, time: "1970-01-01T00:00:59Z" });
+		RETURN crypto::totp::generate($secret, { digits: 8, time: "2005-03-18T01:58:29Z" });
+		RETURN crypto::totp::generate($secret, { digits: 8, time: "2009-02-13T23:31:30Z" });
+		RETURN crypto::totp::generate($secret, { digits: 8, time: "2033-05-18T03:33:20Z" });
+		RETURN crypto::totp::generate($secret, { time: "1970-01-01T00:00:59Z" });
+		RETURN crypto::totp::generate($secret, { digits: 12 });
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 7);
+	//
+	let tmp = res.remove(0).result?;
+	assert_eq!(tmp, Value::None);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from("94287082");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from("07081804");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from("89005924");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from("69279037");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from("287082");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result;
+	assert!(
+		matches!(
+			&tmp,
+			Err(e) if e.to_string() == "Incorrect arguments for function crypto::totp::generate(). The digits must be an integer from 6 to 8."
+		),
+		"{tmp:?}"
+	);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_crypto_totp_verify() -> Result<(), Error> {
+	let sql = r#"
+		LET $secret = "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ";
+		RETURN crypto::totp::verify($secret, "287082", { time: "1970-01-01T00:00:59Z" });
+		RETURN crypto::totp::verify($secret, "287082", { time: "1970-01-01T00:01:29Z" });
+		RETURN crypto::totp::verify($secret, "287082", { time: "1970-01-01T00:01:29Z", window: 0 });
+		RETURN crypto::totp::verify($secret, "287082", { time: "1970-01-01T00:02:00Z" });
+		RETURN crypto::totp::verify($secret, "287083", { time: "1970-01-01T00:00:59Z" });
+		RETURN crypto::totp::verify($secret, crypto::totp::generate($secret));
+		RETURN crypto::totp::verify($secret, "287082", { window: 1000000000 });
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 8);
+	//
+	let tmp = res.remove(0).result?;
+	assert_eq!(tmp, Value::None);
+	//
+	let tmp = res.remove(0).result?;
+	assert_eq!(tmp, Value::Bool(true));
+	//
+	let tmp = res.remove(0).result?;
+	assert_eq!(tmp, Value::Bool(true));
+	//
+	let tmp = res.remove(0).result?;
+	assert_eq!(tmp, Value::Bool(false));
+	//
+	let tmp = res.remove(0).result?;
+	assert_eq!(tmp, Value::Bool(false));
+	//
+	let tmp = res.remove(0).result?;
+	assert_eq!(tmp, Value::Bool(false));
+	//
+	let tmp = res.remove(0).result?;
+	assert_eq!(tmp, Value::Bool(true));
+	//
+	let tmp = res.remove(0).result;
+	assert!(
+		matches!(
+			&tmp,
+			Err(e) if e.to_string() == "Incorrect arguments for function crypto::totp::verify(). The window must be an integer from 0 to 10."
+		),
+		"{tmp:?}"
+	);
+	//
+	Ok(())
+}
+
 // --------------------------------------------------
 // duration
 // --------------------------------------------------
@@ -1973,6 +2488,59 @@ async fn function_parse_geo_distance() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_parse_geo_distance_and_bearing_points() -> Result<(), Error> {
+	let sql = r#"
+		RETURN geo::distance((0, 0), (1, 0));
+		RETURN geo::bearing((0, 0), (0, 1));
+		RETURN geo::bearing((0, 0), (1, 0));
+		CREATE place:london SET loc = (-0.136439, 51.509865);
+		SELECT VALUE geo::distance(loc, (-73.971321, 40.776676)) FROM place;
+		SELECT VALUE geo::bearing(loc, (-73.971321, 40.776676)) FROM place;
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 6);
+	//
+	let Value::Number(tmp) = res.remove(0).result? else {
+		panic!("expected a number");
+	};
+	let tmp = tmp.as_float();
+	assert!((tmp - 111195.08).abs() < 1.0, "{tmp}");
+	//
+	let Value::Number(tmp) = res.remove(0).result? else {
+		panic!("expected a number");
+	};
+	let tmp = tmp.as_float();
+	assert!(tmp.abs() < 0.001, "{tmp}");
+	//
+	let Value::Number(tmp) = res.remove(0).result? else {
+		panic!("expected a number");
+	};
+	let tmp = tmp.as_float();
+	assert!((tmp - 90.0).abs() < 0.001, "{tmp}");
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result?;
+	let Value::Number(tmp) = tmp.first() else {
+		panic!("expected a number");
+	};
+	let tmp = tmp.as_float();
+	assert!((tmp - 5562851.11).abs() < 1.0, "{tmp}");
+	//
+	let tmp = res.remove(0).result?;
+	let Value::Number(tmp) = tmp.first() else {
+		panic!("expected a number");
+	};
+	let tmp = tmp.as_float();
+	assert!((tmp + 71.634).abs() < 0.001, "{tmp}");
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_parse_geo_hash_encode() -> Result<(), Error> {
 	let sql = r#"
@@ -1996,25 +2564,92 @@ async fn function_parse_geo_hash_encode() -> Result<(), Error> {
 #[tokio::test]
 async fn function_parse_geo_hash_decode() -> Result<(), Error> {
 	let sql = r#"
-		RETURN geo::hash::decode('gcpvhchdswz9');
+		RETURN geo::hash::decode('gcpvhchdswz9');
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 1);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"{
+			type: 'Point',
+			coordinates: [
+				-0.13643911108374596,
+				51.50986502878368
+			]
+		}",
+	);
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_parse_geo_simplify() -> Result<(), Error> {
+	let sql = r#"
+		RETURN geo::simplify({
+			type: 'LineString',
+			coordinates: [[0.0, 0.0], [5.0, 4.0], [11.0, 5.5], [17.3, 3.2], [27.8, 0.1]]
+		}, 1.0);
+		RETURN geo::simplify({
+			type: 'LineString',
+			coordinates: [
+				[0.0, 0.0], [1.0, 0.01], [2.0, -0.01], [3.0, 0.02], [4.0, -0.02],
+				[5.0, 0.01], [6.0, -0.01], [7.0, 0.02], [8.0, -0.02], [9.0, 0.0]
+			]
+		}, 0.1);
+		RETURN geo::simplify({
+			type: 'Point',
+			coordinates: [-0.136439, 51.509865]
+		}, 1.0);
+		RETURN geo::simplify({
+			type: 'LineString',
+			coordinates: [[0.0, 0.0], [1.0, 0.0]]
+		}, -1.0);
 	"#;
 	let dbs = Datastore::new("memory").await?;
 	let ses = Session::for_kv().with_ns("test").with_db("test");
 	let res = &mut dbs.execute(sql, &ses, None).await?;
-	assert_eq!(res.len(), 1);
+	assert_eq!(res.len(), 4);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"{
+			type: 'LineString',
+			coordinates: [[0.0, 0.0], [5.0, 4.0], [11.0, 5.5], [27.8, 0.1]]
+		}",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"{
+			type: 'LineString',
+			coordinates: [[0.0, 0.0], [9.0, 0.0]]
+		}",
+	);
+	assert_eq!(tmp, val);
 	//
 	let tmp = res.remove(0).result?;
 	let val = Value::parse(
 		"{
 			type: 'Point',
-			coordinates: [
-				-0.13643911108374596,
-				51.50986502878368
-			]
+			coordinates: [-0.136439, 51.509865]
 		}",
 	);
 	assert_eq!(tmp, val);
 	//
+	let tmp = res.remove(0).result;
+	assert!(
+		matches!(
+			&tmp,
+			Err(e) if e.to_string() == "Incorrect arguments for function geo::simplify(). The tolerance must be a number greater than or equal to 0."
+		),
+		"{tmp:?}"
+	);
+	//
 	Ok(())
 }
 
@@ -2755,6 +3390,31 @@ async fn function_math_round() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_math_round_precision() -> Result<(), Error> {
+	test_queries(
+		r#"
+		RETURN math::round(1.345, 2);
+		RETURN math::round(-1.345, 2);
+		RETURN math::round(1.345dec, 2);
+		RETURN math::round(1234.5, 0);
+		RETURN math::round(1234.5, -1);
+		RETURN math::round(1250, -2);
+		RETURN math::round(1234, -3);
+		RETURN math::floor(1.345, 2);
+		RETURN math::floor(1299, -2);
+		RETURN math::ceil(1.341, 2);
+		RETURN math::ceil(1201, -2);
+	"#,
+		&[
+			"1.35", "-1.35", "1.35dec", "1235", "1230", "1300", "1000", "1.34", "1200", "1.35",
+			"1300",
+		],
+	)
+	.await?;
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_math_spread() -> Result<(), Error> {
 	let sql = r#"
@@ -3545,6 +4205,53 @@ async fn function_string_contains() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_string_count() -> Result<(), Error> {
+	let sql = r#"
+		RETURN string::count("abababab", "ab");
+		RETURN string::count("aaaa", "aa");
+		RETURN string::count("aaaa", "aa", { overlapping: true });
+		RETURN string::count("中华中华中", "中华中", { overlapping: true });
+		RETURN string::count("abcdefg", "xyz");
+		RETURN string::count("abcdefg", "");
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 6);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from(4);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from(2);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from(3);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from(2);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from(0);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result;
+	assert!(
+		matches!(
+			&tmp,
+			Err(e) if e.to_string() == "Incorrect arguments for function string::count(). The substring to count must not be empty."
+		),
+		"{tmp:?}"
+	);
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_string_ends_with() -> Result<(), Error> {
 	let sql = r#"
@@ -3680,6 +4387,40 @@ async fn function_string_repeat() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_string_matches() -> Result<(), Error> {
+	let sql = r#"
+		RETURN string::matches("555-1234", /\d{3}-\d{4}/);
+		RETURN string::matches("call 555-1234 now", '^[0-9]{3}-[0-9]{4}$');
+		RETURN string::matches("call 555-1234 now", '[0-9]{3}-[0-9]{4}');
+		RETURN string::matches("555-1234", '[0-9');
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 4);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::Bool(true);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::Bool(false);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::Bool(true);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp.err(),
+		Some(e) if e.to_string() == "Incorrect arguments for function string::matches(). The pattern '[0-9' is not a valid regular expression."
+	));
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_string_replace() -> Result<(), Error> {
 	let sql = r#"
@@ -3707,17 +4448,54 @@ async fn function_string_replace() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_string_replace_regex() -> Result<(), Error> {
+	let sql = r#"
+		RETURN string::replace_regex("555-1234", /(\d{3})-(\d{4})/, '$2-$1');
+		RETURN string::replace_regex("call 555-1234 or 555-9876", '[0-9]{3}-([0-9]{4})', 'XXX-$1');
+		RETURN string::replace_regex("no numbers here", '[0-9]+', '#');
+		RETURN string::replace_regex("555-1234", '(', '');
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 4);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from("1234-555");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from("call XXX-1234 or XXX-9876");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from("no numbers here");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp.err(),
+		Some(e) if e.to_string() == "Incorrect arguments for function string::replace_regex(). The pattern '(' is not a valid regular expression."
+	));
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_string_reverse() -> Result<(), Error> {
 	let sql = r#"
 		RETURN string::reverse("");
 		RETURN string::reverse("test");
 		RETURN string::reverse("test this string");
+		RETURN string::reverse("noe\u{0301}l");
+		RETURN string::reverse("a👨‍👩‍👧");
+		RETURN string::reverse("🇬🇧🇫🇷");
 	"#;
 	let dbs = Datastore::new("memory").await?;
 	let ses = Session::for_kv().with_ns("test").with_db("test");
 	let res = &mut dbs.execute(sql, &ses, None).await?;
-	assert_eq!(res.len(), 3);
+	assert_eq!(res.len(), 6);
 	//
 	let tmp = res.remove(0).result?;
 	let val = Value::from("");
@@ -3731,6 +4509,50 @@ async fn function_string_reverse() -> Result<(), Error> {
 	let val = Value::from("gnirts siht tset");
 	assert_eq!(tmp, val);
 	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from("le\u{0301}on");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from("👨‍👩‍👧a");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from("🇫🇷🇬🇧");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_string_fuzzy_search() -> Result<(), Error> {
+	let sql = r#"
+		RETURN string::fuzzy::search("abc", ["aXbXc", "abc", "xyz"]);
+		RETURN string::fuzzy::search("abc", []);
+		RETURN string::fuzzy::search("abc", ["xyz", 123]);
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None).await?;
+	assert_eq!(res.len(), 3);
+	//
+	let tmp = res.remove(0).result?;
+	let Value::Array(arr) = tmp else {
+		panic!("expected an array of matches");
+	};
+	assert_eq!(arr.len(), 2);
+	assert_eq!(arr[0].pick(&["value".into()]), Value::from("abc"));
+	assert_eq!(arr[1].pick(&["value".into()]), Value::from("aXbXc"));
+	assert!(arr[0].pick(&["score".into()]) > arr[1].pick(&["score".into()]));
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[]");
+	assert_eq!(tmp, val);
+	//
 	Ok(())
 }
 
@@ -3766,6 +4588,41 @@ async fn function_string_similarity_fuzzy() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_string_similarity_jaro() -> Result<(), Error> {
+	test_queries(
+		r#"
+		RETURN string::similarity::jaro("", "");
+		RETURN string::similarity::jaro("some", "");
+		RETURN string::similarity::jaro("text", "text");
+		RETURN string::similarity::jaro("abc", "xyz");
+		RETURN math::fixed(string::similarity::jaro("martha", "marhta"), 3);
+		RETURN math::fixed(string::similarity::jaro("dixon", "dicksonx"), 3);
+	"#,
+		&["1.0", "0.0", "1.0", "0.0", "0.944", "0.767"],
+	)
+	.await?;
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_string_similarity_jaro_winkler() -> Result<(), Error> {
+	test_queries(
+		r#"
+		RETURN string::similarity::jaro_winkler("", "");
+		RETURN string::similarity::jaro_winkler("text", "text");
+		RETURN string::similarity::jaro_winkler("abc", "xyz");
+		RETURN math::fixed(string::similarity::jaro_winkler("martha", "marhta"), 3);
+		RETURN math::fixed(string::similarity::jaro_winkler("dixon", "dicksonx"), 3);
+		RETURN string::similarity::jaro_winkler("hello world", "hello wrold") > 0.9;
+		RETURN string::similarity::jaro_winkler("hello world", "goodbye") < 0.5;
+	"#,
+		&["1.0", "1.0", "0.0", "0.961", "0.813", "true", "true"],
+	)
+	.await?;
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_string_similarity_smithwaterman() -> Result<(), Error> {
 	let sql = r#"
@@ -3851,11 +4708,12 @@ async fn function_string_slug() -> Result<(), Error> {
 		RETURN string::slug("");
 		RETURN string::slug("this is a test");
 		RETURN string::slug("blog - this is a test with 😀 emojis");
+		RETURN string::slug("Crème Brûlée à la Café");
 	"#;
 	let dbs = Datastore::new("memory").await?;
 	let ses = Session::for_kv().with_ns("test").with_db("test");
 	let res = &mut dbs.execute(sql, &ses, None).await?;
-	assert_eq!(res.len(), 3);
+	assert_eq!(res.len(), 4);
 	//
 	let tmp = res.remove(0).result?;
 	let val = Value::from("");
@@ -3869,6 +4727,10 @@ async fn function_string_slug() -> Result<(), Error> {
 	let val = Value::from("blog-this-is-a-test-with-grinning-emojis");
 	assert_eq!(tmp, val);
 	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from("creme-brulee-a-la-cafe");
+	assert_eq!(tmp, val);
+	//
 	Ok(())
 }
 
@@ -4017,11 +4879,15 @@ async fn function_time_ceil() -> Result<(), Error> {
 		RETURN time::ceil("1987-06-22T08:30:45Z", 1w);
 		RETURN time::ceil("1987-06-22T08:30:45Z", 1y);
 		RETURN time::ceil("2023-05-11T03:09:00Z", 1s);
+		RETURN time::ceil("2023-05-11T03:09:00Z", 1h);
+		RETURN time::ceil("2023-05-11T03:09:00Z", 1d);
+		RETURN time::ceil("2023-05-11T03:00:00Z", 1h);
+		RETURN time::ceil("2023-05-11T00:00:00Z", 1d);
 	"#;
 	let dbs = Datastore::new("memory").await?;
 	let ses = Session::for_kv().with_ns("test").with_db("test");
 	let res = &mut dbs.execute(sql, &ses, None).await?;
-	assert_eq!(res.len(), 3);
+	assert_eq!(res.len(), 7);
 	//
 	let tmp = res.remove(0).result?;
 	let val = Value::parse("'1987-06-25T00:00:00Z'");
@@ -4035,6 +4901,22 @@ async fn function_time_ceil() -> Result<(), Error> {
 	let val = Value::parse("'2023-05-11T03:09:00Z'");
 	assert_eq!(tmp, val);
 	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("'2023-05-11T04:00:00Z'");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("'2023-05-12T00:00:00Z'");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("'2023-05-11T03:00:00Z'");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("'2023-05-11T00:00:00Z'");
+	assert_eq!(tmp, val);
+	//
 	Ok(())
 }
 
@@ -4065,11 +4947,15 @@ async fn function_time_floor() -> Result<(), Error> {
 		RETURN time::floor("1987-06-22T08:30:45Z", 1w);
 		RETURN time::floor("1987-06-22T08:30:45Z", 1y);
 		RETURN time::floor("2023-05-11T03:09:00Z", 1s);
+		RETURN time::floor("2023-05-11T03:09:00Z", 1h);
+		RETURN time::floor("2023-05-11T03:09:00Z", 1d);
+		RETURN time::floor("2023-05-11T03:00:00Z", 1h);
+		RETURN time::floor("2023-05-11T00:00:00Z", 1d);
 	"#;
 	let dbs = Datastore::new("memory").await?;
 	let ses = Session::for_kv().with_ns("test").with_db("test");
 	let res = &mut dbs.execute(sql, &ses, None).await?;
-	assert_eq!(res.len(), 3);
+	assert_eq!(res.len(), 7);
 	//
 	let tmp = res.remove(0).result?;
 	let val = Value::parse("'1987-06-18T00:00:00Z'");
@@ -4083,6 +4969,22 @@ async fn function_time_floor() -> Result<(), Error> {
 	let val = Value::parse("'2023-05-11T03:09:00Z'");
 	assert_eq!(tmp, val);
 	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("'2023-05-11T03:00:00Z'");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("'2023-05-11T00:00:00Z'");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("'2023-05-11T03:00:00Z'");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("'2023-05-11T00:00:00Z'");
+	assert_eq!(tmp, val);
+	//
 	Ok(())
 }
 
@@ -4151,6 +5053,66 @@ async fn function_time_hour() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_time_interval() -> Result<(), Error> {
+	let sql = r#"
+		RETURN time::interval("2023-01-01T00:00:00Z", "2023-01-01T03:00:00Z", 1h);
+		RETURN time::interval("2023-01-01T00:00:00Z", "2023-01-03T12:00:00Z", 1d);
+		RETURN time::interval("2023-01-01T00:00:00Z", "2023-01-01T00:00:00Z", 1h);
+		RETURN time::interval("2023-01-02T00:00:00Z", "2023-01-01T00:00:00Z", 1h);
+		RETURN time::interval("2023-01-01T00:00:00Z", "2023-01-02T00:00:00Z", 0s);
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 5);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			'2023-01-01T00:00:00Z',
+			'2023-01-01T01:00:00Z',
+			'2023-01-01T02:00:00Z',
+			'2023-01-01T03:00:00Z',
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			'2023-01-01T00:00:00Z',
+			'2023-01-02T00:00:00Z',
+			'2023-01-03T00:00:00Z',
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("['2023-01-01T00:00:00Z']");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result;
+	assert!(
+		matches!(
+			&tmp,
+			Err(e) if e.to_string() == "Incorrect arguments for function time::interval(). The first argument must be a datetime which is not after the second argument."
+		),
+		"{tmp:?}"
+	);
+	//
+	let tmp = res.remove(0).result;
+	assert!(
+		matches!(
+			&tmp,
+			Err(e) if e.to_string() == "Incorrect arguments for function time::interval(). The third argument must be a duration greater than zero."
+		),
+		"{tmp:?}"
+	);
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_time_min() -> Result<(), Error> {
 	let sql = r#"
@@ -459,3 +459,112 @@ async fn select_multi_aggregate_composed() -> Result<(), Error> {
 	//
 	Ok(())
 }
+
+#[tokio::test]
+async fn select_group_by_time_bucket() -> Result<(), Error> {
+	let sql = "
+		CREATE reading:1 SET value = 1, time = '2023-05-11T03:00:00Z';
+		CREATE reading:2 SET value = 2, time = '2023-05-11T03:45:12Z';
+		CREATE reading:3 SET value = 3, time = '2023-05-11T04:15:00Z';
+		CREATE reading:4 SET value = 4, time = '2023-05-12T00:00:00Z';
+		SELECT count() AS total, math::sum(value) AS value, time::floor(time, 1h) AS hour FROM reading GROUP BY hour;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 5);
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				hour: '2023-05-11T03:00:00Z',
+				total: 2,
+				value: 3
+			},
+			{
+				hour: '2023-05-11T04:00:00Z',
+				total: 1,
+				value: 3
+			},
+			{
+				hour: '2023-05-12T00:00:00Z',
+				total: 1,
+				value: 4
+			}
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn select_group_by_computed_none_bucket() -> Result<(), Error> {
+	let sql = "
+		CREATE reading:1 SET value = 1, time = '2023-05-11T03:00:00Z';
+		CREATE reading:2 SET value = 2, time = '2023-05-11T03:45:12Z';
+		CREATE reading:3 SET value = 6, time = '2023-05-11T04:15:00Z';
+		CREATE reading:4 SET value = 4;
+		CREATE reading:5 SET value = 8;
+		SELECT count() AS total, math::sum(value) AS sum, math::mean(value) AS mean, IF time THEN time::floor(time, 1h) END AS hour FROM reading GROUP BY hour;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 6);
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				hour: NONE,
+				mean: 6,
+				sum: 12,
+				total: 2
+			},
+			{
+				hour: '2023-05-11T03:00:00Z',
+				mean: 1.5,
+				sum: 3,
+				total: 2
+			},
+			{
+				hour: '2023-05-11T04:00:00Z',
+				mean: 6,
+				sum: 6,
+				total: 1
+			}
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
@@ -0,0 +1,22 @@
+use std::time::Duration;
+use surrealdb::err::Error;
+use surrealdb::kvs::Datastore;
+
+#[tokio::test]
+async fn datastore_health_and_version() -> Result<(), Error> {
+	let dbs = Datastore::new("memory").await?;
+	// A fresh datastore is healthy
+	dbs.health().await?;
+	// The version is a semver string
+	let version = dbs.version();
+	let (release, _) = version.split_once('-').unwrap_or((version, ""));
+	let parts: Vec<&str> = release.split('.').collect();
+	assert_eq!(parts.len(), 3, "{version}");
+	assert!(parts.iter().all(|v| v.parse::<u64>().is_ok()), "{version}");
+	// The datastore is no longer healthy once shut down
+	dbs.shutdown(Duration::from_secs(1)).await?;
+	let res = dbs.health().await;
+	assert!(matches!(res, Err(Error::DsShutdown)), "{res:?}");
+	//
+	Ok(())
+}
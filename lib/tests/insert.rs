@@ -142,6 +142,33 @@ async fn insert_statement_on_duplicate_key() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn insert_statement_on_duplicate_key_update_expressions() -> Result<(), Error> {
+	let sql = "
+		INSERT INTO user (id, name, visits) VALUES ('tobie', 'Tobie', 1) ON DUPLICATE KEY UPDATE name = $input.name, visits += 1;
+		INSERT INTO user (id, name, visits) VALUES ('tobie', 'Tobie Morgan', 1) ON DUPLICATE KEY UPDATE name = $input.name, visits += 1;
+		INSERT INTO user (id, name, visits) VALUES ('tobie', 'Tobie Morgan Hitchcock', 1) ON DUPLICATE KEY UPDATE name = $input.name, visits += 1;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 3);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: user:tobie, name: 'Tobie', visits: 1 }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: user:tobie, name: 'Tobie Morgan', visits: 2 }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: user:tobie, name: 'Tobie Morgan Hitchcock', visits: 3 }]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn insert_statement_output() -> Result<(), Error> {
 	let sql = "
@@ -0,0 +1,53 @@
+mod parse;
+use parse::Parse;
+use surrealdb::dbs::Session;
+use surrealdb::err::Error;
+use surrealdb::kvs::Datastore;
+use surrealdb::sql::{StatementKind, Value};
+
+#[tokio::test]
+async fn inspect_statement_kinds() -> Result<(), Error> {
+	let sql = "
+		LET $name = 'Tobie';
+		CREATE person:tobie SET name = $name;
+		SELECT * FROM person;
+		IF true THEN (UPDATE person SET active = true) END;
+		DELETE person;
+		RETURN $name;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = dbs.inspect(sql, &ses)?;
+	assert_eq!(
+		res,
+		vec![
+			StatementKind::Set,
+			StatementKind::Create,
+			StatementKind::Select,
+			StatementKind::Ifelse,
+			StatementKind::Delete,
+			StatementKind::Output,
+		]
+	);
+	assert_eq!(res[0].to_string(), "LET");
+	assert_eq!(res[5].to_string(), "RETURN");
+	// Nothing should have been executed
+	let res = &mut dbs.execute("SELECT * FROM person", &ses, None).await?;
+	assert_eq!(res.len(), 1);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn inspect_invalid_query() -> Result<(), Error> {
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = dbs.inspect("SELECT * FROM person; CREATE", &ses);
+	assert!(matches!(res, Err(Error::InvalidQuery { .. })), "{res:?}");
+	//
+	Ok(())
+}
@@ -0,0 +1,38 @@
+mod parse;
+use parse::Parse;
+use surrealdb::dbs::Session;
+use surrealdb::err::Error;
+use surrealdb::kvs::Datastore;
+use surrealdb::sql::Value;
+
+#[tokio::test]
+async fn query_within_max_length() -> Result<(), Error> {
+	let sql = "CREATE person:test;";
+	let dbs = Datastore::new("memory").await?.with_max_query_length(Some(sql.len()));
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 1);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: person:test }]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn query_exceeding_max_length() -> Result<(), Error> {
+	// The query is not valid, so it would fail if it were parsed
+	let sql = format!("CREATE person SET data = '{}' ((", "x".repeat(1024));
+	let dbs = Datastore::new("memory").await?.with_max_query_length(Some(1024));
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = dbs.execute(&sql, &ses, None).await;
+	assert!(
+		matches!(res, Err(Error::QueryTooLarge { len, max: 1024 }) if len == sql.len()),
+		"{res:?}"
+	);
+	let res = dbs.prepare(&sql);
+	assert!(matches!(res, Err(Error::QueryTooLarge { .. })));
+	//
+	Ok(())
+}
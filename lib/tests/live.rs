@@ -0,0 +1,90 @@
+mod parse;
+use futures::FutureExt;
+use futures::StreamExt;
+use parse::Parse;
+use surrealdb::dbs::Action;
+use surrealdb::dbs::Session;
+use surrealdb::err::Error;
+use surrealdb::kvs::Datastore;
+use surrealdb::sql::{parse, Statement, Value};
+
+#[tokio::test]
+async fn live_query_stream() -> Result<(), Error> {
+	let sql = "
+		CREATE person:test SET age = 20;
+		CREATE person:child SET age = 5;
+		UPDATE person:test SET age = 21;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let ast = parse("LIVE SELECT * FROM person WHERE age >= 18")?;
+	let Some(Statement::Live(stm)) = ast.iter().next() else {
+		panic!("expected a live statement");
+	};
+	let mut stream = dbs.live("test", "test", stm.clone()).await?;
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 3);
+	//
+	let tmp = stream.next().await.unwrap();
+	assert_eq!(tmp.action, Action::Create);
+	assert_eq!(tmp.result, Value::parse("{ id: person:test, age: 20 }"));
+	//
+	let tmp = stream.next().await.unwrap();
+	assert_eq!(tmp.action, Action::Update);
+	assert_eq!(tmp.result, Value::parse("{ id: person:test, age: 21 }"));
+	// Records which don't match the condition are not sent
+	assert!(stream.next().now_or_never().is_none());
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn live_query_stream_discards_when_full() -> Result<(), Error> {
+	let sql: String = (0..150).map(|i| format!("CREATE person:{i} SET age = 20;")).collect();
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let ast = parse("LIVE SELECT * FROM person")?;
+	let Some(Statement::Live(stm)) = ast.iter().next() else {
+		panic!("expected a live statement");
+	};
+	let mut stream = dbs.live("test", "test", stm.clone()).await?;
+	// Queries are not held up by a subscriber which is not reading
+	let res = &mut dbs.execute(&sql, &ses, None).await?;
+	assert_eq!(res.len(), 150);
+	// Only the buffered notifications are received
+	let tmp: Vec<_> = stream.by_ref().take(100).collect().await;
+	assert_eq!(tmp.len(), 100);
+	assert!(stream.next().now_or_never().is_none());
+	// The stream receives notifications again once it has caught up
+	let res = &mut dbs.execute("CREATE person:test SET age = 20", &ses, None).await?;
+	assert_eq!(res.len(), 1);
+	let tmp = stream.next().await.unwrap();
+	assert_eq!(tmp.action, Action::Create);
+	assert_eq!(tmp.result, Value::parse("{ id: person:test, age: 20 }"));
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn live_query_stream_killed_when_dropped() -> Result<(), Error> {
+	let dbs = Datastore::new("memory").await?.with_notifications();
+	let mut ses = Session::for_kv().with_ns("test").with_db("test");
+	ses.rt = true;
+	let ast = parse("LIVE SELECT * FROM person")?;
+	let Some(Statement::Live(stm)) = ast.iter().next() else {
+		panic!("expected a live statement");
+	};
+	let stream = dbs.live("test", "test", stm.clone()).await?;
+	drop(stream);
+	// The notification is not sent to the notification channel
+	let res = &mut dbs.execute("CREATE person:test SET age = 20", &ses, None).await?;
+	assert_eq!(res.len(), 1);
+	let chn = dbs.notifications().unwrap();
+	assert!(chn.try_recv().is_err());
+	// The live query has been removed
+	let sql = format!("KILL {}", stm.id);
+	let res = &mut dbs.execute(&sql, &ses, None).await?;
+	assert!(res.remove(0).result.is_err());
+	//
+	Ok(())
+}
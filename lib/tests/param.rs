@@ -1,9 +1,10 @@
 mod parse;
 use parse::Parse;
-use surrealdb::dbs::Session;
+use std::collections::BTreeMap;
+use surrealdb::dbs::{Session, Vars};
 use surrealdb::err::Error;
 use surrealdb::kvs::Datastore;
-use surrealdb::sql::Value;
+use surrealdb::sql::{Kind, Thing, Value};
 
 #[tokio::test]
 async fn define_global_param() -> Result<(), Error> {
@@ -25,6 +26,7 @@ async fn define_global_param() -> Result<(), Error> {
 	let tmp = res.remove(0).result?;
 	let val = Value::parse(
 		"{
+			accesses: {},
 			analyzers: {},
 			logins: {},
 			tokens: {},
@@ -84,3 +86,138 @@ async fn define_protected_param() -> Result<(), Error> {
 	//
 	Ok(())
 }
+
+#[tokio::test]
+async fn destructure_object_and_array_params() -> Result<(), Error> {
+	let sql = "
+		LET $obj = { name: 'Tobie', age: 33, admin: true };
+		LET { name, age } = $obj;
+		LET [first, second] = ['one', 'two', 'three'];
+		RETURN [$name, $age, $first, $second];
+		RETURN { LET { admin } = $obj; RETURN $admin; };
+		LET { email } = $obj;
+		LET [x, y] = [1];
+		LET [z] = $obj;
+		LET { $auth } = { auth: true };
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 9);
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("['Tobie', 33, 'one', 'two']");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from(true);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp.err(),
+		Some(e) if e.to_string() == "Unable to destructure the value in the LET statement, as the object has no field 'email'"
+	));
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp.err(),
+		Some(e) if e.to_string() == "Unable to destructure the value in the LET statement, as the array has no element at index 1"
+	));
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp.err(),
+		Some(e) if e.to_string() == "Unable to destructure the value in the LET statement, as the value { admin: true, age: 33, name: 'Tobie' } is not an array"
+	));
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp.err(),
+		Some(e) if e.to_string() == "Found 'auth' but it is not possible to set a variable with this name"
+	));
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn session_typed_variables() -> Result<(), Error> {
+	let sql = "
+		SELECT * FROM $limit;
+		SELECT * FROM [1, 2, 3, 4, 5] LIMIT $limit;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let mut ses = Session::for_kv().with_ns("test").with_db("test");
+	// Valid values are coerced when set
+	ses.set_var_typed("limit", Value::from(2.0), Kind::Int)?;
+	// Invalid values are rejected when set
+	let res = ses.set_var_typed("limit", Value::from("many"), Kind::Int);
+	assert!(matches!(res, Err(Error::CoerceTo { .. })));
+	// Protected variables can not be set
+	let res = ses.set_var_typed("auth", Value::from(1), Kind::Int);
+	assert!(matches!(res, Err(Error::InvalidParam { .. })));
+	//
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 2);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[2]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[1, 2]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn execute_with_bound_params() -> Result<(), Error> {
+	let sql = "
+		CREATE person:tobie SET name = 'Tobie', age = 33;
+		CREATE person:jaime SET name = 'Jaime', age = 25;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 2);
+	//
+	let sql = "SELECT name, age + $add AS age FROM $id;";
+	let vars = Vars::new().set("id", Thing::from(("person", "tobie"))).set("add", 10);
+	let res = &mut dbs.execute_with(sql, &ses, vars).await?;
+	assert_eq!(res.len(), 1);
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ name: 'Tobie', age: 43 }]");
+	assert_eq!(tmp, val);
+	//
+	let vars = BTreeMap::from([
+		("id".to_owned(), Value::from(Thing::from(("person", "jaime")))),
+		("add".to_owned(), Value::from(1)),
+	]);
+	let res = &mut dbs.execute_with(sql, &ses, vars).await?;
+	assert_eq!(res.len(), 1);
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ name: 'Jaime', age: 26 }]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn execute_with_protected_param() -> Result<(), Error> {
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = dbs.execute_with("RETURN $auth;", &ses, Vars::new().set("auth", true)).await;
+	assert!(matches!(res, Err(Error::InvalidParam { .. })), "{res:?}");
+	//
+	Ok(())
+}
@@ -0,0 +1,150 @@
+mod parse;
+use parse::Parse;
+use surrealdb::dbs::Session;
+use surrealdb::err::Error;
+use surrealdb::kvs::Datastore;
+use surrealdb::sql::Value;
+
+#[tokio::test]
+async fn permissions_full_and_none() -> Result<(), Error> {
+	let sql = "
+		DEFINE TABLE open SCHEMALESS PERMISSIONS FULL;
+		DEFINE TABLE closed SCHEMALESS PERMISSIONS NONE;
+		CREATE open:1, closed:1;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 3);
+	for _ in 0..3 {
+		res.remove(0).result?;
+	}
+	//
+	let sql = "
+		SELECT * FROM open;
+		SELECT * FROM closed;
+		CREATE open:2;
+		CREATE closed:2;
+		UPDATE open:1 SET test = true;
+		UPDATE closed:1 SET test = true;
+		DELETE closed:1;
+	";
+	let ses = Session::for_sc("test", "test", "test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 7);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: open:1 }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: open:2 }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: open:1, test: true }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[]");
+	assert_eq!(tmp, val);
+	//
+	res.remove(0).result?;
+	//
+	let sql = "SELECT * FROM closed";
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: closed:1 }]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn permissions_specific_operations() -> Result<(), Error> {
+	let sql = "
+		DEFINE TABLE mixed SCHEMALESS PERMISSIONS
+			FOR select WHERE public = true,
+			FOR create FULL,
+			FOR update NONE,
+			FOR delete WHERE public = false;
+		CREATE mixed:1 SET public = true;
+		CREATE mixed:2 SET public = false;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 3);
+	for _ in 0..3 {
+		res.remove(0).result?;
+	}
+	//
+	let sql = "
+		SELECT * FROM mixed;
+		CREATE mixed:3 SET public = false;
+		UPDATE mixed SET test = true;
+		DELETE mixed;
+	";
+	let ses = Session::for_sc("test", "test", "test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 4);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: mixed:1, public: true }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: mixed:3, public: false }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[]");
+	assert_eq!(tmp, val);
+	//
+	res.remove(0).result?;
+	//
+	let sql = "SELECT * FROM mixed";
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: mixed:1, public: true }]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn permissions_repeated_operations() -> Result<(), Error> {
+	let sql = "
+		DEFINE TABLE repeated SCHEMALESS PERMISSIONS
+			FOR select WHERE true,
+			FOR select NONE,
+			FOR create, update, delete FULL;
+		CREATE repeated:1;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 2);
+	for _ in 0..2 {
+		res.remove(0).result?;
+	}
+	//
+	let sql = "SELECT * FROM repeated";
+	let ses = Session::for_sc("test", "test", "test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
@@ -117,6 +117,61 @@ async fn select_where_iterate_two_no_index() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn select_with_session_explain() -> Result<(), Error> {
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let sql = "
+		CREATE person:tobie SET name = 'Tobie', genre='m';
+		CREATE person:jaime SET name = 'Jaime', genre='m';
+		CREATE person:lizzie SET name = 'Lizzie', genre='f';
+	";
+	let res = dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 3);
+	// Without an index the table is scanned
+	let ses = ses.with_explain(true);
+	let sql = "SELECT name FROM person WHERE genre = 'm'";
+	let mut res = dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 1);
+	check_result(&mut res, &table_explain(2))?;
+	// With an index the index is iterated
+	let sql = "
+		DEFINE INDEX idx_genre ON TABLE person COLUMNS genre;
+		SELECT name FROM person WHERE genre = 'm';
+	";
+	let mut res = dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 2);
+	res.remove(0).result?;
+	check_result(
+		&mut res,
+		"[
+			{
+				detail: {
+					plan: {
+						index: 'idx_genre',
+						operator: '=',
+						value: 'm'
+					},
+					table: 'person'
+				},
+				operation: 'Iterate Index'
+			},
+			{
+				detail: {
+					count: 2
+				},
+				operation: 'Fetch'
+			}
+		]",
+	)?;
+	// Without the session option the results are returned
+	let ses = ses.with_explain(false);
+	let mut res = dbs.execute(sql, &ses, None).await?;
+	res.remove(0).result?;
+	check_result(&mut res, "[{ name: 'Jaime' }, { name: 'Tobie' }]")?;
+	Ok(())
+}
+
 async fn execute_test(sql: &str, expected_result: usize) -> Result<Vec<Response>, Error> {
 	let dbs = Datastore::new("memory").await?;
 	let ses = Session::for_kv().with_ns("test").with_db("test");
@@ -0,0 +1,48 @@
+mod parse;
+use parse::Parse;
+use std::collections::BTreeMap;
+use surrealdb::dbs::Session;
+use surrealdb::err::Error;
+use surrealdb::kvs::Datastore;
+use surrealdb::sql::Value;
+
+#[tokio::test]
+async fn prepared_select_with_different_params() -> Result<(), Error> {
+	let sql = "
+		CREATE person:tobie SET name = 'Tobie', age = 33;
+		CREATE person:jaime SET name = 'Jaime', age = 25;
+		CREATE person:lizzie SET name = 'Lizzie', age = 19;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 3);
+	//
+	let prep = dbs.prepare("SELECT name FROM person WHERE age > $age ORDER BY name;")?;
+	assert_eq!(prep.query().len(), 1);
+	//
+	let vars = BTreeMap::from([("age".to_owned(), Value::from(20))]);
+	let res = &mut dbs.run(&prep, &ses, Some(vars)).await?;
+	assert_eq!(res.len(), 1);
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ name: 'Jaime' }, { name: 'Tobie' }]");
+	assert_eq!(tmp, val);
+	//
+	let vars = BTreeMap::from([("age".to_owned(), Value::from(30))]);
+	let res = &mut dbs.run(&prep, &ses, Some(vars)).await?;
+	assert_eq!(res.len(), 1);
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ name: 'Tobie' }]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn prepared_query_parse_error() -> Result<(), Error> {
+	let dbs = Datastore::new("memory").await?;
+	let res = dbs.prepare("SELECT * FROM;");
+	assert!(matches!(res, Err(Error::InvalidQuery { .. })), "{res:?}");
+	//
+	Ok(())
+}
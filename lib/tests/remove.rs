@@ -30,6 +30,7 @@ async fn remove_statement_table() -> Result<(), Error> {
 	let tmp = res.remove(0).result?;
 	let val = Value::parse(
 		"{
+			accesses: {},
 			analyzers: {},
 			logins: {},
 			tokens: {},
@@ -64,6 +65,7 @@ async fn remove_statement_analyzer() -> Result<(), Error> {
 	let tmp = res.remove(0).result?;
 	let val = Value::parse(
 		"{
+			accesses: {},
 			analyzers: {},
 			logins: {},
 			tokens: {},
@@ -0,0 +1,47 @@
+mod parse;
+use parse::Parse;
+use surrealdb::dbs::{Responses, Session};
+use surrealdb::err::Error;
+use surrealdb::kvs::Datastore;
+use surrealdb::sql::Value;
+
+#[tokio::test]
+async fn response_take_errors() -> Result<(), Error> {
+	let sql = "
+		CREATE person:tobie SET name = 'Tobie';
+		CREATE person:tobie SET name = 'Jaime';
+		SELECT * FROM person;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 3);
+	//
+	let mut errors = res.take_errors();
+	assert_eq!(errors.len(), 1);
+	assert!(
+		matches!(
+			errors.remove(0),
+			(1, Error::RecordExists { thing }) if thing == "person:tobie"
+		),
+		"{errors:?}"
+	);
+	// The indices of the remaining statements are unchanged
+	assert_eq!(res.len(), 3);
+	// The failed statement is left with a placeholder error
+	assert!(matches!(res[1].result, Err(Error::QueryNotExecuted)));
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: person:tobie, name: 'Tobie' }]");
+	assert_eq!(tmp, val);
+	//
+	res.remove(0);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: person:tobie, name: 'Tobie' }]");
+	assert_eq!(tmp, val);
+	//
+	assert!(res.take_errors().is_empty());
+	//
+	Ok(())
+}
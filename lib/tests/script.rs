@@ -2,6 +2,7 @@
 
 mod parse;
 use parse::Parse;
+use std::time::{Duration, Instant};
 use surrealdb::dbs::Session;
 use surrealdb::err::Error;
 use surrealdb::kvs::Datastore;
@@ -37,6 +38,184 @@ async fn script_function_error() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn script_function_error_stack() -> Result<(), Error> {
+	let sql = "
+		SELECT * FROM function() {
+			let value = 1;
+			throw new Error('error');
+		};
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 1);
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp.err(),
+		Some(e @ Error::InvalidScript { stack: Some(_), line: Some(3), .. })
+			if e.to_string().starts_with("Problem with embedded script function. An exception occurred: error")
+	));
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn script_function_memory_limit() -> Result<(), Error> {
+	let sql = "
+		RETURN function() {
+			let values = [];
+			while (true) {
+				values.push('value'.repeat(1024));
+			}
+		};
+		RETURN function() {
+			return 'value'.repeat(1024).length;
+		};
+	";
+	let dbs = Datastore::new("memory").await?.with_script_memory_limit(Some(1_000_000));
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 2);
+	//
+	let tmp = res.remove(0).result;
+	assert!(
+		matches!(
+			&tmp,
+			Err(e @ Error::ScriptMemoryLimit { limit: 1_000_000 })
+				if e.to_string() == "Problem with embedded script function. The script exceeded the memory limit of 1000000 bytes"
+		),
+		"{tmp:?}"
+	);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from(5120);
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn script_function_timeout() -> Result<(), Error> {
+	let sql = "
+		RETURN function() {
+			while (true) {}
+		};
+		RETURN function() {
+			return 'finished';
+		};
+	";
+	let timeout = Duration::from_millis(250);
+	let dbs = Datastore::new("memory").await?.with_script_timeout(Some(timeout));
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let now = Instant::now();
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert!(now.elapsed() < Duration::from_secs(5));
+	assert_eq!(res.len(), 2);
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(&tmp, Err(Error::ScriptTimeout { timeout: t }) if *t == timeout), "{tmp:?}");
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from("finished");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn script_function_console_logs() -> Result<(), Error> {
+	let sql = "
+		RETURN function() {
+			console.log('value is', 'a' + 'b');
+			console.warn('careful');
+			return true;
+		};
+		RETURN function() {
+			for (let i = 0; i < 5000; i++) {
+				console.log('line ' + i);
+			}
+		};
+		RETURN function() {
+			console.log('é'.repeat(20000));
+		};
+		RETURN true;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 4);
+	//
+	let tmp = res.remove(0);
+	assert_eq!(tmp.logs, vec!["[INFO] value is ab", "[WARN] careful"]);
+	//
+	let tmp = res.remove(0);
+	assert_eq!(tmp.logs.len(), 1000);
+	assert_eq!(tmp.logs[0], "[INFO] line 0");
+	//
+	let tmp = res.remove(0);
+	assert_eq!(tmp.logs.len(), 1);
+	assert_eq!(tmp.logs[0], format!("[INFO] {}...", "é".repeat(5000)));
+	//
+	let tmp = res.remove(0);
+	assert!(tmp.logs.is_empty());
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn script_function_text_encoding() -> Result<(), Error> {
+	let sql = "
+		RETURN function() {
+			const text = 'Hello, 世界 👋';
+			const bytes = new TextEncoder().encode(text);
+			return {
+				array: bytes instanceof Uint8Array,
+				length: bytes.length,
+				decoded: new TextDecoder().decode(bytes),
+				buffer: new TextDecoder('utf8').decode(bytes.buffer),
+			};
+		};
+		RETURN function() {
+			return new TextDecoder('utf-8', { fatal: true }).decode(new Uint8Array([0xff]));
+		};
+		RETURN function() {
+			return new TextDecoder().decode(new Uint8Array([0x61, 0xff]));
+		};
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 3);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"{
+			array: true,
+			length: 18,
+			decoded: 'Hello, 世界 👋',
+			buffer: 'Hello, 世界 👋',
+		}",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result;
+	assert!(
+		matches!(
+			&tmp,
+			Err(e) if e.to_string() == "Problem with embedded script function. An exception occurred: The encoded data was not valid UTF-8"
+		),
+		"{tmp:?}"
+	);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from("a\u{fffd}");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn script_function_simple() -> Result<(), Error> {
 	let sql = r#"
@@ -100,6 +279,46 @@ async fn script_function_context() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn script_function_session() -> Result<(), Error> {
+	let sql = "
+		RETURN function() {
+			const { session } = surrealdb;
+			let writable = true;
+			try {
+				session.ns = 'other';
+			} catch (e) {
+				writable = false;
+			}
+			return {
+				ns: session.ns,
+				db: session.db,
+				level: session.level,
+				frozen: Object.isFrozen(session),
+				writable,
+			};
+		};
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("mynamespace").with_db("mydatabase");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 1);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"{
+			ns: 'mynamespace',
+			db: 'mydatabase',
+			level: 'kv',
+			frozen: true,
+			writable: false,
+		}",
+	);
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn script_function_arguments() -> Result<(), Error> {
 	let sql = "
@@ -174,6 +393,38 @@ async fn script_function_types() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn script_function_subsecond_durations() -> Result<(), Error> {
+	let sql = "
+		CREATE article:test SET
+			timeout = function() {
+				return new Duration('500ms');
+			},
+			delay = function() {
+				return new Duration('1h30m500ms');
+			}
+		;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 1);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				id: article:test,
+				timeout: 500ms,
+				delay: 1h30m500ms,
+			}
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn script_function_module_os() -> Result<(), Error> {
 	let sql = "
@@ -193,6 +444,137 @@ async fn script_function_module_os() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn script_function_module_crypto() -> Result<(), Error> {
+	let sql = "
+		RETURN function() {
+			const { subtle } = await import('crypto');
+			const hash = await subtle.digest('SHA-256', 'hello');
+			return Array.from(new Uint8Array(hash))
+				.map((v) => v.toString(16).padStart(2, '0'))
+				.join('');
+		};
+		RETURN function() {
+			const crypto = await import('crypto');
+			return crypto.randomUUID();
+		};
+		RETURN function() {
+			const { randomBytes } = await import('crypto');
+			return randomBytes(16);
+		};
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 3);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from("2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let Value::Uuid(uuid) = tmp else {
+		panic!("expected a uuid");
+	};
+	assert_eq!(uuid.get_version_num(), 4);
+	//
+	let tmp = res.remove(0).result?;
+	let Value::Bytes(bytes) = tmp else {
+		panic!("expected bytes");
+	};
+	assert_eq!(bytes.len(), 16);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+#[cfg(feature = "http")]
+async fn script_function_fetch_allowed_host() -> Result<(), Error> {
+	use wiremock::matchers::{method, path};
+	use wiremock::{Mock, MockServer, ResponseTemplate};
+	let server = MockServer::start().await;
+	Mock::given(method("GET"))
+		.and(path("/users/tobie"))
+		.respond_with(ResponseTemplate::new(200).set_body_string(r#"{ "name": "Tobie" }"#))
+		.expect(1)
+		.mount(&server)
+		.await;
+	let sql = format!(
+		r#"
+		RETURN function() {{
+			let res = await fetch('{}/users/tobie');
+			return {{ status: res.status, body: await res.json() }};
+		}};
+		"#,
+		server.uri()
+	);
+	let dbs = Datastore::new("memory")
+		.await?
+		.with_script_fetch_allowlist(Some(vec!["127.0.0.1".to_owned()]));
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None).await?;
+	assert_eq!(res.len(), 1);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("{ status: 200, body: { name: 'Tobie' } }");
+	assert_eq!(tmp, val);
+	//
+	server.verify().await;
+	Ok(())
+}
+
+#[tokio::test]
+#[cfg(feature = "http")]
+async fn script_function_fetch_blocked_host() -> Result<(), Error> {
+	use wiremock::{Mock, MockServer, ResponseTemplate};
+	let server = MockServer::start().await;
+	Mock::given(wiremock::matchers::any())
+		.respond_with(ResponseTemplate::new(200))
+		.expect(0)
+		.mount(&server)
+		.await;
+	let sql = format!(
+		r#"
+		RETURN function() {{
+			return await fetch('{}/users/tobie');
+		}};
+		"#,
+		server.uri()
+	);
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	// The host is not in the allowlist
+	let dbs = Datastore::new("memory")
+		.await?
+		.with_script_fetch_allowlist(Some(vec!["surrealdb.com".to_owned()]));
+	let res = &mut dbs.execute(&sql, &ses, None).await?;
+	assert_eq!(res.len(), 1);
+	//
+	let tmp = res.remove(0).result;
+	assert!(
+		matches!(
+			&tmp,
+			Err(e) if e.to_string() == "Problem with embedded script function. An exception occurred: The host '127.0.0.1' is not allowed to be fetched from"
+		),
+		"{tmp:?}"
+	);
+	// No allowlist has been configured
+	let dbs = Datastore::new("memory").await?;
+	let res = &mut dbs.execute(&sql, &ses, None).await?;
+	assert_eq!(res.len(), 1);
+	//
+	let tmp = res.remove(0).result;
+	assert!(
+		matches!(
+			&tmp,
+			Err(e) if e.to_string() == "Problem with embedded script function. An exception occurred: The 'fetch' function is disabled. In order to use 'fetch', configure the hosts which embedded scripts are allowed to fetch from."
+		),
+		"{tmp:?}"
+	);
+	//
+	server.verify().await;
+	Ok(())
+}
+
 #[tokio::test]
 async fn script_query_from_script_select() -> Result<(), Error> {
 	let sql = r#"
@@ -247,6 +629,163 @@ async fn script_query_from_script_select() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn script_query_from_script_typed_bindings() -> Result<(), Error> {
+	let sql = r#"
+		CREATE user:joanna SET name = "Joanna";
+		CREATE user:tobie SET name = "Tobie";
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	dbs.execute(sql, &ses, None).await?;
+	//
+	let sql = r#"
+		RETURN function() {
+			let query = new surrealdb.Query(`SELECT * FROM user WHERE id = $u`);
+			query.bind("u", new Record('user', 'joanna'));
+			return await surrealdb.query(query);
+		};
+		RETURN function() {
+			let query = new surrealdb.Query(`RETURN [$d, $t, $x]`);
+			query.bind("d", new Duration('1w2d6h'));
+			query.bind("t", new Date('1995-12-17T03:24:00Z'));
+			query.bind("x", new Uuid('03412258-988f-47cd-82db-549902cdaffe'));
+			return await surrealdb.query(query);
+		};
+	"#;
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 2);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: user:joanna, name: 'Joanna' }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			1w2d6h,
+			'1995-12-17T03:24:00Z',
+			'03412258-988f-47cd-82db-549902cdaffe',
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn script_query_from_script_timeout() -> Result<(), Error> {
+	let sql = r#"
+		CREATE test SET name = "a", number = 0;
+		CREATE test SET name = "b", number = 1;
+		CREATE test SET name = "c", number = 2;
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	dbs.execute(sql, &ses, None).await?;
+	//
+	let sql = r#"
+		RETURN function() {
+			try {
+				await surrealdb.query(`SELECT * FROM test`, {}, { timeout: new Duration('1ns') });
+				return 'completed';
+			} catch (e) {
+				return { name: e.name, message: e.message };
+			}
+		};
+		RETURN function() {
+			const res = await surrealdb.query(`SELECT number FROM test WHERE name = $name`, {
+				name: "a"
+			}, { timeout: 5000 });
+			return res;
+		};
+		RETURN function() {
+			try {
+				await surrealdb.query(`SELECT * FROM test`, {}, { timeout: 1e300 });
+				return 'completed';
+			} catch (e) {
+				return e.name;
+			}
+		};
+		RETURN function() {
+			try {
+				await surrealdb.query(`SELECT * FROM test`, {}, { timeout: 1.8e22 });
+				return 'completed';
+			} catch (e) {
+				return e.name;
+			}
+		};
+	"#;
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 4);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"{
+			name: 'TimeoutError',
+			message: 'The query was not executed because it exceeded the timeout',
+		}",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ number: 0 }]");
+	assert_eq!(tmp, val);
+	// Timeouts which are too large to be represented are rejected
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("'TypeError'");
+	assert_eq!(tmp, val);
+	// Timeouts which are too large to be added to the current time are rejected
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("'RangeError'");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn script_call_defined_function() -> Result<(), Error> {
+	let sql = r#"
+		DEFINE FUNCTION fn::double($num: number) {
+			RETURN $num * 2;
+		};
+		RETURN function() {
+			return surrealdb.call("fn::double", 21);
+		};
+		RETURN function() {
+			return await surrealdb.call("double", 2.5);
+		};
+		RETURN function() {
+			return surrealdb.call("fn::double");
+		};
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 4);
+	//
+	res.remove(0).result?;
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from(42);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from(5.0);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result;
+	assert!(
+		matches!(
+			&tmp,
+			Err(e) if e.to_string() == "Problem with embedded script function. An exception occurred: Incorrect arguments for function fn::double(). The function expects 1 argument."
+		),
+		"{tmp:?}"
+	);
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn script_query_from_script() -> Result<(), Error> {
 	let sql = r#"
@@ -477,6 +477,46 @@ async fn select_where_field_is_thing_and_with_index() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn select_where_field_is_truthy() -> Result<(), Error> {
+	let sql = "
+		SELECT VALUE n FROM [
+			{ n: 1, v: 0 },
+			{ n: 2, v: 1 },
+			{ n: 3, v: '' },
+			{ n: 4, v: 'text' },
+			{ n: 5, v: [] },
+			{ n: 6, v: [0] },
+			{ n: 7, v: {} },
+			{ n: 8, v: { a: 0 } },
+			{ n: 9, v: NONE },
+			{ n: 10, v: NULL },
+			{ n: 11, v: false },
+			{ n: 12, v: true },
+		] WHERE v;
+		SELECT VALUE n FROM [
+			{ n: 1, v: 0 },
+			{ n: 2, v: 1 },
+			{ n: 3, v: '' },
+			{ n: 4, v: 'text' },
+		] WHERE !v;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 2);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[2, 4, 6, 8, 12]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[1, 3]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn select_where_and_with_index() -> Result<(), Error> {
 	let sql = "
@@ -683,3 +723,152 @@ async fn select_where_explain() -> Result<(), Error> {
 	//
 	Ok(())
 }
+
+#[tokio::test]
+async fn select_distinct() -> Result<(), Error> {
+	let sql = "
+		CREATE person:1 SET name = 'Tobie', age = 30;
+		CREATE person:2 SET name = 'Jaime', age = 30;
+		CREATE person:3 SET name = 'Tobie', age = 30;
+		CREATE person:4 SET name = 'Tobie', age = 40;
+		SELECT DISTINCT name FROM person;
+		SELECT DISTINCT VALUE age FROM person;
+		SELECT DISTINCT name, age FROM person;
+		SELECT DISTINCT name FROM person LIMIT 2;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 8);
+	//
+	for _ in 0..4 {
+		let tmp = res.remove(0).result;
+		assert!(tmp.is_ok());
+	}
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				name: 'Tobie'
+			},
+			{
+				name: 'Jaime'
+			}
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[30, 40]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				age: 30,
+				name: 'Tobie'
+			},
+			{
+				age: 30,
+				name: 'Jaime'
+			},
+			{
+				age: 40,
+				name: 'Tobie'
+			}
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				name: 'Tobie'
+			},
+			{
+				name: 'Jaime'
+			}
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn select_idiom_flatten() -> Result<(), Error> {
+	let sql = "
+		CREATE post:1 SET tags = [{ values: ['a', 'b'] }, { values: ['c', ['d']] }];
+		SELECT VALUE tags.*.values.flatten() FROM post;
+		SELECT tags.*.values.flatten(2) AS values FROM post;
+		SELECT VALUE tags.*.values.flatten().flatten() FROM post;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 4);
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[['a', 'b', 'c', ['d']]]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				values: ['a', 'b', 'c', 'd']
+			}
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[['a', 'b', 'c', 'd']]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn select_response_statistics() -> Result<(), Error> {
+	let sql = "
+		CREATE |person:1..100| SET name = 'Tobie';
+		SELECT * FROM person WHERE name = 'Tobie' ORDER BY id;
+		SELECT * FROM person:1;
+		RETURN NONE;
+		SELECT * FROM person WHERE name = 'Jaime';
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test").with_stats(true);
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 5);
+	//
+	let tmp = res.remove(0);
+	assert!(tmp.time > std::time::Duration::ZERO);
+	assert_eq!(tmp.rows, Some(100));
+	//
+	let tmp = res.remove(0);
+	assert!(tmp.time > std::time::Duration::ZERO);
+	assert_eq!(tmp.rows, Some(100));
+	//
+	let tmp = res.remove(0);
+	assert_eq!(tmp.rows, Some(1));
+	//
+	let tmp = res.remove(0);
+	assert_eq!(tmp.rows, Some(0));
+	//
+	let tmp = res.remove(0);
+	assert_eq!(tmp.rows, Some(0));
+	//
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute("SELECT * FROM person", &ses, None).await?;
+	assert_eq!(res.remove(0).rows, None);
+	//
+	Ok(())
+}
@@ -0,0 +1,80 @@
+mod parse;
+use parse::Parse;
+use std::sync::Arc;
+use std::time::Duration;
+use surrealdb::dbs::Session;
+use surrealdb::err::Error;
+use surrealdb::kvs::Datastore;
+use surrealdb::sql::Value;
+
+#[tokio::test]
+async fn shutdown_waits_for_inflight_transactions() -> Result<(), Error> {
+	let sql = "
+		BEGIN;
+		CREATE person:test;
+		SLEEP 300ms;
+		COMMIT;
+	";
+	let dbs = Arc::new(Datastore::new("memory").await?);
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	// Start a slow transaction
+	let slow = tokio::spawn({
+		let dbs = dbs.clone();
+		let ses = ses.clone();
+		async move { dbs.execute(sql, &ses, None).await }
+	});
+	tokio::time::sleep(Duration::from_millis(50)).await;
+	// Begin shutting down while the transaction is running
+	let shutdown = tokio::spawn({
+		let dbs = dbs.clone();
+		async move { dbs.shutdown(Duration::from_secs(5)).await }
+	});
+	tokio::time::sleep(Duration::from_millis(50)).await;
+	// New queries are rejected while draining
+	let res = dbs.execute("CREATE person:other", &ses, None).await;
+	assert!(matches!(res, Err(Error::DsShutdown)), "{res:?}");
+	let res = dbs.transaction(true, false).await;
+	assert!(matches!(res, Err(Error::DsShutdown)));
+	// The in-flight transaction is committed
+	shutdown.await.unwrap()?;
+	let res = &mut slow.await.unwrap()?;
+	assert_eq!(res.len(), 2);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: person:test }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	assert_eq!(tmp, Value::None);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn shutdown_rolls_back_unfinished_transactions() -> Result<(), Error> {
+	let sql = "
+		BEGIN;
+		CREATE person:test;
+		SLEEP 500ms;
+		COMMIT;
+	";
+	let dbs = Arc::new(Datastore::new("memory").await?);
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	// Start a slow transaction
+	let slow = tokio::spawn({
+		let dbs = dbs.clone();
+		let ses = ses.clone();
+		async move { dbs.execute(sql, &ses, None).await }
+	});
+	tokio::time::sleep(Duration::from_millis(50)).await;
+	// Shut down without waiting long enough for the transaction
+	dbs.shutdown(Duration::from_millis(50)).await?;
+	// The in-flight transaction fails to commit
+	let res = slow.await.unwrap()?;
+	assert_eq!(res.len(), 2);
+	for tmp in res {
+		assert!(tmp.result.is_err(), "{:?}", tmp.result);
+	}
+	//
+	Ok(())
+}
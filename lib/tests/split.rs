@@ -0,0 +1,80 @@
+mod parse;
+use parse::Parse;
+use surrealdb::dbs::Session;
+use surrealdb::err::{ClauseErrorKind, Error};
+use surrealdb::kvs::Datastore;
+use surrealdb::sql::Value;
+
+#[tokio::test]
+async fn select_split_multiple_fields() -> Result<(), Error> {
+	let sql = "
+		CREATE person:tobie SET colours = ['red', 'blue'], sizes = [1, 2, 3];
+		SELECT id, colours, sizes FROM person SPLIT colours, sizes;
+		SELECT count() FROM (SELECT colours, sizes FROM person SPLIT colours, sizes) GROUP ALL;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 3);
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{ id: person:tobie, colours: 'red', sizes: 1 },
+			{ id: person:tobie, colours: 'red', sizes: 2 },
+			{ id: person:tobie, colours: 'red', sizes: 3 },
+			{ id: person:tobie, colours: 'blue', sizes: 1 },
+			{ id: person:tobie, colours: 'blue', sizes: 2 },
+			{ id: person:tobie, colours: 'blue', sizes: 3 },
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ count: 6 }]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn select_split_nested_fields() -> Result<(), Error> {
+	let sql = "SELECT id, info FROM person SPLIT tags";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = dbs.execute(sql, &ses, None).await;
+	assert!(
+		matches!(
+			&res,
+			Err(e) if e.to_string() == "Found 'tags' in SPLIT ON clause on line 1, but field is not present in SELECT expression"
+		),
+		"{res:?}"
+	);
+	let (kind, field) = res.as_ref().unwrap_err().clause_error().unwrap();
+	assert_eq!(kind, ClauseErrorKind::Split);
+	assert_eq!(field, "tags");
+	//
+	let sql = "
+		CREATE person:tobie SET info.tags = ['one', 'two'], info.name = 'Tobie';
+		SELECT id, info FROM person SPLIT info.tags;
+	";
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 2);
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{ id: person:tobie, info: { name: 'Tobie', tags: 'one' } },
+			{ id: person:tobie, info: { name: 'Tobie', tags: 'two' } },
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
@@ -0,0 +1,141 @@
+mod parse;
+use parse::Parse;
+use std::collections::BTreeMap;
+use std::ops::Range;
+use std::sync::{Arc, Mutex};
+use surrealdb::dbs::Session;
+use surrealdb::err::Error;
+use surrealdb::kvs::{Datastore, Key, KvStore, KvTransaction, StoreFuture, Val};
+use surrealdb::sql::Value;
+
+/// A trivial storage engine which keeps all data in a map
+#[derive(Clone, Default)]
+struct MapStore {
+	data: Arc<Mutex<BTreeMap<Key, Val>>>,
+}
+
+/// A transaction which works on a copy of the map, and replaces it on commit
+struct MapTransaction {
+	done: bool,
+	write: bool,
+	data: BTreeMap<Key, Val>,
+	store: Arc<Mutex<BTreeMap<Key, Val>>>,
+}
+
+impl MapTransaction {
+	fn check(&self, write: bool) -> Result<(), Error> {
+		if self.done {
+			return Err(Error::TxFinished);
+		}
+		if write && !self.write {
+			return Err(Error::TxReadonly);
+		}
+		Ok(())
+	}
+}
+
+impl KvStore for MapStore {
+	fn transaction(&self, write: bool, _: bool) -> StoreFuture<'_, Box<dyn KvTransaction>> {
+		let tx = MapTransaction {
+			done: false,
+			write,
+			data: self.data.lock().unwrap().clone(),
+			store: self.data.clone(),
+		};
+		Box::pin(async move { Ok(Box::new(tx) as Box<dyn KvTransaction>) })
+	}
+}
+
+impl KvTransaction for MapTransaction {
+	fn closed(&self) -> bool {
+		self.done
+	}
+	fn cancel(&mut self) -> StoreFuture<'_, ()> {
+		Box::pin(async move {
+			self.check(false)?;
+			self.done = true;
+			Ok(())
+		})
+	}
+	fn commit(&mut self) -> StoreFuture<'_, ()> {
+		Box::pin(async move {
+			self.check(true)?;
+			self.done = true;
+			*self.store.lock().unwrap() = std::mem::take(&mut self.data);
+			Ok(())
+		})
+	}
+	fn get(&mut self, key: Key) -> StoreFuture<'_, Option<Val>> {
+		Box::pin(async move {
+			self.check(false)?;
+			Ok(self.data.get(&key).cloned())
+		})
+	}
+	fn set(&mut self, key: Key, val: Val) -> StoreFuture<'_, ()> {
+		Box::pin(async move {
+			self.check(true)?;
+			self.data.insert(key, val);
+			Ok(())
+		})
+	}
+	fn del(&mut self, key: Key) -> StoreFuture<'_, ()> {
+		Box::pin(async move {
+			self.check(true)?;
+			self.data.remove(&key);
+			Ok(())
+		})
+	}
+	fn scan(&mut self, rng: Range<Key>, limit: u32) -> StoreFuture<'_, Vec<(Key, Val)>> {
+		Box::pin(async move {
+			self.check(false)?;
+			Ok(self
+				.data
+				.range(rng)
+				.take(limit as usize)
+				.map(|(k, v)| (k.clone(), v.clone()))
+				.collect())
+		})
+	}
+}
+
+#[tokio::test]
+async fn custom_store_table_definitions() -> Result<(), Error> {
+	let sql = "
+		DEFINE TABLE person SCHEMALESS;
+		DEFINE TABLE animal SCHEMALESS;
+		CREATE person:tobie SET name = 'Tobie';
+	";
+	let store = MapStore::default();
+	let dbs = Datastore::new_with_store(store.clone());
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 3);
+	for _ in 0..3 {
+		res.remove(0).result?;
+	}
+	assert!(!store.data.lock().unwrap().is_empty());
+	//
+	let sql = "
+		INFO FOR DB;
+		SELECT * FROM person;
+	";
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 2);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"{
+			animal: 'DEFINE TABLE animal SCHEMALESS',
+			person: 'DEFINE TABLE person SCHEMALESS',
+		}",
+	);
+	assert_eq!(tmp.pick(&["tables".into()]), val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: person:tobie, name: 'Tobie' }]");
+	assert_eq!(tmp, val);
+	//
+	assert_eq!(dbs.to_string(), "custom");
+	//
+	Ok(())
+}
@@ -3,6 +3,7 @@ use parse::Parse;
 use surrealdb::dbs::Session;
 use surrealdb::err::Error;
 use surrealdb::kvs::Datastore;
+use surrealdb::opt::Config;
 use surrealdb::sql::Value;
 
 #[tokio::test]
@@ -164,6 +165,30 @@ async fn strict_mode_no_table() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn strict_mode_from_config() -> Result<(), Error> {
+	let sql = "
+		DEFINE NAMESPACE test;
+		DEFINE DATABASE test;
+		CREATE test:tester;
+	";
+	let dbs = Datastore::new_with_config("memory", Config::new().strict()).await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 3);
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(tmp, Err(Error::TbNotFound { .. })), "{tmp:?}");
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn strict_mode_all_ok() -> Result<(), Error> {
 	let sql = "
@@ -240,6 +265,7 @@ async fn loose_mode_all_ok() -> Result<(), Error> {
 	let tmp = res.remove(0).result?;
 	let val = Value::parse(
 		"{
+			accesses: {},
 			databases: { test: 'DEFINE DATABASE test' },
 			logins: {},
 			tokens: {},
@@ -250,6 +276,7 @@ async fn loose_mode_all_ok() -> Result<(), Error> {
 	let tmp = res.remove(0).result?;
 	let val = Value::parse(
 		"{
+			accesses: {},
 			analyzers: {},
 			logins: {},
 			tokens: {},
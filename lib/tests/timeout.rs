@@ -0,0 +1,57 @@
+mod parse;
+use parse::Parse;
+use std::time::Duration;
+use surrealdb::dbs::Session;
+use surrealdb::err::Error;
+use surrealdb::kvs::Datastore;
+use surrealdb::sql::Value;
+
+#[tokio::test]
+async fn session_query_timeout() -> Result<(), Error> {
+	let sql = "
+		RETURN sleep(2s);
+		CREATE person:test;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv()
+		.with_ns("test")
+		.with_db("test")
+		.with_query_timeout(Duration::from_millis(100));
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 2);
+	//
+	let tmp = res.remove(0);
+	assert!(tmp.time < Duration::from_secs(2), "{:?}", tmp.time);
+	assert!(matches!(tmp.result, Err(Error::QueryTimedout)), "{:?}", tmp.result);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: person:test }]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn statement_timeout_overrides_session_timeout() -> Result<(), Error> {
+	let sql = "
+		SELECT * FROM person WHERE sleep(200ms) = NONE TIMEOUT 5s;
+		SELECT * FROM person WHERE sleep(200ms) = NONE;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute("CREATE person:test", &ses, None).await?;
+	assert_eq!(res.len(), 1);
+	//
+	let ses = ses.with_query_timeout(Duration::from_millis(100));
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 2);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: person:test }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(tmp, Err(Error::QueryTimedout)), "{tmp:?}");
+	//
+	Ok(())
+}
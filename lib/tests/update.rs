@@ -133,3 +133,38 @@ async fn update_complex_with_input() -> Result<(), Error> {
 	//
 	Ok(())
 }
+
+#[tokio::test]
+async fn update_with_return_clause() -> Result<(), Error> {
+	let sql = "
+		CREATE person:tobie SET name = 'Tobie';
+		UPDATE person:tobie SET name = 'Jaime' RETURN BEFORE;
+		UPDATE person:tobie SET name = 'Tobie' RETURN DIFF;
+		UPDATE person:tobie SET name = 'Jaime' RETURN NONE;
+		SELECT * FROM person;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 5);
+	//
+	let _ = res.remove(0).result?;
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: person:tobie, name: 'Tobie' }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[[{ op: 'replace', path: '/name', value: 'Tobie' }]]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: person:tobie, name: 'Jaime' }]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
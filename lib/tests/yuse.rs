@@ -82,3 +82,77 @@ async fn use_statement_set_both() -> Result<(), Error> {
 	//
 	Ok(())
 }
+
+#[tokio::test]
+async fn session_scoped_to_other_database() -> Result<(), Error> {
+	let sql = "
+		CREATE person:test;
+		SELECT * FROM $session.ns, session::ns(), $session.db, session::db();
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let scoped = ses.scoped(Some("other_ns"), Some("other_db"))?;
+	let res = &mut dbs.execute(sql, &scoped, None).await?;
+	assert_eq!(res.len(), 2);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: person:test }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("['other_ns', 'other_ns', 'other_db', 'other_db']");
+	assert_eq!(tmp, val);
+	//
+	let sql = "
+		SELECT * FROM person;
+		SELECT * FROM $session.ns, session::ns(), $session.db, session::db();
+	";
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 2);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("['test', 'test', 'test', 'test']");
+	assert_eq!(tmp, val);
+	//
+	let scoped = ses.scoped(None, Some("other_db"))?;
+	assert_eq!(scoped.ns.as_deref(), Some("test"));
+	assert_eq!(scoped.db.as_deref(), Some("other_db"));
+	assert_eq!(scoped.au, ses.au);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn session_scoped_checks_authentication() -> Result<(), Error> {
+	let ses = Session::for_ns("test");
+	let scoped = ses.scoped(None, Some("other_db"))?;
+	assert_eq!(scoped.db.as_deref(), Some("other_db"));
+	let res = ses.scoped(Some("other_ns"), None);
+	assert!(matches!(res, Err(Error::NsNotAllowed { .. })));
+	//
+	let ses = Session::for_db("test", "test");
+	let scoped = ses.scoped(Some("test"), Some("test"))?;
+	assert_eq!(scoped, ses);
+	let res = ses.scoped(None, Some("other_db"));
+	assert!(matches!(res, Err(Error::DbNotAllowed { .. })));
+	//
+	let ses = Session::for_sc("test", "test", "user");
+	let res = ses.scoped(None, Some("test"));
+	assert!(matches!(res, Err(Error::DbNotAllowed { .. })));
+	//
+	let mut ses = Session::for_kv().with_ns("test").with_db("test");
+	ses.sc = Some(String::from("user"));
+	ses.sd = Some(Value::from("user:tobie"));
+	let scoped = ses.scoped(None, None)?;
+	assert_eq!(scoped, ses);
+	let scoped = ses.scoped(None, Some("other_db"))?;
+	assert_eq!(scoped.sc, None);
+	assert_eq!(scoped.sd, None);
+	assert_eq!(scoped.tk, None);
+	//
+	Ok(())
+}
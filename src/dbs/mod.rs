@@ -1,4 +1,5 @@
 use crate::cli::CF;
+use crate::cnf::EXPIRY_COMPACTION_INTERVAL;
 use crate::err::Error;
 use clap::Args;
 use once_cell::sync::OnceCell;
@@ -21,6 +22,10 @@ pub struct StartCommandDbsOptions {
 	#[arg(env = "SURREAL_TRANSACTION_TIMEOUT", long)]
 	#[arg(value_parser = super::cli::validator::duration)]
 	transaction_timeout: Option<Duration>,
+	#[arg(help = "The hosts which embedded scripts are allowed to fetch from")]
+	#[arg(env = "SURREAL_SCRIPT_FETCH_ALLOWLIST", long)]
+	#[arg(value_delimiter = ',')]
+	script_fetch_allowlist: Option<Vec<String>>,
 }
 
 pub async fn init(
@@ -28,6 +33,7 @@ pub async fn init(
 		strict_mode,
 		query_timeout,
 		transaction_timeout,
+		script_fetch_allowlist,
 	}: StartCommandDbsOptions,
 ) -> Result<(), Error> {
 	// Get local copy of options
@@ -42,16 +48,33 @@ pub async fn init(
 	if let Some(v) = transaction_timeout {
 		debug!("Maximum transaction processing timeout is {v:?}");
 	}
+	// Log specified script fetch allowlist
+	if let Some(v) = &script_fetch_allowlist {
+		debug!("Embedded scripts are allowed to fetch from {v:?}");
+	}
 	// Parse and setup the desired kv datastore
 	let dbs = Datastore::new(&opt.path)
 		.await?
 		.with_notifications()
 		.with_strict_mode(strict_mode)
 		.with_query_timeout(query_timeout)
-		.with_transaction_timeout(transaction_timeout);
+		.with_transaction_timeout(transaction_timeout)
+		.with_script_fetch_allowlist(script_fetch_allowlist);
 	dbs.bootstrap().await?;
 	// Store database instance
 	let _ = DB.set(dbs);
+	// Periodically remove expired keys, once any keys have been set with a time-to-live
+	tokio::spawn(async {
+		let mut interval = tokio::time::interval(EXPIRY_COMPACTION_INTERVAL);
+		loop {
+			interval.tick().await;
+			if let Some(dbs) = DB.get() {
+				if let Err(e) = dbs.compact_expired().await {
+					warn!("Unable to remove expired keys: {e}");
+				}
+			}
+		}
+	});
 	// All ok
 	Ok(())
 }